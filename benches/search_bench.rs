@@ -1,9 +1,19 @@
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use interpretable_chess_engine::core::GameState;
+use interpretable_chess_engine::movegen::is_in_check;
 
 // TODO: Add actual benchmarks when search is implemented
 fn placeholder_benchmark(c: &mut Criterion) {
     c.bench_function("placeholder", |b| b.iter(|| black_box(42)));
 }
 
-criterion_group!(benches, placeholder_benchmark);
+fn check_detection_benchmark(c: &mut Criterion) {
+    let game =
+        GameState::from_fen("r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1")
+            .unwrap();
+    c.bench_function("is_check", |b| b.iter(|| black_box(&game).is_check()));
+    c.bench_function("is_in_check", |b| b.iter(|| is_in_check(black_box(&game))));
+}
+
+criterion_group!(benches, placeholder_benchmark, check_detection_benchmark);
 criterion_main!(benches);
@@ -0,0 +1,7 @@
+//! Higher-level game analysis built on top of the core engine.
+
+pub mod openings;
+pub mod plan;
+
+pub use openings::classify_opening;
+pub use plan::suggest_plan;
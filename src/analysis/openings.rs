@@ -0,0 +1,88 @@
+//! Opening classification from the moves played.
+
+use crate::core::{GameState, Move};
+use crate::movegen::find_legal_move;
+
+/// Opening names keyed by their defining SAN move sequence.
+const OPENINGS: &[(&str, &str)] = &[
+    ("e4", "King's Pawn Game"),
+    ("e4 e5", "Open Game"),
+    ("e4 e5 Nf3 Nc6 Bb5", "Ruy Lopez"),
+    ("e4 e5 Nf3 Nc6 Bc4", "Italian Game"),
+    ("e4 e5 Nf3 Nc6 d4", "Scotch Game"),
+    ("e4 e5 Nf3 Nf6", "Petrov's Defense"),
+    ("e4 e5 f4", "King's Gambit"),
+    ("e4 c5", "Sicilian Defense"),
+    ("e4 e6", "French Defense"),
+    ("e4 c6", "Caro-Kann Defense"),
+    ("e4 d5", "Scandinavian Defense"),
+    ("d4", "Queen's Pawn Game"),
+    ("d4 d5 c4", "Queen's Gambit"),
+    ("d4 d5 c4 e6", "Queen's Gambit Declined"),
+    ("d4 d5 c4 dxc4", "Queen's Gambit Accepted"),
+    ("d4 d5 c4 c6", "Slav Defense"),
+    ("d4 Nf6 c4 g6", "King's Indian Defense"),
+    ("d4 Nf6 c4 e6 Nc3 Bb4", "Nimzo-Indian Defense"),
+    ("c4", "English Opening"),
+    ("Nf3", "Reti Opening"),
+];
+
+/// Names the opening reached by `moves` from the starting position.
+///
+/// The moves are replayed to SAN and matched against a small table; the
+/// longest matching prefix wins. Replay stops at the first illegal move.
+pub fn classify_opening(moves: &[Move]) -> Option<String> {
+    let mut game = GameState::starting_position();
+    let mut played = Vec::new();
+    for mv in moves {
+        let Some(legal) = find_legal_move(&game, mv) else {
+            break;
+        };
+        played.push(legal.to_san(&game).trim_end_matches(['+', '#']).to_string());
+        game.make_move(&legal);
+    }
+
+    OPENINGS
+        .iter()
+        .map(|&(line, name)| (line.split_whitespace().collect::<Vec<_>>(), name))
+        .filter(|(line, _)| {
+            line.len() <= played.len() && line.iter().zip(&played).all(|(a, b)| a == b)
+        })
+        .max_by_key(|(line, _)| line.len())
+        .map(|(_, name)| name.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn moves(uci: &str) -> Vec<Move> {
+        uci.split_whitespace()
+            .map(|m| Move::from_uci(m).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_ruy_lopez() {
+        let line = moves("e2e4 e7e5 g1f3 b8c6 f1b5");
+        assert_eq!(classify_opening(&line), Some("Ruy Lopez".to_string()));
+
+        // Longer games keep the most specific name
+        let longer = moves("e2e4 e7e5 g1f3 b8c6 f1b5 a7a6 b5a4");
+        assert_eq!(classify_opening(&longer), Some("Ruy Lopez".to_string()));
+    }
+
+    #[test]
+    fn test_prefix_fallback() {
+        assert_eq!(
+            classify_opening(&moves("e2e4 c7c5 g1f3")),
+            Some("Sicilian Defense".to_string())
+        );
+        assert_eq!(
+            classify_opening(&moves("d2d4 d7d5 c2c4 d5c4")),
+            Some("Queen's Gambit Accepted".to_string())
+        );
+        assert_eq!(classify_opening(&moves("a2a3")), None);
+        assert_eq!(classify_opening(&[]), None);
+    }
+}
@@ -0,0 +1,184 @@
+//! Strategic plan suggestions derived from positional features.
+
+use crate::core::{Color, Coord, GameState, PieceType, StandardBoard};
+use crate::eval::space;
+use crate::threats::king_attack_weight;
+
+/// King-attack weight above which a pawn storm is suggested.
+const KING_ATTACK_THRESHOLD: i32 = 8;
+
+/// Space lead (in squares) needed before suggesting to keep the bind.
+const SPACE_MARGIN: i32 = 4;
+
+/// Files a to c, where a minority attack is played.
+const QUEENSIDE_FILES: std::ops::RangeInclusive<u8> = 0..=2;
+
+/// Returns the coordinates of `color`'s pieces of type `piece_type`.
+fn pieces_of(game: &GameState, color: Color, piece_type: PieceType) -> Vec<Coord> {
+    game.board()
+        .pieces()
+        .filter(|(_, p)| p.color == color && p.piece_type == piece_type)
+        .map(|(coord, _)| coord)
+        .collect()
+}
+
+/// Distance of a file from the center, used to prefer central files.
+fn center_distance(file: u8) -> u8 {
+    (2 * file).abs_diff(StandardBoard::WIDTH - 1)
+}
+
+/// Suggests a pawn storm when `color` already bears down on the enemy king.
+fn king_attack_plan(game: &GameState, color: Color) -> Option<String> {
+    if king_attack_weight(game, color) < KING_ATTACK_THRESHOLD {
+        return None;
+    }
+    let king = game.board().find_king(color.opposite())?;
+    let wing = if king.file >= StandardBoard::WIDTH / 2 {
+        "kingside"
+    } else {
+        "queenside"
+    };
+    Some(format!("Advance the {} pawns to attack the king", wing))
+}
+
+/// Suggests using the most central file that has no pawns on it.
+fn open_file_plan(game: &GameState, color: Color) -> Option<String> {
+    let pawns: Vec<Coord> = [Color::White, Color::Black]
+        .into_iter()
+        .flat_map(|c| pieces_of(game, c, PieceType::Pawn))
+        .collect();
+    let file = (0..StandardBoard::WIDTH)
+        .filter(|&f| pawns.iter().all(|p| p.file != f))
+        .min_by_key(|&f| center_distance(f))?;
+
+    let rooks = pieces_of(game, color, PieceType::Rook);
+    let name = Coord::new(file, 0).file_char();
+    match rooks.len() {
+        0 => None,
+        1 if rooks[0].file == file => None,
+        1 => Some(format!("Place a rook on the open {}-file", name)),
+        _ => Some(format!("Double rooks on the {}-file", name)),
+    }
+}
+
+/// Returns true if an enemy pawn could ever attack `coord`.
+fn can_be_chased(game: &GameState, coord: Coord, color: Color) -> bool {
+    pieces_of(game, color.opposite(), PieceType::Pawn)
+        .iter()
+        .any(|p| {
+            p.file.abs_diff(coord.file) == 1
+                && match color {
+                    Color::White => p.rank > coord.rank,
+                    Color::Black => p.rank < coord.rank,
+                }
+        })
+}
+
+/// Suggests a knight outpost: an advanced, pawn-protected square that no
+/// enemy pawn can challenge.
+fn outpost_plan(game: &GameState, color: Color) -> Option<String> {
+    if pieces_of(game, color, PieceType::Knight).is_empty() {
+        return None;
+    }
+    let pawns = pieces_of(game, color, PieceType::Pawn);
+    let behind: isize = match color {
+        Color::White => -1,
+        Color::Black => 1,
+    };
+    let outpost = StandardBoard::all_coords()
+        .filter(|c| {
+            let relative = match color {
+                Color::White => c.rank,
+                Color::Black => StandardBoard::HEIGHT - 1 - c.rank,
+            };
+            (4..=5).contains(&relative)
+        })
+        .filter(|c| game.board().piece_at(c).is_none())
+        .filter(|c| {
+            pawns.iter().any(|p| {
+                p.file.abs_diff(c.file) == 1 && p.rank as isize == c.rank as isize + behind
+            })
+        })
+        .filter(|c| !can_be_chased(game, *c, color))
+        .min_by_key(|c| center_distance(c.file))?;
+    Some(format!("Plant a knight on the outpost at {}", outpost))
+}
+
+/// Suggests a minority attack when `color` has fewer queenside pawns.
+fn minority_attack_plan(game: &GameState, color: Color) -> Option<String> {
+    let queenside = |c: Color| {
+        pieces_of(game, c, PieceType::Pawn)
+            .iter()
+            .filter(|p| QUEENSIDE_FILES.contains(&p.file))
+            .count()
+    };
+    let ours = queenside(color);
+    (ours > 0 && ours < queenside(color.opposite()))
+        .then(|| "Launch a minority attack on the queenside".to_string())
+}
+
+/// Suggests keeping the bind when `color` controls clearly more space.
+fn space_plan(game: &GameState, color: Color) -> Option<String> {
+    (space(game, color) >= space(game, color.opposite()) + SPACE_MARGIN)
+        .then(|| "Keep the space advantage and avoid freeing exchanges".to_string())
+}
+
+/// Suggests strategic plans for `color`, most urgent first.
+///
+/// Each suggestion comes from one detected feature: pressure on the enemy
+/// king, an open file for the rooks, a knight outpost, a queenside pawn
+/// minority, or a space advantage.
+pub fn suggest_plan(game: &GameState, color: Color) -> Vec<String> {
+    [
+        king_attack_plan(game, color),
+        open_file_plan(game, color),
+        outpost_plan(game, color),
+        minority_attack_plan(game, color),
+        space_plan(game, color),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_no_plans_at_start() {
+        let game = GameState::starting_position();
+        assert!(suggest_plan(&game, Color::White).is_empty());
+        assert!(suggest_plan(&game, Color::Black).is_empty());
+    }
+
+    #[test]
+    fn test_double_rooks_on_open_file() {
+        // Only the d-file is open; White is castled and developed
+        let game = GameState::from_fen(
+            "r1bqkb1r/ppp2ppp/2n2n2/4p3/2B1P3/2N2N2/PPP2PPP/R2Q1RK1 w kq - 0 1",
+        )
+        .unwrap();
+        let plans = suggest_plan(&game, Color::White);
+        assert!(plans.contains(&"Double rooks on the d-file".to_string()));
+    }
+
+    #[test]
+    fn test_knight_outpost() {
+        // d5 is guarded by e4 and Black has no c- or e-pawn to chase a knight
+        let game = GameState::from_fen("4k3/pp3ppp/3p4/8/4P3/2N5/PP3PPP/4K3 w - - 0 1").unwrap();
+        let plans = suggest_plan(&game, Color::White);
+        assert!(plans.contains(&"Plant a knight on the outpost at d5".to_string()));
+    }
+
+    #[test]
+    fn test_minority_attack() {
+        // Carlsbad structure: White's a- and b-pawns against a6, b7, c6
+        let game =
+            GameState::from_fen("r4rk1/1p3ppp/p1p5/3p4/3P4/4P3/PP3PPP/R4RK1 w - - 0 1").unwrap();
+        let plans = suggest_plan(&game, Color::White);
+        assert!(plans.contains(&"Launch a minority attack on the queenside".to_string()));
+        assert!(!suggest_plan(&game, Color::Black)
+            .contains(&"Launch a minority attack on the queenside".to_string()));
+    }
+}
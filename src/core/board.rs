@@ -14,6 +14,10 @@ pub struct Board {
     white_pieces: Bitboard64,
     /// Bitboard for black pieces
     black_pieces: Bitboard64,
+    /// Bitboards for each piece type (both colors), indexed by `PieceType`
+    piece_types: [Bitboard64; 6],
+    /// Number of pieces, indexed by color then piece type
+    counts: [[u8; 6]; 2],
 }
 
 impl Board {
@@ -24,6 +28,8 @@ impl Board {
             occupied: Bitboard64::EMPTY,
             white_pieces: Bitboard64::EMPTY,
             black_pieces: Bitboard64::EMPTY,
+            piece_types: [Bitboard64::EMPTY; 6],
+            counts: [[0; 6]; 2],
         }
     }
 
@@ -33,10 +39,13 @@ impl Board {
         self.squares[index]
     }
 
-    /// Places a piece on the board.
+    /// Places a piece on the board, replacing any piece already there.
     pub fn set_piece(&mut self, coord: &Coord, piece: Piece) {
         if let Some(index) = StandardBoard::to_index(coord) {
+            self.remove_piece(coord);
             self.squares[index] = Some(piece);
+            self.counts[piece.color as usize][piece.piece_type as usize] += 1;
+            self.piece_types[piece.piece_type as usize].set(index);
             self.occupied.set(index);
             match piece.color {
                 Color::White => self.white_pieces.set(index),
@@ -51,6 +60,8 @@ impl Board {
             let piece = self.squares[index];
             if let Some(p) = piece {
                 self.squares[index] = None;
+                self.counts[p.color as usize][p.piece_type as usize] -= 1;
+                self.piece_types[p.piece_type as usize].clear(index);
                 self.occupied.clear(index);
                 match p.color {
                     Color::White => self.white_pieces.clear(index),
@@ -78,6 +89,17 @@ impl Board {
         self.occupied
     }
 
+    /// Returns the occupied squares with the piece on `coord` removed.
+    ///
+    /// Used for x-ray analysis, where sliders must see through a piece.
+    pub fn occupied_without(&self, coord: &Coord) -> Bitboard64 {
+        let mut occupied = self.occupied;
+        if let Some(sq) = StandardBoard::to_index(coord) {
+            occupied.clear(sq);
+        }
+        occupied
+    }
+
     /// Returns the bitboard of pieces for a given color.
     pub fn pieces_of_color(&self, color: Color) -> Bitboard64 {
         match color {
@@ -86,16 +108,40 @@ impl Board {
         }
     }
 
+    /// Returns the squares holding a piece of `piece_type`, of either color.
+    pub fn pieces_of_type(&self, piece_type: PieceType) -> Bitboard64 {
+        self.piece_types[piece_type as usize]
+    }
+
+    /// Returns the squares holding `color`'s pieces of `piece_type`.
+    pub fn pieces_of(&self, color: Color, piece_type: PieceType) -> Bitboard64 {
+        self.pieces_of_type(piece_type) & self.pieces_of_color(color)
+    }
+
+    /// Returns how many pieces of `piece_type` `color` has.
+    pub fn count(&self, color: Color, piece_type: PieceType) -> u8 {
+        self.counts[color as usize][piece_type as usize]
+    }
+
+    /// Returns the total material of `color` in centipawns.
+    pub fn material(&self, color: Color) -> i32 {
+        [
+            PieceType::Pawn,
+            PieceType::Knight,
+            PieceType::Bishop,
+            PieceType::Rook,
+            PieceType::Queen,
+        ]
+        .iter()
+        .map(|&pt| self.count(color, pt) as i32 * pt.value())
+        .sum()
+    }
+
     /// Finds the king of the given color.
     pub fn find_king(&self, color: Color) -> Option<Coord> {
-        for sq in 0..64 {
-            if let Some(piece) = self.squares[sq] {
-                if piece.color == color && piece.piece_type == PieceType::King {
-                    return StandardBoard::from_index(sq);
-                }
-            }
-        }
-        None
+        self.pieces_of(color, PieceType::King)
+            .lsb()
+            .and_then(StandardBoard::from_index)
     }
 
     /// Returns an iterator over all pieces on the board.
@@ -127,6 +173,54 @@ impl Board {
         result.push_str("  a b c d e f g h\n");
         result
     }
+
+    /// Returns the board drawn with Unicode chess glyphs, White at the bottom.
+    pub fn to_unicode(&self) -> String {
+        self.render(|piece| piece.to_unicode(), false)
+    }
+
+    /// Returns an ASCII drawing from Black's side: rank 1 at the top and
+    /// files reversed.
+    pub fn to_ascii_flipped(&self) -> String {
+        self.render(|piece| piece.to_char(), true)
+    }
+
+    /// Returns a Unicode drawing from Black's side.
+    pub fn to_unicode_flipped(&self) -> String {
+        self.render(|piece| piece.to_unicode(), true)
+    }
+
+    /// Draws the board with rank labels on both sides and file labels above
+    /// and below.
+    fn render(&self, glyph: impl Fn(Piece) -> char, flipped: bool) -> String {
+        let ranks: Vec<u8> = if flipped {
+            (0..8).collect()
+        } else {
+            (0..8).rev().collect()
+        };
+        let files: Vec<u8> = if flipped {
+            (0..8).rev().collect()
+        } else {
+            (0..8).collect()
+        };
+        let labels: String = files
+            .iter()
+            .map(|&f| format!("{} ", Coord::new(f, 0).file_char()))
+            .collect();
+        let labels = format!("  {}\n", labels.trim_end());
+
+        let mut result = labels.clone();
+        for &rank in &ranks {
+            result.push_str(&format!("{} ", rank + 1));
+            for &file in &files {
+                result.push(self.piece_at(&Coord::new(file, rank)).map_or('.', &glyph));
+                result.push(' ');
+            }
+            result.push_str(&format!("{}\n", rank + 1));
+        }
+        result.push_str(&labels);
+        result
+    }
 }
 
 impl Default for Board {
@@ -202,6 +296,104 @@ mod tests {
         assert_eq!(board.occupied().popcount(), 1);
     }
 
+    #[test]
+    fn test_counts_track_moves_and_captures() {
+        let scan = |board: &Board, color: Color, pt: PieceType| {
+            board
+                .pieces()
+                .filter(|(_, p)| p.color == color && p.piece_type == pt)
+                .count() as u8
+        };
+        let matches_scan = |board: &Board| {
+            [Color::White, Color::Black].iter().all(|&color| {
+                [
+                    PieceType::Pawn,
+                    PieceType::Knight,
+                    PieceType::Bishop,
+                    PieceType::Rook,
+                    PieceType::Queen,
+                    PieceType::King,
+                ]
+                .iter()
+                .all(|&pt| board.count(color, pt) == scan(board, color, pt))
+            })
+        };
+
+        let mut game = crate::core::GameState::starting_position();
+        assert_eq!(game.board().count(Color::White, PieceType::Pawn), 8);
+        assert_eq!(game.board().material(Color::White), 4000);
+
+        game.make_move(&crate::core::Move::from_uci("e2e4").unwrap());
+        assert_eq!(game.board().count(Color::White, PieceType::Pawn), 8);
+        assert!(matches_scan(game.board()));
+
+        for uci in ["d7d5", "e4d5"] {
+            game.make_move(&crate::core::Move::from_uci(uci).unwrap());
+        }
+        assert_eq!(game.board().count(Color::Black, PieceType::Pawn), 7);
+        assert_eq!(game.board().material(Color::Black), 3900);
+        assert!(matches_scan(game.board()));
+
+        // Replacing a piece in place keeps both colors' counts right
+        let mut board = game.board().clone();
+        board.set_piece(
+            &Coord::new(3, 4),
+            Piece::new(PieceType::Queen, Color::Black),
+        );
+        assert_eq!(board.count(Color::White, PieceType::Pawn), 7);
+        assert!(!board.pieces_of_color(Color::White).get(35));
+        assert!(matches_scan(&board));
+    }
+
+    #[test]
+    fn test_piece_type_bitboards() {
+        let game = crate::core::GameState::starting_position();
+        let board = game.board();
+        let knights = board.pieces_of_type(PieceType::Knight);
+        assert_eq!(knights.popcount(), 4);
+        assert_eq!(knights, Bitboard64::from_squares(&[1, 6, 57, 62]));
+        assert_eq!(
+            board.pieces_of(Color::Black, PieceType::Knight),
+            Bitboard64::from_squares(&[57, 62])
+        );
+        assert_eq!(
+            board.pieces_of(Color::White, PieceType::Pawn),
+            Bitboard64::RANK_2
+        );
+
+        let mut board = board.clone();
+        board.move_piece(&Coord::new(1, 0), &Coord::new(2, 2));
+        assert_eq!(
+            board.pieces_of(Color::White, PieceType::Knight),
+            Bitboard64::from_squares(&[6, 18])
+        );
+        board.set_piece(
+            &Coord::new(2, 2),
+            Piece::new(PieceType::Bishop, Color::Black),
+        );
+        assert_eq!(board.pieces_of_type(PieceType::Knight).popcount(), 3);
+        assert!(board.pieces_of(Color::Black, PieceType::Bishop).get(18));
+    }
+
+    #[test]
+    fn test_flipped_rendering() {
+        let game = crate::core::GameState::starting_position();
+        let flipped = game.board().to_ascii_flipped();
+        let lines: Vec<&str> = flipped.lines().collect();
+        assert_eq!(lines[0], "  h g f e d c b a");
+        assert_eq!(lines[1], "1 R N B K Q B N R 1");
+        assert_eq!(lines[8], "8 r n b k q b n r 8");
+        assert_eq!(lines[9], lines[0]);
+
+        let unicode = game.board().to_unicode_flipped();
+        let last_rank = unicode.lines().nth(8).unwrap();
+        assert!(last_rank.starts_with("8 ♜") && last_rank.ends_with("♜ 8"));
+
+        let upright = game.board().to_unicode();
+        assert_eq!(upright.lines().nth(1), Some("8 ♜ ♞ ♝ ♛ ♚ ♝ ♞ ♜ 8"));
+        assert_eq!(upright.lines().nth(8), Some("1 ♖ ♘ ♗ ♕ ♔ ♗ ♘ ♖ 1"));
+    }
+
     #[test]
     fn test_find_king() {
         let mut board = Board::empty();
@@ -224,4 +416,25 @@ mod tests {
         let pieces: Vec<_> = board.pieces().collect();
         assert_eq!(pieces.len(), 2);
     }
+
+    #[test]
+    fn test_occupied_without() {
+        use crate::movegen::rook_attacks;
+
+        let mut board = Board::empty();
+        let a1 = Coord::new(0, 0);
+        let a4 = Coord::new(0, 3);
+        board.set_piece(&a1, Piece::new(PieceType::Rook, Color::White));
+        board.set_piece(&a4, Piece::new(PieceType::Pawn, Color::White));
+
+        let blocked = rook_attacks(0, board.occupied());
+        assert!(blocked.get(24));
+        assert!(!blocked.get(32));
+
+        let occupied = board.occupied_without(&a4);
+        assert_eq!(occupied.popcount(), 1);
+        let xray = rook_attacks(0, occupied);
+        assert!(xray.get(32));
+        assert!(xray.get(56));
+    }
 }
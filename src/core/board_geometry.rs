@@ -136,6 +136,50 @@ impl<const WIDTH: u8, const HEIGHT: u8> BoardGeometry<WIDTH, HEIGHT> {
         let to_top = HEIGHT - 1 - coord.rank;
         Some(to_left.min(to_right).min(to_bottom).min(to_top))
     }
+
+    /// Returns the valid coordinates within Chebyshev distance `radius` of
+    /// `center`, excluding the center itself.
+    pub fn neighbors(center: Coord, radius: u8) -> impl Iterator<Item = Coord> {
+        let lo_file = center.file.saturating_sub(radius);
+        let lo_rank = center.rank.saturating_sub(radius);
+        let hi_file = center
+            .file
+            .saturating_add(radius)
+            .min(WIDTH.saturating_sub(1));
+        let hi_rank = center
+            .rank
+            .saturating_add(radius)
+            .min(HEIGHT.saturating_sub(1));
+
+        (lo_rank..=hi_rank)
+            .flat_map(move |rank| (lo_file..=hi_file).map(move |file| Coord::new(file, rank)))
+            .filter(move |coord| *coord != center && Self::is_valid(coord))
+    }
+
+    /// Returns the squares strictly between `a` and `b`.
+    ///
+    /// Works on any board size by stepping coordinates. Returns an empty
+    /// vector when the squares do not share a rank, file, or diagonal, or
+    /// when either lies off the board.
+    pub fn between(a: &Coord, b: &Coord) -> Vec<Coord> {
+        let delta = a.delta_to(*b);
+        let aligned = delta.dx == 0 || delta.dy == 0 || delta.dx.abs() == delta.dy.abs();
+        if !aligned || !Self::is_valid(a) || !Self::is_valid(b) {
+            return Vec::new();
+        }
+
+        let step = delta.normalize();
+        let mut squares = Vec::new();
+        let mut current = *a;
+        while let Some(next) = Self::offset(&current, step) {
+            if next == *b {
+                break;
+            }
+            squares.push(next);
+            current = next;
+        }
+        squares
+    }
 }
 
 /// Type alias for standard 8x8 chess board.
@@ -310,6 +354,29 @@ mod tests {
         assert_eq!(Board8x8::distance_to_edge(&Coord::new(4, 4)), Some(3)); // e5
     }
 
+    #[test]
+    fn test_between_10x10() {
+        // Rank: a1..j1
+        let rank = Board10x10::between(&Coord::new(0, 0), &Coord::new(9, 0));
+        assert_eq!(rank.len(), 8);
+        assert_eq!(rank[0], Coord::new(1, 0));
+        assert_eq!(rank[7], Coord::new(8, 0));
+
+        // File: j10 down to j7
+        let file = Board10x10::between(&Coord::new(9, 9), &Coord::new(9, 6));
+        assert_eq!(file, vec![Coord::new(9, 8), Coord::new(9, 7)]);
+
+        // Diagonal: a1..j10
+        let diagonal = Board10x10::between(&Coord::new(0, 0), &Coord::new(9, 9));
+        assert_eq!(diagonal.len(), 8);
+        assert!(diagonal.iter().all(|c| c.file == c.rank));
+
+        // Adjacent and unaligned squares have nothing between them
+        assert!(Board10x10::between(&Coord::new(4, 4), &Coord::new(5, 5)).is_empty());
+        assert!(Board10x10::between(&Coord::new(0, 0), &Coord::new(1, 2)).is_empty());
+        assert!(Board8x8::between(&Coord::new(0, 0), &Coord::new(9, 9)).is_empty());
+    }
+
     #[test]
     fn test_standard_board_constants() {
         assert_eq!(StandardBoard::A1, Coord::new(0, 0));
@@ -317,4 +384,14 @@ mod tests {
         assert_eq!(StandardBoard::H8, Coord::new(7, 7));
         assert_eq!(StandardBoard::E4, Coord::new(4, 3));
     }
+
+    #[test]
+    fn test_neighbors() {
+        assert_eq!(Board8x8::neighbors(Coord::new(4, 3), 1).count(), 8); // e4
+        assert_eq!(Board8x8::neighbors(Coord::new(0, 0), 1).count(), 3); // a1
+        assert_eq!(Board8x8::neighbors(Coord::new(4, 3), 2).count(), 24);
+        assert_eq!(Board6x6::neighbors(Coord::new(5, 5), 2).count(), 8);
+        assert!(Board8x8::neighbors(Coord::new(0, 0), 1)
+            .all(|c| c.chebyshev_distance(&Coord::new(0, 0)) == 1));
+    }
 }
@@ -1,5 +1,6 @@
 /// Represents a player's color in chess.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Color {
     White,
     Black,
@@ -16,6 +17,24 @@ impl Color {
     }
 }
 
+/// Color of a square on the board.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SquareColor {
+    Light,
+    Dark,
+}
+
+impl SquareColor {
+    /// Returns the other square color.
+    #[inline]
+    pub fn opposite(self) -> Self {
+        match self {
+            SquareColor::Light => SquareColor::Dark,
+            SquareColor::Dark => SquareColor::Light,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -30,4 +49,10 @@ mod tests {
     fn test_double_opposite() {
         assert_eq!(Color::White.opposite().opposite(), Color::White);
     }
+
+    #[test]
+    fn test_square_color_opposite() {
+        assert_eq!(SquareColor::Light.opposite(), SquareColor::Dark);
+        assert_eq!(SquareColor::Dark.opposite(), SquareColor::Light);
+    }
 }
@@ -1,5 +1,46 @@
-use super::Delta;
+use super::{Delta, SquareColor, StandardBoard};
+use std::cmp::Ordering;
+use std::collections::VecDeque;
 use std::fmt;
+use std::sync::OnceLock;
+
+/// Knight moves needed between every pair of squares on an 8x8 board.
+static KNIGHT_DISTANCE: OnceLock<[[u8; 64]; 64]> = OnceLock::new();
+
+/// Fills the knight distance table with a breadth-first search from each square.
+fn init_knight_distance() -> [[u8; 64]; 64] {
+    const JUMPS: [(i8, i8); 8] = [
+        (1, 2),
+        (2, 1),
+        (2, -1),
+        (1, -2),
+        (-1, -2),
+        (-2, -1),
+        (-2, 1),
+        (-1, 2),
+    ];
+    let mut table = [[u8::MAX; 64]; 64];
+
+    for (start, row) in table.iter_mut().enumerate() {
+        row[start] = 0;
+        let mut queue = VecDeque::from([start]);
+        while let Some(index) = queue.pop_front() {
+            let coord = StandardBoard::from_index(index).expect("index is on the board");
+            for (dx, dy) in JUMPS {
+                let next = StandardBoard::offset(&coord, Delta::new(dx, dy))
+                    .and_then(|c| StandardBoard::to_index(&c));
+                if let Some(next) = next {
+                    if row[next] == u8::MAX {
+                        row[next] = row[index] + 1;
+                        queue.push_back(next);
+                    }
+                }
+            }
+        }
+    }
+
+    table
+}
 
 /// A coordinate on a chess board, independent of board size.
 ///
@@ -7,6 +48,7 @@ use std::fmt;
 /// without any assumptions about the board dimensions.
 /// Validity checking is done by `BoardGeometry`.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Coord {
     /// File (column), 0-indexed from left (a=0, b=1, ...)
     pub file: u8,
@@ -49,6 +91,27 @@ impl Coord {
         )
     }
 
+    /// Returns the Chebyshev (king-move) distance to another coordinate.
+    pub fn chebyshev_distance(&self, other: &Coord) -> u8 {
+        self.delta_to(*other).chebyshev_distance()
+    }
+
+    /// Returns the Manhattan distance to another coordinate.
+    pub fn manhattan_distance(&self, other: &Coord) -> u8 {
+        self.delta_to(*other).manhattan_distance()
+    }
+
+    /// Returns the minimum number of knight moves to reach another coordinate
+    /// on an 8x8 board.
+    ///
+    /// # Panics
+    /// Panics if either coordinate lies off the 8x8 board.
+    pub fn knight_distance(&self, other: &Coord) -> u8 {
+        let from = StandardBoard::to_index(self).expect("coordinate must be on an 8x8 board");
+        let to = StandardBoard::to_index(other).expect("coordinate must be on an 8x8 board");
+        KNIGHT_DISTANCE.get_or_init(init_knight_distance)[from][to]
+    }
+
     /// Returns the file as a character ('a', 'b', ..., 'z').
     /// For files >= 26, returns '?' (use `file_string()` for large boards).
     pub fn file_char(&self) -> char {
@@ -114,6 +177,22 @@ impl Coord {
         Some(Self::new(file, rank))
     }
 
+    /// Returns the color of this square (a1 is dark, h1 is light).
+    #[inline]
+    pub fn square_color(&self) -> SquareColor {
+        if (self.file ^ self.rank) & 1 == 0 {
+            SquareColor::Dark
+        } else {
+            SquareColor::Light
+        }
+    }
+
+    /// Returns true if this is a light square (`file + rank` odd, so a1 is dark).
+    #[inline]
+    pub fn is_light(&self) -> bool {
+        self.square_color() == SquareColor::Light
+    }
+
     /// Returns algebraic notation (e.g., "e4", "a10").
     pub fn to_algebraic(&self) -> String {
         format!("{}{}", self.file_string(), self.rank_string())
@@ -126,10 +205,34 @@ impl fmt::Display for Coord {
     }
 }
 
+/// Orders coordinates by square index: rank first, then file (a1 < h1 < a2).
+impl Ord for Coord {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.rank, self.file).cmp(&(other.rank, other.file))
+    }
+}
+
+impl PartialOrd for Coord {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_ordering_by_index() {
+        let mut coords = [Coord::new(0, 1), Coord::new(7, 0), Coord::new(0, 0)];
+        coords.sort();
+        assert_eq!(
+            coords,
+            [Coord::new(0, 0), Coord::new(7, 0), Coord::new(0, 1)]
+        );
+        assert!(Coord::new(3, 3) < Coord::new(4, 3));
+    }
+
     #[test]
     fn test_new() {
         let c = Coord::new(4, 3);
@@ -200,4 +303,38 @@ mod tests {
         assert_eq!(format!("{}", Coord::new(4, 3)), "e4");
         assert_eq!(format!("{}", Coord::new(9, 9)), "j10");
     }
+
+    #[test]
+    fn test_square_color() {
+        assert_eq!(Coord::new(0, 0).square_color(), SquareColor::Dark); // a1
+        assert_eq!(Coord::new(7, 0).square_color(), SquareColor::Light); // h1
+        assert_eq!(Coord::new(0, 7).square_color(), SquareColor::Light); // a8
+        assert_eq!(Coord::new(7, 7).square_color(), SquareColor::Dark); // h8
+        assert_eq!(Coord::new(3, 0).square_color(), SquareColor::Light); // d1
+    }
+
+    #[test]
+    fn test_is_light() {
+        assert!(!Coord::new(0, 0).is_light()); // a1
+        assert!(!Coord::new(7, 7).is_light()); // h8
+        assert!(Coord::new(7, 0).is_light()); // h1
+        assert!(Coord::new(0, 7).is_light()); // a8
+
+        // c1 and f1 bishops start on opposite colors
+        assert_ne!(Coord::new(2, 0).is_light(), Coord::new(5, 0).is_light());
+        assert_ne!(Coord::new(2, 7).is_light(), Coord::new(5, 7).is_light());
+    }
+
+    #[test]
+    fn test_distances() {
+        let a1 = Coord::new(0, 0);
+        let e4 = Coord::new(4, 3);
+        assert_eq!(a1.chebyshev_distance(&e4), 4);
+        assert_eq!(e4.manhattan_distance(&a1), 7);
+
+        assert_eq!(a1.knight_distance(&Coord::new(1, 2)), 1); // b3
+        assert_eq!(a1.knight_distance(&a1), 0);
+        assert_eq!(a1.knight_distance(&Coord::new(1, 1)), 4); // b2
+        assert_eq!(a1.knight_distance(&Coord::new(7, 7)), 6); // h8
+    }
 }
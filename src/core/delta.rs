@@ -3,6 +3,7 @@ use std::ops::{Add, Mul, Neg, Sub};
 /// A 2D vector representing movement on the chess board.
 /// Used for piece movements, directions, and offsets.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Delta {
     pub dx: i8,
     pub dy: i8,
@@ -1,8 +1,14 @@
-use super::{Board, Color, Coord, Move, MoveFlags, Piece, PieceType, StandardBoard};
+use super::{zobrist, Board, Color, Coord, Move, MoveFlags, Piece, PieceType, StandardBoard};
+use crate::movegen::{
+    attackers_to, find_legal_move, generate_legal_moves, is_square_attacked, king_attacks,
+    pawn_attacks, piece_attacks, MoveGenerator,
+};
 use std::fmt;
+use std::str::FromStr;
 
 /// Castling rights for a player.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CastlingRights {
     /// Can castle kingside (O-O)
     pub kingside: bool,
@@ -27,8 +33,104 @@ impl CastlingRights {
     pub fn any(&self) -> bool {
         self.kingside || self.queenside
     }
+
+    /// Returns true if both castling rights have been lost.
+    pub fn is_none(&self) -> bool {
+        !self.any()
+    }
+
+    /// Returns true if the right for the given castling move is retained.
+    ///
+    /// Non-castling flags always return false.
+    pub fn allows(&self, flags: MoveFlags) -> bool {
+        match flags {
+            MoveFlags::CastleKingside => self.kingside,
+            MoveFlags::CastleQueenside => self.queenside,
+            _ => false,
+        }
+    }
+}
+
+/// The board changes caused by a single move.
+///
+/// Returned by [`GameState::make_move`] so callers can update derived
+/// structures (attack graphs, hashes, ...) without diffing whole boards.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MoveOutcome {
+    /// The move that was played.
+    pub mv: Move,
+    /// The piece that moved (before promotion), if the origin was occupied.
+    pub moved: Option<Piece>,
+    /// The captured piece and the square it was removed from.
+    ///
+    /// For en passant this is not the move's destination square.
+    pub captured: Option<(Coord, Piece)>,
+    /// The rook's (from, to) squares for castling moves.
+    pub rook_move: Option<(Coord, Coord)>,
+}
+
+impl MoveOutcome {
+    /// Returns every square whose occupant changed.
+    pub fn changed_squares(&self) -> Vec<Coord> {
+        let mut squares = vec![self.mv.from, self.mv.to];
+        if let Some((coord, _)) = self.captured {
+            if coord != self.mv.to {
+                squares.push(coord);
+            }
+        }
+        if let Some((rook_from, rook_to)) = self.rook_move {
+            // In Chess960 the move's destination is the rook, not the king
+            let king_to = Coord::new(if rook_to.file == 5 { 6 } else { 2 }, rook_to.rank);
+            for square in [rook_from, rook_to, king_to] {
+                if !squares.contains(&square) {
+                    squares.push(square);
+                }
+            }
+        }
+        squares
+    }
+}
+
+/// Whether the game is over, and if so how.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GameStatus {
+    /// The side to move has legal moves and no draw applies.
+    Ongoing,
+    /// The side to move is in check with no legal moves.
+    Checkmate,
+    /// The side to move is not in check but has no legal moves.
+    Stalemate,
+    /// Fifty moves by each side without a capture or pawn move.
+    DrawFiftyMove,
+    /// The position has occurred three times.
+    DrawRepetition,
+    /// Neither side can possibly mate.
+    DrawInsufficientMaterial,
+}
+
+/// Everything needed to take back a move with [`GameState::unmake_move`].
+///
+/// Returned by [`GameState::make_move`]. The board changes are kept in
+/// `outcome`; the rest is the state a move overwrites and cannot rederive.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct UndoInfo {
+    /// The board changes caused by the move, including any captured piece.
+    pub outcome: MoveOutcome,
+    /// White's castling rights before the move.
+    pub white_castling: CastlingRights,
+    /// Black's castling rights before the move.
+    pub black_castling: CastlingRights,
+    /// The en passant target square before the move.
+    pub en_passant: Option<Coord>,
+    /// The halfmove clock before the move.
+    pub halfmove_clock: u32,
+    /// The Zobrist hash before the move.
+    pub hash: u64,
 }
 
+/// Rook files for castling in standard chess: h-file kingside, a-file queenside.
+const STANDARD_ROOK_FILES: [[u8; 2]; 2] = [[7, 0], [7, 0]];
+
 /// Complete game state including board position and metadata.
 #[derive(Clone, Debug)]
 pub struct GameState {
@@ -40,12 +142,23 @@ pub struct GameState {
     white_castling: CastlingRights,
     /// Castling rights for black
     black_castling: CastlingRights,
+    /// Home files of the castling rooks, indexed `[color][kingside, queenside]`
+    castling_rook_files: [[u8; 2]; 2],
+    /// Whether castling follows Chess960 conventions (king-takes-rook moves)
+    chess960: bool,
     /// En passant target square (if a pawn just moved two squares)
     en_passant: Option<Coord>,
     /// Halfmove clock for 50-move rule
     halfmove_clock: u32,
     /// Fullmove number (starts at 1, incremented after Black's move)
     fullmove_number: u32,
+    /// Zobrist hash, kept in sync by make/unmake
+    hash: u64,
+    /// Hashes of the positions before each move made on this state.
+    ///
+    /// Only the last `halfmove_clock` entries can repeat the current position;
+    /// anything older lies before an irreversible move.
+    history: Vec<u64>,
 }
 
 impl GameState {
@@ -56,9 +169,13 @@ impl GameState {
             side_to_move: Color::White,
             white_castling: CastlingRights::NONE,
             black_castling: CastlingRights::NONE,
+            castling_rook_files: STANDARD_ROOK_FILES,
+            chess960: false,
             en_passant: None,
             halfmove_clock: 0,
             fullmove_number: 1,
+            hash: 0,
+            history: Vec::new(),
         }
     }
 
@@ -68,6 +185,60 @@ impl GameState {
             .expect("Starting position FEN should be valid")
     }
 
+    /// Creates the Chess960 starting position with the given Scharnagl number.
+    ///
+    /// Position 518 is the standard starting position. Castling rights refer
+    /// to the files the rooks actually start on.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `position_id` is not below 960.
+    pub fn chess960(position_id: u16) -> Self {
+        assert!(position_id < 960, "Chess960 position id out of range");
+        const KNIGHTS: [(usize, usize); 10] = [
+            (0, 1),
+            (0, 2),
+            (0, 3),
+            (0, 4),
+            (1, 2),
+            (1, 3),
+            (1, 4),
+            (2, 3),
+            (2, 4),
+            (3, 4),
+        ];
+
+        let mut rank = [None; 8];
+        let mut n = position_id as usize;
+        rank[2 * (n % 4) + 1] = Some('b');
+        n /= 4;
+        rank[2 * (n % 4)] = Some('b');
+        n /= 4;
+
+        let empty = |rank: &[Option<char>; 8]| -> Vec<usize> {
+            (0..8).filter(|&f| rank[f].is_none()).collect()
+        };
+        rank[empty(&rank)[n % 6]] = Some('q');
+        n /= 6;
+
+        let free = empty(&rank);
+        let (first, second) = KNIGHTS[n];
+        rank[free[first]] = Some('n');
+        rank[free[second]] = Some('n');
+
+        for (file, piece) in empty(&rank).into_iter().zip(['r', 'k', 'r']) {
+            rank[file] = Some(piece);
+        }
+
+        let black: String = rank.iter().flatten().collect();
+        let fen = format!(
+            "{}/pppppppp/8/8/8/8/PPPPPPPP/{} w KQkq - 0 1",
+            black,
+            black.to_uppercase()
+        );
+        Self::from_fen(&fen).expect("Chess960 FEN should be valid")
+    }
+
     /// Parses a FEN string into a GameState.
     ///
     /// FEN format: position side castling en_passant halfmove fullmove
@@ -78,39 +249,7 @@ impl GameState {
             return Err("FEN must have at least 4 parts".to_string());
         }
 
-        // Parse board position
-        let mut board = Board::empty();
-        let ranks: Vec<&str> = parts[0].split('/').collect();
-        if ranks.len() != 8 {
-            return Err("FEN board must have 8 ranks".to_string());
-        }
-
-        for (rank_idx, rank_str) in ranks.iter().enumerate() {
-            let rank = 7 - rank_idx; // FEN starts from rank 8
-            let mut file = 0;
-
-            for ch in rank_str.chars() {
-                if ch.is_ascii_digit() {
-                    // Empty squares
-                    let empty_count = ch.to_digit(10).unwrap() as u8;
-                    file += empty_count;
-                } else {
-                    // Piece
-                    if file >= 8 {
-                        return Err(format!("Rank {} has too many squares", rank + 1));
-                    }
-                    let piece = Piece::from_char(ch)
-                        .ok_or_else(|| format!("Invalid piece character: {}", ch))?;
-                    let coord = Coord::new(file, rank as u8);
-                    board.set_piece(&coord, piece);
-                    file += 1;
-                }
-            }
-
-            if file != 8 {
-                return Err(format!("Rank {} has {} squares, expected 8", rank + 1, file));
-            }
-        }
+        let board = Self::parse_placement(parts[0])?;
 
         // Parse side to move
         let side_to_move = match parts[1] {
@@ -119,16 +258,9 @@ impl GameState {
             _ => return Err(format!("Invalid side to move: {}", parts[1])),
         };
 
-        // Parse castling rights
-        let castling = parts[2];
-        let white_castling = CastlingRights {
-            kingside: castling.contains('K'),
-            queenside: castling.contains('Q'),
-        };
-        let black_castling = CastlingRights {
-            kingside: castling.contains('k'),
-            queenside: castling.contains('q'),
-        };
+        // Parse castling rights (standard, X-FEN, or Shredder-FEN)
+        let (castling, castling_rook_files, chess960) = Self::parse_castling(&board, parts[2])?;
+        let [white_castling, black_castling] = castling;
 
         // Parse en passant target
         let en_passant = if parts[3] == "-" {
@@ -158,15 +290,194 @@ impl GameState {
             1
         };
 
-        Ok(Self {
+        let mut game = Self {
             board,
             side_to_move,
             white_castling,
             black_castling,
+            castling_rook_files,
+            chess960,
             en_passant,
             halfmove_clock,
             fullmove_number,
-        })
+            hash: 0,
+            history: Vec::new(),
+        };
+        game.hash = zobrist::hash_position(&game);
+        Ok(game)
+    }
+
+    /// Parses the castling field of a FEN string.
+    ///
+    /// `KQkq` name the outermost rook on each wing (X-FEN), while file
+    /// letters such as `HAha` name the rook directly (Shredder-FEN). The
+    /// position counts as Chess960 if file letters are used or a right
+    /// refers to a king or rook off its standard square.
+    #[allow(clippy::type_complexity)]
+    fn parse_castling(
+        board: &Board,
+        field: &str,
+    ) -> Result<([CastlingRights; 2], [[u8; 2]; 2], bool), String> {
+        let mut rights = [CastlingRights::NONE; 2];
+        let mut files = STANDARD_ROOK_FILES;
+        let mut chess960 = false;
+        if field == "-" {
+            return Ok((rights, files, chess960));
+        }
+
+        for ch in field.chars() {
+            let color = if ch.is_ascii_uppercase() {
+                Color::White
+            } else {
+                Color::Black
+            };
+            let rank = if color == Color::White { 0 } else { 7 };
+            let king_file = board
+                .find_king(color)
+                .filter(|king| king.rank == rank)
+                .map_or(4, |king| king.file);
+            let rook = Piece::new(PieceType::Rook, color);
+            let outermost = |files: Vec<u8>| {
+                files
+                    .into_iter()
+                    .find(|&f| board.piece_at(&Coord::new(f, rank)) == Some(rook))
+            };
+
+            let (kingside, file) = match ch.to_ascii_lowercase() {
+                'k' => (
+                    true,
+                    outermost((king_file + 1..8).rev().collect()).unwrap_or(7),
+                ),
+                'q' => (false, outermost((0..king_file).collect()).unwrap_or(0)),
+                f @ 'a'..='h' => {
+                    chess960 = true;
+                    let file = f as u8 - b'a';
+                    (file > king_file, file)
+                }
+                _ => return Err(format!("Invalid castling rights: {}", field)),
+            };
+
+            let side = &mut rights[color as usize];
+            if kingside {
+                side.kingside = true;
+            } else {
+                side.queenside = true;
+            }
+            files[color as usize][usize::from(!kingside)] = file;
+            chess960 |= king_file != 4 || file != if kingside { 7 } else { 0 };
+        }
+
+        Ok((rights, files, chess960))
+    }
+
+    /// Parses a FEN string and rejects positions that cannot arise in a game.
+    ///
+    /// On top of [`GameState::from_fen`], this requires exactly one king per
+    /// side, kings that are not adjacent, and no pawns on the first or
+    /// eighth rank.
+    pub fn from_fen_validated(fen: &str) -> Result<Self, String> {
+        let game = Self::from_fen(fen)?;
+
+        let mut kings = [Vec::new(), Vec::new()];
+        for (coord, piece) in game.board.pieces() {
+            match piece.piece_type {
+                PieceType::King => kings[piece.color as usize].push(coord),
+                PieceType::Pawn if coord.rank == 0 || coord.rank == 7 => {
+                    return Err(format!("Pawn on back rank: {}", coord));
+                }
+                _ => {}
+            }
+        }
+
+        for color in [Color::White, Color::Black] {
+            let count = kings[color as usize].len();
+            if count != 1 {
+                return Err(format!(
+                    "{:?} must have exactly one king, found {}",
+                    color, count
+                ));
+            }
+        }
+        let (white, black) = (kings[0][0], kings[1][0]);
+        if white.chebyshev_distance(&black) <= 1 {
+            return Err(format!("Kings are adjacent: {} and {}", white, black));
+        }
+
+        Ok(game)
+    }
+
+    /// Parses the piece-placement field of a FEN string into a board.
+    fn parse_placement(placement: &str) -> Result<Board, String> {
+        let mut board = Board::empty();
+        let ranks: Vec<&str> = placement.split('/').collect();
+        if ranks.len() != 8 {
+            return Err("FEN board must have 8 ranks".to_string());
+        }
+
+        for (rank_idx, rank_str) in ranks.iter().enumerate() {
+            let rank = 7 - rank_idx; // FEN starts from rank 8
+            let mut file = 0;
+
+            for ch in rank_str.chars() {
+                if ch.is_ascii_digit() {
+                    // Empty squares
+                    let empty_count = ch.to_digit(10).unwrap() as u8;
+                    file += empty_count;
+                } else {
+                    // Piece
+                    if file >= 8 {
+                        return Err(format!("Rank {} has too many squares", rank + 1));
+                    }
+                    let piece = Piece::from_char(ch)
+                        .ok_or_else(|| format!("Invalid piece character: {}", ch))?;
+                    let coord = Coord::new(file, rank as u8);
+                    board.set_piece(&coord, piece);
+                    file += 1;
+                }
+            }
+
+            if file != 8 {
+                return Err(format!("Rank {} has {} squares, expected 8", rank + 1, file));
+            }
+        }
+
+        Ok(board)
+    }
+
+    /// Parses only the piece-placement field of a FEN string.
+    ///
+    /// Example: "8/8/8/8/4K3/8/8/4k3". The position has no castling rights
+    /// and no en passant square, which suits puzzle imports.
+    pub fn from_board_fen(placement: &str, side: Color) -> Result<Self, String> {
+        let mut game = Self::empty();
+        game.board = Self::parse_placement(placement.trim())?;
+        game.side_to_move = side;
+        game.hash = zobrist::hash_position(&game);
+        Ok(game)
+    }
+
+    /// Builds a position from a list of (square, piece character) placements.
+    ///
+    /// Example: `[("e1", 'K'), ("e8", 'k'), ("d1", 'Q')]`. The position has
+    /// no castling rights and no en passant square, which makes this handy
+    /// for setting up endgame studies in code.
+    pub fn from_placements(placements: &[(&str, char)], side: Color) -> Result<Self, String> {
+        let mut game = Self::empty();
+        game.side_to_move = side;
+
+        for &(square, ch) in placements {
+            let coord = StandardBoard::parse_algebraic(square)
+                .ok_or_else(|| format!("Invalid square: {}", square))?;
+            let piece =
+                Piece::from_char(ch).ok_or_else(|| format!("Invalid piece character: {}", ch))?;
+            if game.board.piece_at(&coord).is_some() {
+                return Err(format!("Square {} is occupied twice", square));
+            }
+            game.board.set_piece(&coord, piece);
+        }
+
+        game.hash = zobrist::hash_position(&game);
+        Ok(game)
     }
 
     /// Converts the game state to a FEN string.
@@ -206,17 +517,24 @@ impl GameState {
         // Castling rights
         fen.push(' ');
         let mut castling = String::new();
-        if self.white_castling.kingside {
-            castling.push('K');
-        }
-        if self.white_castling.queenside {
-            castling.push('Q');
-        }
-        if self.black_castling.kingside {
-            castling.push('k');
-        }
-        if self.black_castling.queenside {
-            castling.push('q');
+        for color in [Color::White, Color::Black] {
+            let rights = self.castling_rights(color);
+            for (kingside, allowed) in [(true, rights.kingside), (false, rights.queenside)] {
+                if !allowed {
+                    continue;
+                }
+                let ch = if self.chess960 {
+                    (b'a' + self.castling_rook_file(color, kingside)) as char
+                } else if kingside {
+                    'k'
+                } else {
+                    'q'
+                };
+                castling.push(match color {
+                    Color::White => ch.to_ascii_uppercase(),
+                    Color::Black => ch,
+                });
+            }
         }
         if castling.is_empty() {
             castling.push('-');
@@ -237,15 +555,66 @@ impl GameState {
         fen
     }
 
+    /// Returns the color-mirrored position.
+    ///
+    /// Ranks are reversed and piece colors swapped, along with side to move,
+    /// castling rights, and the en passant square. The result is the same
+    /// position seen from the other side of the board.
+    pub fn mirrored(&self) -> Self {
+        let mut board = Board::empty();
+        for (coord, piece) in self.board.pieces() {
+            let flipped = Coord::new(coord.file, 7 - coord.rank);
+            board.set_piece(
+                &flipped,
+                Piece::new(piece.piece_type, piece.color.opposite()),
+            );
+        }
+
+        let mut mirrored = Self {
+            board,
+            side_to_move: self.side_to_move.opposite(),
+            white_castling: self.black_castling,
+            black_castling: self.white_castling,
+            castling_rook_files: [self.castling_rook_files[1], self.castling_rook_files[0]],
+            chess960: self.chess960,
+            en_passant: self.en_passant.map(|ep| Coord::new(ep.file, 7 - ep.rank)),
+            halfmove_clock: self.halfmove_clock,
+            fullmove_number: self.fullmove_number,
+            hash: 0,
+            history: Vec::new(),
+        };
+        mirrored.hash = zobrist::hash_position(&mirrored);
+        mirrored
+    }
+
+    /// Returns the FEN as seen from `color`'s side of the board.
+    ///
+    /// For White this is the normal FEN. For Black the position is mirrored
+    /// first, so tooling that always analyzes from White's side can be fed
+    /// either perspective.
+    pub fn perspective_fen(&self, color: Color) -> String {
+        match color {
+            Color::White => self.to_fen(),
+            Color::Black => self.mirrored().to_fen(),
+        }
+    }
+
     // Getters
     pub fn board(&self) -> &Board {
         &self.board
     }
 
+    /// Direct board access. Edits made here are not reflected in
+    /// [`GameState::hash`].
     pub fn board_mut(&mut self) -> &mut Board {
         &mut self.board
     }
 
+    /// Returns the Zobrist hash of the position.
+    pub fn hash(&self) -> u64 {
+        self.hash
+    }
+
     pub fn side_to_move(&self) -> Color {
         self.side_to_move
     }
@@ -257,6 +626,97 @@ impl GameState {
         }
     }
 
+    /// Returns the home file of the rook `color` castles with on a wing.
+    pub fn castling_rook_file(&self, color: Color, kingside: bool) -> u8 {
+        self.castling_rook_files[color as usize][usize::from(!kingside)]
+    }
+
+    /// Returns true if castling follows Chess960 rules.
+    ///
+    /// Castling moves are then written king-takes-rook (e.g. `g1h1`), since
+    /// the king may start next to or on its destination.
+    pub fn is_chess960(&self) -> bool {
+        self.chess960
+    }
+
+    /// Switches between standard and Chess960 castling notation.
+    pub fn set_chess960(&mut self, chess960: bool) {
+        self.chess960 = chess960;
+    }
+
+    /// Returns the (king destination, rook origin, rook destination) squares
+    /// of castling on a wing. The king always lands on the g- or c-file and
+    /// the rook on the f- or d-file, wherever they started.
+    pub fn castling_squares(&self, color: Color, kingside: bool) -> (Coord, Coord, Coord) {
+        let rank = match color {
+            Color::White => 0,
+            Color::Black => StandardBoard::HEIGHT - 1,
+        };
+        let (king_file, rook_file) = if kingside { (6, 5) } else { (2, 3) };
+        (
+            Coord::new(king_file, rank),
+            Coord::new(self.castling_rook_file(color, kingside), rank),
+            Coord::new(rook_file, rank),
+        )
+    }
+
+    /// Returns the castling move on a wing for the side to move, if legal.
+    ///
+    /// The right must remain and the rook must stand on its home square.
+    /// Every square the king or rook crosses must be empty apart from the
+    /// two castling pieces, and the king may not start in, pass through, or
+    /// land on an attacked square.
+    pub fn legal_castling(&self, kingside: bool) -> Option<Move> {
+        let us = self.side_to_move;
+        let flags = if kingside {
+            MoveFlags::CastleKingside
+        } else {
+            MoveFlags::CastleQueenside
+        };
+        if !self.castling_rights(us).allows(flags) {
+            return None;
+        }
+
+        let king = self.board.find_king(us)?;
+        let (king_to, rook_from, rook_to) = self.castling_squares(us, kingside);
+        if king.rank != king_to.rank
+            || self.board.piece_at(&rook_from) != Some(Piece::new(PieceType::Rook, us))
+        {
+            return None;
+        }
+
+        let span = |a: Coord, b: Coord| {
+            (a.file.min(b.file)..=a.file.max(b.file)).map(move |f| Coord::new(f, a.rank))
+        };
+        let mut others = self.board.occupied();
+        for coord in [king, rook_from] {
+            others.clear(StandardBoard::to_index(&coord)?);
+        }
+        let blocked = span(king, king_to)
+            .chain(span(rook_from, rook_to))
+            .any(|c| StandardBoard::to_index(&c).is_some_and(|sq| others.get(sq)));
+        let them = self.board.pieces_of_color(us.opposite());
+        let attacked = span(king, king_to).any(|c| {
+            StandardBoard::to_index(&c)
+                .is_some_and(|sq| (attackers_to(&self.board, sq, others) & them).is_not_empty())
+        });
+        if blocked || attacked {
+            return None;
+        }
+
+        let to = if self.chess960 { rook_from } else { king_to };
+        Some(Move::with_flags(king, to, flags))
+    }
+
+    /// Returns true if `color` still holds any castling right.
+    ///
+    /// This is distinct from being able to castle right now: the right can be
+    /// retained while castling is temporarily prevented by checks or blocking
+    /// pieces. Once lost (the king or rook moved), it never comes back.
+    pub fn can_ever_castle(&self, color: Color) -> bool {
+        self.castling_rights(color).any()
+    }
+
     pub fn en_passant(&self) -> Option<Coord> {
         self.en_passant
     }
@@ -269,18 +729,250 @@ impl GameState {
         self.fullmove_number
     }
 
+    /// Returns true if the current position has occurred three times.
+    ///
+    /// Only positions since the last capture or pawn move are compared, and
+    /// only those with the same side to move.
+    pub fn is_threefold_repetition(&self) -> bool {
+        let window = (self.halfmove_clock as usize).min(self.history.len());
+        let earlier = self.history[self.history.len() - window..]
+            .iter()
+            .rev()
+            .skip(1)
+            .step_by(2)
+            .filter(|&&hash| hash == self.hash)
+            .count();
+        earlier + 1 >= 3
+    }
+
+    /// Classifies the position as ongoing, decisive, or drawn.
+    ///
+    /// Legal moves are generated once. Checkmate and stalemate take priority
+    /// over the draw rules, so a mate on the hundredth halfmove still counts.
+    pub fn status(&self) -> GameStatus {
+        let gen = MoveGenerator::new(self);
+        if gen.generate_moves().is_empty() {
+            return if gen.in_check() {
+                GameStatus::Checkmate
+            } else {
+                GameStatus::Stalemate
+            };
+        }
+
+        if self.is_fifty_move_draw() {
+            GameStatus::DrawFiftyMove
+        } else if self.is_threefold_repetition() {
+            GameStatus::DrawRepetition
+        } else if self.is_insufficient_material() {
+            GameStatus::DrawInsufficientMaterial
+        } else {
+            GameStatus::Ongoing
+        }
+    }
+
+    /// Returns true if neither side has enough material to mate.
+    ///
+    /// Covers K vs K, a lone minor piece against a bare king, and positions
+    /// where every remaining bishop stands on the same square color.
+    pub fn is_insufficient_material(&self) -> bool {
+        let mut minors = Vec::new();
+        for (coord, piece) in self.board.pieces() {
+            match piece.piece_type {
+                PieceType::King => {}
+                PieceType::Knight | PieceType::Bishop => minors.push((coord, piece)),
+                _ => return false,
+            }
+        }
+
+        match minors.as_slice() {
+            [] | [_] => true,
+            [(first, _), rest @ ..] => {
+                minors
+                    .iter()
+                    .all(|(_, piece)| piece.piece_type == PieceType::Bishop)
+                    && rest
+                        .iter()
+                        .all(|(coord, _)| coord.square_color() == first.square_color())
+            }
+        }
+    }
+
+    /// Returns true if fifty moves by each side passed without a capture or
+    /// pawn move.
+    pub fn is_fifty_move_draw(&self) -> bool {
+        self.halfmove_clock >= 100
+    }
+
+    /// Returns true if the side to move is in check.
+    ///
+    /// Cheaper than building a `MoveGenerator`: only the attackers of the
+    /// king's square are computed, without pins or full enemy attack sets.
+    pub fn is_check(&self) -> bool {
+        let Some(sq) = self
+            .board
+            .find_king(self.side_to_move)
+            .and_then(|king| StandardBoard::to_index(&king))
+        else {
+            return false;
+        };
+        let attackers = attackers_to(&self.board, sq, self.board.occupied());
+        !(attackers & self.board.pieces_of_color(self.side_to_move.opposite())).is_empty()
+    }
+
+    /// Returns true if `mv` is legal in this position, without generating
+    /// every legal move.
+    ///
+    /// Like [`find_legal_move`], only the squares and promotion piece are
+    /// compared; castling, en passant, and double pushes are recognized from
+    /// the geometry. The move is played on a copy to confirm the king is safe.
+    pub fn is_legal(&self, mv: &Move) -> bool {
+        let Some(resolved) = self.resolve_pseudo_legal(mv) else {
+            return false;
+        };
+        if resolved.is_castling() {
+            // Already checked square by square
+            return true;
+        }
+
+        let mut next = self.clone();
+        next.make_move(&resolved);
+        next.board
+            .find_king(self.side_to_move)
+            .is_some_and(|king| !is_square_attacked(&next, king, self.side_to_move.opposite()))
+    }
+
+    /// Checks the piece, geometry, path, and special-move preconditions of
+    /// `mv`, ignoring king safety. Returns the move with its flags filled in.
+    fn resolve_pseudo_legal(&self, mv: &Move) -> Option<Move> {
+        let us = self.side_to_move;
+        let piece = self.board.piece_at(&mv.from).filter(|p| p.color == us)?;
+        if piece.piece_type == PieceType::King && mv.promoted_piece().is_none() {
+            if let Some(castle) = self.resolve_castling(mv) {
+                return Some(castle);
+            }
+        }
+        let from = StandardBoard::to_index(&mv.from)?;
+        let to = StandardBoard::to_index(&mv.to)?;
+        let target = self.board.piece_at(&mv.to);
+        if target.is_some_and(|p| p.color == us) {
+            return None;
+        }
+
+        let last_rank = match us {
+            Color::White => StandardBoard::HEIGHT - 1,
+            Color::Black => 0,
+        };
+        let promotes = piece.piece_type == PieceType::Pawn && mv.to.rank == last_rank;
+        match mv.promoted_piece() {
+            Some(PieceType::Knight | PieceType::Bishop | PieceType::Rook | PieceType::Queen)
+                if promotes => {}
+            None if !promotes => {}
+            _ => return None,
+        }
+        let plain = Move::with_flags(mv.from, mv.to, mv.flags);
+
+        match piece.piece_type {
+            PieceType::King if !king_attacks(from).get(to) => None,
+            PieceType::Pawn => {
+                let forward: i8 = match us {
+                    Color::White => 1,
+                    Color::Black => -1,
+                };
+                // White pawns on the first rank (Horde) may also double push
+                let can_double_push = match us {
+                    Color::White => mv.from.rank <= 1,
+                    Color::Black => mv.from.rank == StandardBoard::HEIGHT - 2,
+                };
+                let rank_step = mv.to.rank as i8 - mv.from.rank as i8;
+                let empty = |c: Coord| self.board.piece_at(&c).is_none();
+
+                if mv.to.file != mv.from.file {
+                    if !pawn_attacks(from, us as usize).get(to) {
+                        None
+                    } else if target.is_some() {
+                        Some(plain)
+                    } else if self.en_passant == Some(mv.to) {
+                        Some(Move::with_flags(mv.from, mv.to, MoveFlags::EnPassant))
+                    } else {
+                        None
+                    }
+                } else if target.is_some() {
+                    None
+                } else if rank_step == forward {
+                    Some(plain)
+                } else if rank_step == 2 * forward && can_double_push {
+                    let middle = Coord::new(mv.from.file, (mv.from.rank + mv.to.rank) / 2);
+                    empty(middle)
+                        .then(|| Move::with_flags(mv.from, mv.to, MoveFlags::DoublePawnPush))
+                } else {
+                    None
+                }
+            }
+            _ => piece_attacks(piece, from, self.board.occupied())
+                .get(to)
+                .then_some(plain),
+        }
+    }
+
+    /// Matches `mv` against the legal castling moves on either wing.
+    fn resolve_castling(&self, mv: &Move) -> Option<Move> {
+        [true, false]
+            .into_iter()
+            .filter_map(|kingside| self.legal_castling(kingside))
+            .find(|castle| castle.from == mv.from && castle.to == mv.to)
+    }
+
+    /// Returns true if `mv` captures a piece in this position.
+    ///
+    /// A move is a capture when its destination holds an enemy piece or it
+    /// is an en passant capture.
+    pub fn is_capture(&self, mv: &Move) -> bool {
+        self.captured_piece(mv).is_some()
+    }
+
+    /// Returns the piece `mv` would capture, if any.
+    ///
+    /// For en passant this is the enemy pawn beside the moving pawn rather
+    /// than anything on the destination square. Castling never captures,
+    /// even when the move's destination is the rook's square (Chess960).
+    pub fn captured_piece(&self, mv: &Move) -> Option<Piece> {
+        if mv.is_castling() {
+            return None;
+        }
+        let mover = self.board.piece_at(&mv.from)?;
+        if mv.is_en_passant() {
+            return Some(Piece::new(PieceType::Pawn, mover.color.opposite()));
+        }
+        self.board
+            .piece_at(&mv.to)
+            .filter(|target| target.color != mover.color)
+    }
+
     /// Makes a move on the board (without legality checking).
     ///
-    /// This is a basic implementation that will be expanded later.
-    pub fn make_move(&mut self, mv: &Move) {
+    /// Returns an [`UndoInfo`] whose `outcome` describes the board changes
+    /// caused by the move; pass it to [`GameState::unmake_move`] to take the
+    /// move back.
+    pub fn make_move(&mut self, mv: &Move) -> UndoInfo {
+        let white_castling = self.white_castling;
+        let black_castling = self.black_castling;
+        let en_passant = self.en_passant;
+        let halfmove_clock = self.halfmove_clock;
+        let hash = self.hash;
+        self.history.push(hash);
+
+        let moved = self.board.piece_at(&mv.from);
+        let mut captured = None;
+        let mut rook_move = None;
+
         // Handle special moves
         if mv.is_castling() {
-            self.make_castling(mv);
+            rook_move = Some(self.make_castling(mv));
         } else if mv.is_en_passant() {
-            self.make_en_passant(mv);
+            captured = self.make_en_passant(mv);
         } else {
             // Normal move
-            let captured = self.board.move_piece(&mv.from, &mv.to);
+            captured = self.board.move_piece(&mv.from, &mv.to).map(|p| (mv.to, p));
 
             // Handle promotion
             if let MoveFlags::Promotion { piece: promo_type } = mv.flags {
@@ -291,12 +983,7 @@ impl GameState {
             }
 
             // Update halfmove clock
-            if captured.is_some()
-                || self
-                    .board
-                    .piece_at(&mv.to)
-                    .is_some_and(|p| p.piece_type == PieceType::Pawn)
-            {
+            if captured.is_some() || moved.is_some_and(|p| p.piece_type == PieceType::Pawn) {
                 self.halfmove_clock = 0;
             } else {
                 self.halfmove_clock += 1;
@@ -308,16 +995,16 @@ impl GameState {
         if let Some(piece) = self.board.piece_at(&mv.to) {
             if piece.piece_type == PieceType::Pawn {
                 let rank_diff = (mv.to.rank as i8 - mv.from.rank as i8).abs();
-                if rank_diff == 2 {
+                let ep_rank = (mv.from.rank + mv.to.rank) / 2;
+                // A double push from the first rank (Horde) cannot be taken en passant
+                if rank_diff == 2 && (ep_rank == 2 || ep_rank == StandardBoard::HEIGHT - 3) {
                     // Pawn moved two squares, set en passant target
-                    let ep_rank = (mv.from.rank + mv.to.rank) / 2;
                     self.en_passant = Some(Coord::new(mv.from.file, ep_rank));
                 }
             }
         }
 
-        // Update castling rights (basic version)
-        self.update_castling_rights(mv);
+        self.update_castling_rights(mv, moved);
 
         // Switch side to move
         self.side_to_move = self.side_to_move.opposite();
@@ -326,64 +1013,180 @@ impl GameState {
         if self.side_to_move == Color::White {
             self.fullmove_number += 1;
         }
-    }
-
-    fn make_castling(&mut self, mv: &Move) {
-        // Move king
-        self.board.move_piece(&mv.from, &mv.to);
 
-        // Move rook
-        let (rook_from, rook_to) = if mv.to.file == 6 {
-            // Kingside
-            (Coord::new(7, mv.from.rank), Coord::new(5, mv.from.rank))
-        } else {
-            // Queenside
-            (Coord::new(0, mv.from.rank), Coord::new(3, mv.from.rank))
+        self.hash ^= zobrist::side_key()
+            ^ zobrist::castling_key(white_castling, black_castling)
+            ^ zobrist::castling_key(self.white_castling, self.black_castling)
+            ^ zobrist::en_passant_key(en_passant)
+            ^ zobrist::en_passant_key(self.en_passant);
+        if let Some(piece) = moved {
+            self.hash ^= zobrist::piece_key(piece, mv.from);
+        }
+        let landing = match rook_move {
+            Some((_, rook_to)) => Coord::new(if rook_to.file == 5 { 6 } else { 2 }, rook_to.rank),
+            None => mv.to,
         };
-        self.board.move_piece(&rook_from, &rook_to);
-
+        if let Some(piece) = self.board.piece_at(&landing) {
+            self.hash ^= zobrist::piece_key(piece, landing);
+        }
+        if let Some((coord, piece)) = captured {
+            self.hash ^= zobrist::piece_key(piece, coord);
+        }
+        if let Some((rook_from, rook_to)) = rook_move {
+            if let Some(rook) = self.board.piece_at(&rook_to) {
+                self.hash ^=
+                    zobrist::piece_key(rook, rook_from) ^ zobrist::piece_key(rook, rook_to);
+            }
+        }
+
+        UndoInfo {
+            outcome: MoveOutcome {
+                mv: *mv,
+                moved,
+                captured,
+                rook_move,
+            },
+            white_castling,
+            black_castling,
+            en_passant,
+            halfmove_clock,
+            hash,
+        }
+    }
+
+    /// Takes back `mv`, restoring the position exactly as it was before.
+    ///
+    /// `undo` must be the value returned when `mv` was made, and `mv` must be
+    /// the last move made on this state.
+    pub fn unmake_move(&mut self, mv: &Move, undo: UndoInfo) {
+        self.side_to_move = self.side_to_move.opposite();
+        if self.side_to_move == Color::Black {
+            self.fullmove_number -= 1;
+        }
+        self.white_castling = undo.white_castling;
+        self.black_castling = undo.black_castling;
+        self.en_passant = undo.en_passant;
+        self.halfmove_clock = undo.halfmove_clock;
+        self.hash = undo.hash;
+        self.history.pop();
+
+        let outcome = undo.outcome;
+        if let Some((rook_from, rook_to)) = outcome.rook_move {
+            let king_to = Coord::new(if rook_to.file == 5 { 6 } else { 2 }, rook_to.rank);
+            let king = self.board.remove_piece(&king_to);
+            let rook = self.board.remove_piece(&rook_to);
+            if let (Some(king), Some(rook)) = (king, rook) {
+                self.board.set_piece(&mv.from, king);
+                self.board.set_piece(&rook_from, rook);
+            }
+            return;
+        }
+
+        // Restore the mover as it was before any promotion
+        self.board.remove_piece(&mv.to);
+        if let Some(piece) = outcome.moved {
+            self.board.set_piece(&mv.from, piece);
+        }
+        if let Some((coord, piece)) = outcome.captured {
+            self.board.set_piece(&coord, piece);
+        }
+    }
+
+    /// Passes the turn without moving a piece.
+    ///
+    /// Used to ask "what would the opponent do if it were their move?". The
+    /// en passant square is cleared since the right to capture expires.
+    pub fn make_null_move(&mut self) {
+        self.hash ^= zobrist::en_passant_key(self.en_passant) ^ zobrist::side_key();
+        self.en_passant = None;
+        self.side_to_move = self.side_to_move.opposite();
+    }
+
+    /// Returns the opponent's legal replies after playing `mv`.
+    ///
+    /// Fails if `mv` is not legal in this position.
+    pub fn replies_to(&self, mv: &Move) -> Result<Vec<Move>, String> {
+        let legal = find_legal_move(self, mv).ok_or_else(|| format!("Illegal move: {}", mv))?;
+        let mut next = self.clone();
+        next.make_move(&legal);
+        Ok(generate_legal_moves(&next))
+    }
+
+    /// Returns the promotion moves available to the pawn on `from`.
+    ///
+    /// Each reachable promotion square yields four moves (queen, rook,
+    /// bishop, knight). Empty if `from` holds no pawn of the side to move or
+    /// the pawn cannot promote this move.
+    pub fn promotion_targets(&self, from: Coord) -> Vec<Move> {
+        generate_legal_moves(self)
+            .into_iter()
+            .filter(|mv| mv.from == from && mv.is_promotion())
+            .collect()
+    }
+
+    /// Moves the king and rook for a castling move.
+    ///
+    /// Both pieces are lifted before either is placed, since in Chess960
+    /// one may land where the other started. Returns the rook's (from, to)
+    /// squares.
+    fn make_castling(&mut self, mv: &Move) -> (Coord, Coord) {
+        let kingside = matches!(mv.flags, MoveFlags::CastleKingside);
+        let (king_to, rook_from, rook_to) = self.castling_squares(self.side_to_move, kingside);
+
+        let king = self.board.remove_piece(&mv.from);
+        let rook = self.board.remove_piece(&rook_from);
+        if let (Some(king), Some(rook)) = (king, rook) {
+            self.board.set_piece(&king_to, king);
+            self.board.set_piece(&rook_to, rook);
+        }
+
         self.halfmove_clock += 1;
+
+        (rook_from, rook_to)
     }
 
-    fn make_en_passant(&mut self, mv: &Move) {
+    /// Plays an en passant capture.
+    ///
+    /// Returns the captured pawn and the square it was removed from.
+    fn make_en_passant(&mut self, mv: &Move) -> Option<(Coord, Piece)> {
         // Move the pawn
         self.board.move_piece(&mv.from, &mv.to);
 
         // Remove the captured pawn
         let captured_rank = mv.from.rank;
         let captured_coord = Coord::new(mv.to.file, captured_rank);
-        self.board.remove_piece(&captured_coord);
+        let captured = self.board.remove_piece(&captured_coord);
 
         self.halfmove_clock = 0;
+
+        captured.map(|p| (captured_coord, p))
     }
 
-    fn update_castling_rights(&mut self, mv: &Move) {
+    fn update_castling_rights(&mut self, mv: &Move, moved: Option<Piece>) {
         // If king moves, lose all castling rights
-        if let Some(piece) = self.board.piece_at(&mv.to) {
-            if piece.piece_type == PieceType::King {
-                match piece.color {
-                    Color::White => self.white_castling = CastlingRights::NONE,
-                    Color::Black => self.black_castling = CastlingRights::NONE,
-                }
+        if let Some(piece) = moved.filter(|p| p.piece_type == PieceType::King) {
+            match piece.color {
+                Color::White => self.white_castling = CastlingRights::NONE,
+                Color::Black => self.black_castling = CastlingRights::NONE,
             }
         }
 
-        // If rook moves from starting position, lose that side's castling right
-        let update_rook_rights = |coord: &Coord, rights: &mut CastlingRights| {
-            if coord.rank == 0 || coord.rank == 7 {
-                if coord.file == 0 {
-                    rights.queenside = false;
-                } else if coord.file == 7 {
-                    rights.kingside = false;
+        // A move from or to a castling rook's home square ends that right,
+        // whether the rook moved away or was captured there
+        for color in [Color::White, Color::Black] {
+            for kingside in [true, false] {
+                let (_, rook_home, _) = self.castling_squares(color, kingside);
+                if mv.from != rook_home && mv.to != rook_home {
+                    continue;
                 }
-            }
-        };
-
-        if let Some(piece) = self.board.piece_at(&mv.to) {
-            if piece.piece_type == PieceType::Rook {
-                match piece.color {
-                    Color::White => update_rook_rights(&mv.from, &mut self.white_castling),
-                    Color::Black => update_rook_rights(&mv.from, &mut self.black_castling),
+                let rights = match color {
+                    Color::White => &mut self.white_castling,
+                    Color::Black => &mut self.black_castling,
+                };
+                if kingside {
+                    rights.kingside = false;
+                } else {
+                    rights.queenside = false;
                 }
             }
         }
@@ -400,6 +1203,32 @@ impl fmt::Display for GameState {
     }
 }
 
+/// Parses a FEN string, like [`GameState::from_fen`].
+impl FromStr for GameState {
+    type Err = String;
+
+    fn from_str(fen: &str) -> Result<Self, Self::Err> {
+        Self::from_fen(fen)
+    }
+}
+
+/// Serializes as the FEN string to stay compact.
+#[cfg(feature = "serde")]
+impl serde::Serialize for GameState {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_fen())
+    }
+}
+
+/// Deserializes from a FEN string. Move history is not preserved.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for GameState {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let fen = String::deserialize(deserializer)?;
+        GameState::from_fen(&fen).map_err(serde::de::Error::custom)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -454,6 +1283,519 @@ mod tests {
         assert!(GameState::from_fen("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP w KQkq - 0 1").is_err());
     }
 
+    #[test]
+    fn test_is_legal() {
+        // The e3 knight is pinned by the e8 rook
+        let pinned = GameState::from_fen("4r1k1/8/8/8/8/4N3/8/4K3 w - - 0 1").unwrap();
+        assert!(!pinned.is_legal(&Move::from_uci("e3d5").unwrap()));
+        assert!(pinned.is_legal(&Move::from_uci("e1d1").unwrap()));
+
+        let open =
+            GameState::from_fen("rnbqkbnr/ppp1pppp/8/3p4/4P3/8/PPPP1PPP/RNBQKBNR w KQkq - 0 2")
+                .unwrap();
+        assert!(open.is_legal(&Move::from_uci("e4d5").unwrap()));
+        assert!(!open.is_legal(&Move::from_uci("e4e6").unwrap()));
+        assert!(!open.is_legal(&Move::from_uci("d1h5q").unwrap()));
+
+        // bxc6 e.p. would remove both pawns from the fifth rank
+        let ep = GameState::from_fen("8/8/8/KPp4r/8/8/8/4k3 w - c6 0 1").unwrap();
+        assert!(!ep.is_legal(&Move::from_uci("b5c6").unwrap()));
+        assert!(ep.is_legal(&Move::from_uci("b5b6").unwrap()));
+    }
+
+    #[test]
+    fn test_is_legal_agrees_with_generator() {
+        let fens = [
+            "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+            "r3k2r/p1ppqpb1/bn2pnp1/3PN3/Pp2P3/2N2Q1p/1PPBBPPP/R3K2R b KQkq a3 0 1",
+            "r3k2r/8/8/8/8/8/8/R3K1R1 b Qkq - 0 1",
+            "1r2k3/P1P5/8/3pP3/8/8/8/4K3 w - d6 0 1",
+            "4k3/8/8/8/8/8/3q4/R3K2R w KQ - 0 1",
+        ];
+        let promotions = [None, Some(PieceType::Queen), Some(PieceType::Knight)];
+        for fen in fens {
+            let game = GameState::from_fen(fen).unwrap();
+            for from in StandardBoard::all_coords() {
+                for to in StandardBoard::all_coords() {
+                    for promo in promotions {
+                        let mv = match promo {
+                            Some(piece) => Move::promotion(from, to, piece),
+                            None => Move::new(from, to),
+                        };
+                        assert_eq!(
+                            game.is_legal(&mv),
+                            find_legal_move(&game, &mv).is_some(),
+                            "{} in {}",
+                            mv,
+                            fen
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_from_fen_validated() {
+        let rejected = [
+            "8/8/8/8/8/8/8/4K3 w - - 0 1",    // no black king
+            "4k3/8/8/8/8/8/8/3KK3 w - - 0 1", // two white kings
+            "8/8/8/8/8/8/3k4/4K3 w - - 0 1",  // adjacent kings
+            "4k3/8/8/8/8/8/8/P3K3 w - - 0 1", // pawn on rank 1
+            "p3k3/8/8/8/8/8/8/4K3 w - - 0 1", // pawn on rank 8
+        ];
+        for fen in rejected {
+            assert!(GameState::from_fen_validated(fen).is_err(), "{}", fen);
+            assert!(GameState::from_fen(fen).is_ok(), "{}", fen);
+        }
+
+        let kiwipete = "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1";
+        let game = GameState::from_fen_validated(kiwipete).unwrap();
+        assert_eq!(game.to_fen(), kiwipete);
+    }
+
+    #[test]
+    fn test_perspective_fen() {
+        let game = GameState::starting_position();
+        assert_eq!(game.perspective_fen(Color::White), game.to_fen());
+
+        // The start position is symmetric, so only the side to move changes
+        assert_eq!(
+            game.perspective_fen(Color::Black),
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR b KQkq - 0 1"
+        );
+    }
+
+    #[test]
+    fn test_mirrored() {
+        let game = GameState::from_fen("rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b Kq e3 0 1")
+            .unwrap();
+        assert_eq!(
+            game.mirrored().to_fen(),
+            "rnbqkbnr/pppp1ppp/8/4p3/8/8/PPPPPPPP/RNBQKBNR w Qk e6 0 1"
+        );
+        assert_eq!(game.mirrored().mirrored().to_fen(), game.to_fen());
+    }
+
+    #[test]
+    fn test_move_outcome() {
+        let mut game =
+            GameState::from_fen("rnbqkbnr/pppp1ppp/8/4pP2/8/8/PPPPP1PP/RNBQKBNR w KQkq e6 0 3")
+                .unwrap();
+        let ep = Move::with_flags(Coord::new(5, 4), Coord::new(4, 5), MoveFlags::EnPassant);
+        let outcome = game.make_move(&ep).outcome;
+        assert_eq!(
+            outcome.moved,
+            Some(Piece::new(PieceType::Pawn, Color::White))
+        );
+        assert_eq!(
+            outcome.captured,
+            Some((Coord::new(4, 4), Piece::new(PieceType::Pawn, Color::Black)))
+        );
+        assert_eq!(outcome.changed_squares().len(), 3);
+
+        let mut game = GameState::from_fen("r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1").unwrap();
+        let castle = Move::with_flags(
+            Coord::new(4, 0),
+            Coord::new(6, 0),
+            MoveFlags::CastleKingside,
+        );
+        let outcome = game.make_move(&castle).outcome;
+        assert_eq!(outcome.captured, None);
+        assert_eq!(
+            outcome.rook_move,
+            Some((Coord::new(7, 0), Coord::new(5, 0)))
+        );
+    }
+
+    #[test]
+    fn test_unmake_move_round_trips() {
+        let fens = [
+            // Castling both ways, rook captures on home squares
+            "r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1",
+            // Kiwipete: captures, pins, castling
+            "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+            // En passant available
+            "rnbqkbnr/pppp1ppp/8/4pP2/8/8/PPPPP1PP/RNBQKBNR w KQkq e6 0 3",
+            // Promotions and capture-promotions, Black to move
+            "4k3/8/8/8/8/8/1p5p/R5N1 b - - 5 40",
+        ];
+
+        for fen in fens {
+            let game = GameState::from_fen(fen).unwrap();
+            for mv in generate_legal_moves(&game) {
+                let mut copy = game.clone();
+                let undo = copy.make_move(&mv);
+                assert_ne!(copy.to_fen(), fen);
+                copy.unmake_move(&mv, undo);
+                assert_eq!(copy.to_fen(), fen, "unmake of {} in {}", mv, fen);
+            }
+        }
+    }
+
+    #[test]
+    fn test_incremental_hash_matches_recomputation() {
+        let mut game = GameState::starting_position();
+        assert_eq!(game.hash(), zobrist::hash_position(&game));
+
+        // Double pushes, en passant, captures, castling, and a promotion
+        let line = [
+            "e2e4", "g8f6", "e4e5", "d7d5", "e5d6", "e7d6", "g1f3", "f8e7", "f1c4", "e8g8", "e1g1",
+            "b7b5", "c4b5", "c8a6", "b5a6", "b8a6", "d2d4", "a6b4", "a2a3", "b4c2", "d1c2", "c7c5",
+            "d4c5", "d6d5", "c5c6", "d8d6", "c6c7", "d6d7", "c7c8q",
+        ];
+        let mut undos = Vec::new();
+        for uci in line {
+            let mv = find_legal_move(&game, &Move::from_uci(uci).unwrap())
+                .unwrap_or_else(|| panic!("{} should be legal", uci));
+            let before = game.hash();
+            undos.push((mv, game.make_move(&mv)));
+            assert_ne!(game.hash(), before);
+            assert_eq!(game.hash(), zobrist::hash_position(&game), "after {}", uci);
+        }
+
+        while let Some((mv, undo)) = undos.pop() {
+            game.unmake_move(&mv, undo);
+            assert_eq!(game.hash(), zobrist::hash_position(&game));
+        }
+        assert_eq!(game.hash(), GameState::starting_position().hash());
+
+        game.make_null_move();
+        assert_eq!(game.hash(), zobrist::hash_position(&game));
+    }
+
+    #[test]
+    fn test_hash_transposition() {
+        let play = |moves: &[&str]| {
+            let mut game = GameState::starting_position();
+            for uci in moves {
+                game.make_move(&Move::from_uci(uci).unwrap());
+            }
+            game
+        };
+        let a = play(&["g1f3", "g8f6", "b1c3", "b8c6"]);
+        let b = play(&["b1c3", "b8c6", "g1f3", "g8f6"]);
+        assert_eq!(a.hash(), b.hash());
+        assert_ne!(a.hash(), GameState::starting_position().hash());
+    }
+
+    #[test]
+    fn test_threefold_repetition_knight_shuffle() {
+        let mut game = GameState::starting_position();
+        let shuffle = ["g1f3", "g8f6", "f3g1", "f6g8"];
+
+        for uci in shuffle {
+            game.make_move(&Move::from_uci(uci).unwrap());
+            assert!(!game.is_threefold_repetition());
+        }
+        // The starting position has now occurred twice
+        for (i, uci) in shuffle.iter().enumerate() {
+            game.make_move(&Move::from_uci(uci).unwrap());
+            assert_eq!(game.is_threefold_repetition(), i == 3, "after {}", uci);
+        }
+
+        // Taking the last move back undoes the repetition
+        let mut undone = GameState::starting_position();
+        let mut last = None;
+        for uci in shuffle.iter().chain(shuffle.iter()) {
+            let mv = Move::from_uci(uci).unwrap();
+            last = Some((mv, undone.make_move(&mv)));
+        }
+        assert!(undone.is_threefold_repetition());
+        let (mv, undo) = last.unwrap();
+        undone.unmake_move(&mv, undo);
+        assert!(!undone.is_threefold_repetition());
+    }
+
+    #[test]
+    fn test_repetition_counted_after_pawn_moves() {
+        let mut game = GameState::starting_position();
+        for uci in ["g1f3", "g8f6", "f3g1", "f6g8", "e2e3", "e7e6"] {
+            game.make_move(&Move::from_uci(uci).unwrap());
+        }
+        // The earlier shuffle lies before the pawn moves and does not count
+        for uci in ["g1f3", "g8f6", "f3g1", "f6g8"] {
+            game.make_move(&Move::from_uci(uci).unwrap());
+        }
+        assert!(!game.is_threefold_repetition());
+        for uci in ["g1f3", "g8f6", "f3g1", "f6g8"] {
+            game.make_move(&Move::from_uci(uci).unwrap());
+        }
+        assert!(game.is_threefold_repetition());
+    }
+
+    #[test]
+    fn test_insufficient_material() {
+        let insufficient = [
+            "4k3/8/8/8/8/8/8/4K3 w - - 0 1",    // K vs K
+            "4k3/8/8/8/8/8/8/2B1K3 w - - 0 1",  // K+B vs K
+            "4k3/8/8/8/8/8/8/1N2K3 b - - 0 1",  // K+N vs K
+            "2b1k3/8/8/8/8/8/8/3BK3 w - - 0 1", // K+B vs K+B, both on light squares
+        ];
+        for fen in insufficient {
+            let game = GameState::from_fen(fen).unwrap();
+            assert!(game.is_insufficient_material(), "{}", fen);
+        }
+
+        let sufficient = [
+            "4k3/8/8/8/8/8/8/1NN1K3 w - - 0 1", // K+N+N vs K
+            "4k3/8/8/8/8/8/4P3/4K3 w - - 0 1",  // K+P vs K
+            "4k3/8/8/8/8/8/8/R3K3 w - - 0 1",   // K+R vs K
+            "3bk3/8/8/8/8/8/8/3BK3 w - - 0 1",  // opposite-colored bishops
+            "4k3/8/8/8/8/8/8/1N1BK3 w - - 0 1", // K+B+N vs K
+        ];
+        for fen in sufficient {
+            let game = GameState::from_fen(fen).unwrap();
+            assert!(!game.is_insufficient_material(), "{}", fen);
+        }
+        assert!(!GameState::starting_position().is_insufficient_material());
+    }
+
+    #[test]
+    fn test_status() {
+        assert_eq!(GameState::starting_position().status(), GameStatus::Ongoing);
+
+        let mut scholars = GameState::starting_position();
+        for uci in ["e2e4", "e7e5", "f1c4", "b8c6", "d1h5", "g8f6", "h5f7"] {
+            scholars.make_move(&Move::from_uci(uci).unwrap());
+        }
+        assert_eq!(scholars.status(), GameStatus::Checkmate);
+
+        let stalemate = GameState::from_fen("7k/5Q2/6K1/8/8/8/8/8 b - - 0 1").unwrap();
+        assert_eq!(stalemate.status(), GameStatus::Stalemate);
+
+        let fifty = GameState::from_fen("4k3/8/8/8/8/8/8/R3K3 w - - 100 90").unwrap();
+        assert_eq!(fifty.status(), GameStatus::DrawFiftyMove);
+
+        let bare = GameState::from_fen("4k3/8/8/8/8/8/8/1N2K3 w - - 0 1").unwrap();
+        assert_eq!(bare.status(), GameStatus::DrawInsufficientMaterial);
+
+        let mut shuffle = GameState::starting_position();
+        for _ in 0..2 {
+            for uci in ["g1f3", "g8f6", "f3g1", "f6g8"] {
+                shuffle.make_move(&Move::from_uci(uci).unwrap());
+            }
+        }
+        assert_eq!(shuffle.status(), GameStatus::DrawRepetition);
+    }
+
+    #[test]
+    fn test_fifty_move_draw() {
+        let game = GameState::from_fen("4k3/8/8/8/8/8/8/4K2R w - - 99 80").unwrap();
+        assert!(!game.is_fifty_move_draw());
+        let mut next = game.clone();
+        next.make_move(&Move::from_uci("h1h2").unwrap());
+        assert!(next.is_fifty_move_draw());
+        let capture = GameState::from_fen("4k3/8/8/8/8/8/7r/4K2R w - - 99 80").unwrap();
+        let mut next = capture.clone();
+        next.make_move(&Move::from_uci("h1h2").unwrap());
+        assert!(!next.is_fifty_move_draw());
+    }
+
+    #[test]
+    fn test_capturing_home_rook_removes_castling_right() {
+        let mut game = GameState::from_fen("r3k2r/8/8/8/8/8/6n1/R3K2R b KQkq - 0 1").unwrap();
+        game.make_move(&Move::from_uci("g2h4").unwrap());
+        game.make_move(&Move::from_uci("a1a8").unwrap());
+        assert_eq!(
+            game.castling_rights(Color::Black),
+            CastlingRights {
+                kingside: true,
+                queenside: false
+            }
+        );
+        assert_eq!(
+            game.castling_rights(Color::White),
+            CastlingRights {
+                kingside: true,
+                queenside: false
+            }
+        );
+
+        // A rook on the far corner moving away does not touch its own side's rights
+        let mut game = GameState::from_fen("R3k3/8/8/8/8/8/8/4K2R w K - 0 1").unwrap();
+        game.make_move(&Move::from_uci("a8a7").unwrap());
+        assert!(game.castling_rights(Color::White).kingside);
+    }
+
+    #[test]
+    fn test_castling_rights_helpers() {
+        let rights = CastlingRights {
+            kingside: true,
+            queenside: false,
+        };
+        assert!(rights.allows(MoveFlags::CastleKingside));
+        assert!(!rights.allows(MoveFlags::CastleQueenside));
+        assert!(!rights.allows(MoveFlags::Normal));
+        assert!(!rights.is_none());
+        assert!(CastlingRights::NONE.is_none());
+    }
+
+    #[test]
+    fn test_can_ever_castle_lost_after_king_returns() {
+        let mut game = GameState::from_fen("r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1").unwrap();
+        assert!(game.can_ever_castle(Color::White));
+
+        for uci in ["e1f1", "a8b8", "f1e1"] {
+            game.make_move(&Move::from_uci(uci).unwrap());
+        }
+
+        // The king is home again, but the right is gone for good
+        assert_eq!(
+            game.board.piece_at(&Coord::new(4, 0)).unwrap().piece_type,
+            PieceType::King
+        );
+        assert!(!game.can_ever_castle(Color::White));
+        assert!(game.can_ever_castle(Color::Black));
+        assert!(game.castling_rights(Color::Black).kingside);
+        assert!(!game.castling_rights(Color::Black).queenside);
+    }
+
+    #[test]
+    fn test_is_capture() {
+        let game =
+            GameState::from_fen("rnbqkbnr/ppp2ppp/8/3ppP2/4P3/8/PPPP2PP/RNBQKBNR w KQkq e6 0 4")
+                .unwrap();
+
+        // exd5
+        assert!(game.is_capture(&Move::from_uci("e4d5").unwrap()));
+        // fxe6 en passant
+        let ep = Move::with_flags(Coord::new(5, 4), Coord::new(4, 5), MoveFlags::EnPassant);
+        assert!(game.is_capture(&ep));
+        // Quiet moves
+        assert!(!game.is_capture(&Move::from_uci("g1f3").unwrap()));
+        assert!(!game.is_capture(&Move::from_uci("d2d3").unwrap()));
+    }
+
+    #[test]
+    fn test_captured_piece() {
+        let game =
+            GameState::from_fen("rnbqkbnr/ppp2ppp/8/3ppP2/4P3/8/PPPP2PP/RNBQKBNR w KQkq e6 0 4")
+                .unwrap();
+        let black_pawn = Some(Piece::new(PieceType::Pawn, Color::Black));
+
+        assert_eq!(game.captured_piece(&Move::from_uci("g1f3").unwrap()), None);
+        assert_eq!(
+            game.captured_piece(&Move::from_uci("e4d5").unwrap()),
+            black_pawn
+        );
+        // En passant takes the e5 pawn, although e6 is empty
+        let ep = Move::with_flags(Coord::new(5, 4), Coord::new(4, 5), MoveFlags::EnPassant);
+        assert_eq!(game.captured_piece(&ep), black_pawn);
+
+        // Castling onto the own rook's square (Chess960) is not a capture
+        let game = GameState::from_fen("4k3/8/8/8/8/8/8/1R3KR1 w GB - 0 1").unwrap();
+        let castle = game.legal_castling(true).unwrap();
+        assert_eq!(game.captured_piece(&castle), None);
+        assert!(!game.is_capture(&castle));
+    }
+
+    #[test]
+    fn test_from_placements() {
+        let game =
+            GameState::from_placements(&[("e1", 'K'), ("e8", 'k'), ("d1", 'Q')], Color::White)
+                .unwrap();
+        assert_eq!(game.to_fen(), "4k3/8/8/8/8/8/8/3QK3 w - - 0 1");
+
+        assert!(GameState::from_placements(&[("i9", 'K')], Color::White).is_err());
+        assert!(GameState::from_placements(&[("e1", 'X')], Color::White).is_err());
+        assert!(GameState::from_placements(&[("e1", 'K'), ("e1", 'Q')], Color::Black).is_err());
+    }
+
+    #[test]
+    fn test_is_check_agrees_with_generator() {
+        use crate::movegen::is_in_check;
+
+        let fens = [
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+            "rnbqkbnr/ppppp2p/5p2/6pQ/4P3/8/PPPP1PPP/RNB1KBNR b KQkq - 1 3",
+            "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+            "4k3/8/8/8/8/8/3p4/4K3 w - - 0 1",
+            "4k3/8/8/8/8/5n2/8/4K3 w - - 0 1",
+            "4k3/8/8/8/8/8/8/r3K3 w - - 0 1",
+            "4k3/4r3/8/8/8/8/4P3/4K3 w - - 0 1",
+        ];
+        for fen in fens {
+            let mut game = GameState::from_fen(fen).unwrap();
+            for _ in 0..2 {
+                assert_eq!(game.is_check(), is_in_check(&game), "{}", fen);
+                for mv in generate_legal_moves(&game) {
+                    let mut next = game.clone();
+                    next.make_move(&mv);
+                    assert_eq!(next.is_check(), is_in_check(&next), "{} {}", fen, mv);
+                }
+                if game.is_check() {
+                    break;
+                }
+                game.make_null_move();
+            }
+        }
+    }
+
+    #[test]
+    fn test_replies_to() {
+        let game = GameState::starting_position();
+        let replies = game.replies_to(&Move::from_uci("e2e4").unwrap()).unwrap();
+        assert_eq!(replies.len(), 20);
+        assert!(replies.iter().any(|m| m.to_string() == "e7e5"));
+
+        assert!(game.replies_to(&Move::from_uci("e2e5").unwrap()).is_err());
+    }
+
+    #[test]
+    fn test_from_board_fen() {
+        let game = GameState::from_board_fen("8/8/8/8/4K3/8/8/4k3", Color::White).unwrap();
+        assert_eq!(
+            game.board().piece_at(&Coord::new(4, 3)),
+            Some(Piece::new(PieceType::King, Color::White))
+        );
+        assert_eq!(
+            game.board().piece_at(&Coord::new(4, 0)),
+            Some(Piece::new(PieceType::King, Color::Black))
+        );
+        assert_eq!(game.board().occupied().popcount(), 2);
+        assert_eq!(game.side_to_move(), Color::White);
+        assert!(game.castling_rights(Color::White).is_none());
+        assert_eq!(game.en_passant(), None);
+        assert_eq!(game.to_fen(), "8/8/8/8/4K3/8/8/4k3 w - - 0 1");
+
+        assert!(GameState::from_board_fen("8/8/8/8/4K3/8/8", Color::White).is_err());
+        assert!(GameState::from_board_fen("8/8/8/8/4X3/8/8/4k3", Color::Black).is_err());
+    }
+
+    #[test]
+    fn test_promotion_targets() {
+        let e7 = Coord::new(4, 6);
+
+        let push = GameState::from_fen("k7/4P3/8/8/8/8/8/K7 w - - 0 1").unwrap();
+        assert_eq!(push.promotion_targets(e7).len(), 4);
+
+        // Push to e8 or capture on d8
+        let capture = GameState::from_fen("k2r4/4P3/8/8/8/8/8/K7 w - - 0 1").unwrap();
+        let moves = capture.promotion_targets(e7);
+        assert_eq!(moves.len(), 8);
+        assert!(moves.iter().all(|m| m.from == e7 && m.is_promotion()));
+
+        // Blocked pawn, pawn not on the seventh, or not the side to move
+        let blocked = GameState::from_fen("k3r3/4P3/8/8/8/8/8/K7 w - - 0 1").unwrap();
+        assert!(blocked.promotion_targets(e7).is_empty());
+        assert!(GameState::starting_position()
+            .promotion_targets(Coord::new(4, 1))
+            .is_empty());
+        let black_to_move = GameState::from_fen("k7/4P3/8/8/8/8/8/K7 b - - 0 1").unwrap();
+        assert!(black_to_move.promotion_targets(e7).is_empty());
+    }
+
+    #[test]
+    fn test_null_move() {
+        let mut game =
+            GameState::from_fen("rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 1")
+                .unwrap();
+        game.make_null_move();
+        assert_eq!(game.side_to_move(), Color::White);
+        assert_eq!(game.en_passant(), None);
+    }
+
     #[test]
     fn test_castling_rights_none() {
         let game = GameState::from_fen("r3k2r/8/8/8/8/8/8/R3K2R w - - 0 1").unwrap();
@@ -488,4 +1830,128 @@ mod tests {
         // Check empty squares
         assert_eq!(game.board.piece_at(&Coord::new(4, 3)), None);
     }
+
+    #[test]
+    fn test_chess960_castling_fen() {
+        // Shredder-FEN names the rook files directly
+        let fen = "4k3/8/8/8/8/8/8/1R3KR1 w GB - 0 1";
+        let game = GameState::from_fen(fen).unwrap();
+        assert!(game.is_chess960());
+        assert_eq!(game.castling_rook_file(Color::White, true), 6);
+        assert_eq!(game.castling_rook_file(Color::White, false), 1);
+        assert_eq!(game.to_fen(), fen);
+
+        // X-FEN letters pick the outermost rook; a standard setup stays standard
+        let game = GameState::from_fen("4k3/8/8/8/8/8/8/1R3KR1 w KQ - 0 1").unwrap();
+        assert!(game.is_chess960());
+        assert_eq!(game.to_fen(), fen);
+        assert!(!GameState::starting_position().is_chess960());
+    }
+
+    #[test]
+    fn test_chess960_castling_moves() {
+        let game = GameState::from_fen("4k3/8/8/8/8/8/8/1R3KR1 w GB - 0 1").unwrap();
+        let castles: Vec<String> = generate_legal_moves(&game)
+            .iter()
+            .filter(|m| m.is_castling())
+            .map(|m| m.to_uci())
+            .collect();
+        assert_eq!(castles.len(), 2);
+        assert!(castles.contains(&"f1g1".to_string()));
+        assert!(castles.contains(&"f1b1".to_string()));
+
+        // King and rook swap places on the kingside
+        let kingside = find_legal_move(&game, &Move::from_uci("f1g1").unwrap()).unwrap();
+        assert!(game.is_legal(&kingside));
+        let mut next = game.clone();
+        let undo = next.make_move(&kingside);
+        assert_eq!(next.to_fen(), "4k3/8/8/8/8/8/8/1R3RK1 b - - 1 1");
+        assert_eq!(next.hash(), zobrist::hash_position(&next));
+        let mut changed = undo.outcome.changed_squares();
+        changed.sort_by_key(|c| c.file);
+        assert_eq!(changed, vec![Coord::new(5, 0), Coord::new(6, 0)]);
+        next.unmake_move(&kingside, undo);
+        assert_eq!(next.to_fen(), game.to_fen());
+
+        let queenside = find_legal_move(&game, &Move::from_uci("f1b1").unwrap()).unwrap();
+        let mut next = game.clone();
+        next.make_move(&queenside);
+        assert_eq!(next.to_fen(), "4k3/8/8/8/8/8/8/2KR2R1 b - - 1 1");
+    }
+
+    #[test]
+    fn test_chess960_castling_through_attack() {
+        // The e8 rook covers e1, which the king crosses on its way to c1
+        let game = GameState::from_fen("4r1k1/8/8/8/8/8/8/1R3KR1 w GB - 0 1").unwrap();
+        assert!(game.legal_castling(false).is_none());
+        assert!(game.legal_castling(true).is_some());
+    }
+
+    #[test]
+    fn test_chess960_start_positions() {
+        assert_eq!(
+            GameState::chess960(518).to_fen(),
+            GameState::starting_position().to_fen()
+        );
+        assert!(!GameState::chess960(518).is_chess960());
+        assert_eq!(
+            GameState::chess960(0).to_fen(),
+            "bbqnnrkr/pppppppp/8/8/8/8/PPPPPPPP/BBQNNRKR w HFhf - 0 1"
+        );
+
+        for id in 0..960 {
+            let game = GameState::chess960(id);
+            for color in [Color::White, Color::Black] {
+                let rank = if color == Color::White { 0 } else { 7 };
+                let files = |kind: PieceType| -> Vec<u8> {
+                    (0..8)
+                        .filter(|&f| {
+                            game.board.piece_at(&Coord::new(f, rank))
+                                == Some(Piece::new(kind, color))
+                        })
+                        .collect()
+                };
+                let bishops = files(PieceType::Bishop);
+                assert_ne!(bishops[0] % 2, bishops[1] % 2, "position {}", id);
+                let rooks = files(PieceType::Rook);
+                let king = files(PieceType::King)[0];
+                assert!(rooks[0] < king && king < rooks[1], "position {}", id);
+                assert_eq!(game.castling_rook_file(color, true), rooks[1]);
+                assert_eq!(game.castling_rook_file(color, false), rooks[0]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_round_trip() {
+        for fen in [
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+            "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+            "rnbqkbnr/ppp2ppp/8/3ppP2/4P3/8/PPPP2PP/RNBQKBNR w KQkq e6 0 4",
+            "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 b - - 12 40",
+            "bqnb1rkr/pp3ppp/3ppn2/2p5/5P2/P2P4/NPP1P1PP/BQ1BNRKR w HFhf - 2 9",
+        ] {
+            let game: GameState = fen.parse().unwrap();
+            assert_eq!(game.to_fen(), fen);
+        }
+        assert!("not a fen".parse::<GameState>().is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let mv = Move::promotion(Coord::new(4, 6), Coord::new(4, 7), PieceType::Knight);
+        let json = serde_json::to_string(&mv).unwrap();
+        assert_eq!(serde_json::from_str::<Move>(&json).unwrap(), mv);
+
+        let fen = "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1";
+        let game = GameState::from_fen(fen).unwrap();
+        let json = serde_json::to_string(&game).unwrap();
+        assert_eq!(json, format!("\"{}\"", fen));
+        let back: GameState = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.to_fen(), fen);
+        assert_eq!(back.hash(), game.hash());
+
+        assert!(serde_json::from_str::<GameState>("\"not a fen\"").is_err());
+    }
 }
@@ -8,12 +8,13 @@ pub mod delta;
 pub mod gamestate;
 pub mod moves;
 pub mod piece;
+pub mod zobrist;
 
 pub use board::Board;
 pub use board_geometry::{BoardGeometry, StandardBoard};
-pub use color::Color;
+pub use color::{Color, SquareColor};
 pub use coord::Coord;
 pub use delta::Delta;
-pub use gamestate::{CastlingRights, GameState};
-pub use moves::{Move, MoveFlags};
+pub use gamestate::{CastlingRights, GameState, GameStatus, MoveOutcome, UndoInfo};
+pub use moves::{normalize_san, Move, MoveFlags};
 pub use piece::{MovementType, Piece, PieceDefinition, PieceType};
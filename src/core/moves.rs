@@ -1,8 +1,11 @@
-use super::{Coord, PieceType};
+use super::{Color, Coord, GameState, Piece, PieceType, StandardBoard};
+use crate::movegen::{generate_legal_moves, is_in_check};
+use std::cmp::Ordering;
 use std::fmt;
 
 /// Represents a chess move.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Move {
     /// Source square.
     pub from: Coord,
@@ -14,6 +17,7 @@ pub struct Move {
 
 /// Flags indicating special move types.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MoveFlags {
     /// Normal move or capture.
     Normal,
@@ -105,6 +109,149 @@ impl Move {
         s
     }
 
+    /// Packs the move into 16 bits: from (6 bits), to (6 bits), flags (4 bits).
+    ///
+    /// Only valid for boards with at most 64 squares, like the magic bitboard
+    /// path. Panics if a square lies outside the standard board.
+    pub fn to_u16(&self) -> u16 {
+        let from = StandardBoard::to_index(&self.from).expect("square off the 8x8 board");
+        let to = StandardBoard::to_index(&self.to).expect("square off the 8x8 board");
+        let code = match self.flags {
+            MoveFlags::Normal => 0,
+            MoveFlags::DoublePawnPush => 1,
+            MoveFlags::EnPassant => 2,
+            MoveFlags::CastleKingside => 3,
+            MoveFlags::CastleQueenside => 4,
+            MoveFlags::Promotion { piece } => match piece {
+                PieceType::Knight => 5,
+                PieceType::Bishop => 6,
+                PieceType::Rook => 7,
+                PieceType::Queen => 8,
+                PieceType::Pawn => 9,
+                PieceType::King => 10,
+            },
+        };
+        (from as u16) | ((to as u16) << 6) | (code << 12)
+    }
+
+    /// Unpacks a move encoded with [`Move::to_u16`].
+    ///
+    /// Returns `None` for unused flag codes.
+    pub fn from_u16(bits: u16) -> Option<Self> {
+        let from = StandardBoard::from_index((bits & 0x3F) as usize)?;
+        let to = StandardBoard::from_index(((bits >> 6) & 0x3F) as usize)?;
+        let flags = match bits >> 12 {
+            0 => MoveFlags::Normal,
+            1 => MoveFlags::DoublePawnPush,
+            2 => MoveFlags::EnPassant,
+            3 => MoveFlags::CastleKingside,
+            4 => MoveFlags::CastleQueenside,
+            5 => MoveFlags::Promotion {
+                piece: PieceType::Knight,
+            },
+            6 => MoveFlags::Promotion {
+                piece: PieceType::Bishop,
+            },
+            7 => MoveFlags::Promotion {
+                piece: PieceType::Rook,
+            },
+            8 => MoveFlags::Promotion {
+                piece: PieceType::Queen,
+            },
+            9 => MoveFlags::Promotion {
+                piece: PieceType::Pawn,
+            },
+            10 => MoveFlags::Promotion {
+                piece: PieceType::King,
+            },
+            _ => return None,
+        };
+        Some(Self::with_flags(from, to, flags))
+    }
+
+    /// Converts the move to Standard Algebraic Notation (e.g., "Nf3", "exd5", "O-O").
+    ///
+    /// Disambiguation only considers other legal moves of the same piece type
+    /// to the same square, so a pinned piece never forces a qualifier.
+    pub fn to_san(&self, game: &GameState) -> String {
+        let mut san = self.san_body(game);
+
+        let mut next = game.clone();
+        next.make_move(self);
+        if is_in_check(&next) {
+            san.push(if generate_legal_moves(&next).is_empty() {
+                '#'
+            } else {
+                '+'
+            });
+        }
+        san
+    }
+
+    /// Parses a SAN move (e.g. "Nbd7", "exd5", "e8=Q+", "O-O") in `game`.
+    ///
+    /// Check markers, annotation glyphs, and the capture `x` are optional;
+    /// `0-0` is accepted for castling and the `=` before a promotion piece
+    /// may be omitted. Returns None if no legal move matches, including
+    /// when the SAN is ambiguous.
+    pub fn from_san(san: &str, game: &GameState) -> Option<Move> {
+        let canonical = |s: &str| normalize_san(s).replace('0', "O").replace('=', "");
+        let wanted = canonical(san);
+        generate_legal_moves(game)
+            .into_iter()
+            .find(|mv| canonical(&mv.san_body(game)) == wanted)
+    }
+
+    /// Builds the SAN for a move, without check markers.
+    fn san_body(&self, game: &GameState) -> String {
+        match self.flags {
+            MoveFlags::CastleKingside => "O-O".to_string(),
+            MoveFlags::CastleQueenside => "O-O-O".to_string(),
+            _ => self.piece_san(game),
+        }
+    }
+
+    /// Builds the SAN body for a non-castling move, without check markers.
+    fn piece_san(&self, game: &GameState) -> String {
+        let Some(piece) = game.board().piece_at(&self.from) else {
+            return self.to_uci();
+        };
+        let capture = game.is_capture(self);
+        let mut san = String::new();
+
+        if piece.piece_type == PieceType::Pawn {
+            if capture {
+                san.push_str(&self.from.file_string());
+            }
+        } else {
+            san.push(san_letter(piece.piece_type));
+            let rivals: Vec<Move> = generate_legal_moves(game)
+                .into_iter()
+                .filter(|m| m.to == self.to && m.from != self.from)
+                .filter(|m| game.board().piece_at(&m.from) == Some(piece))
+                .collect();
+            if !rivals.is_empty() {
+                if rivals.iter().all(|m| m.from.file != self.from.file) {
+                    san.push_str(&self.from.file_string());
+                } else if rivals.iter().all(|m| m.from.rank != self.from.rank) {
+                    san.push_str(&self.from.rank_string());
+                } else {
+                    san.push_str(&self.from.to_algebraic());
+                }
+            }
+        }
+
+        if capture {
+            san.push('x');
+        }
+        san.push_str(&self.to.to_algebraic());
+        if let Some(promoted) = self.promoted_piece() {
+            san.push('=');
+            san.push(san_letter(promoted));
+        }
+        san
+    }
+
     /// Parses a move from UCI notation (e.g., "e2e4", "e7e8q").
     ///
     /// Note: This does NOT validate the move against a board position.
@@ -134,16 +281,104 @@ impl Move {
     }
 }
 
+/// Returns the uppercase SAN letter for a piece type.
+fn san_letter(piece_type: PieceType) -> char {
+    Piece::new(piece_type, Color::White).to_char()
+}
+
+/// Normalizes a SAN string for comparison against generated SAN.
+///
+/// Strips check/mate markers (`+`, `#`), annotation glyphs (`!`, `?`), a
+/// trailing `e.p.` suffix, and the internal `x` capture marker, so that
+/// annotated PGN such as `"Qxf7#!"` compares equal to plain `"Qf7"`.
+pub fn normalize_san(san: &str) -> String {
+    let mut s = san.trim();
+    loop {
+        let trimmed = s
+            .trim_end_matches(['+', '#', '!', '?'])
+            .trim_end_matches("e.p.")
+            .trim_end();
+        if trimmed.len() == s.len() {
+            break;
+        }
+        s = trimmed;
+    }
+    s.chars().filter(|&c| c != 'x').collect()
+}
+
 impl fmt::Display for Move {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.to_uci())
     }
 }
 
+impl MoveFlags {
+    /// Sort key: no promotion first, then promotions in `PieceType` order,
+    /// then the remaining special moves so that `Ord` agrees with `Eq`.
+    fn sort_key(&self) -> (u8, u8) {
+        match self {
+            MoveFlags::Normal => (0, 0),
+            MoveFlags::Promotion { piece } => (1, *piece as u8),
+            MoveFlags::DoublePawnPush => (2, 0),
+            MoveFlags::EnPassant => (2, 1),
+            MoveFlags::CastleKingside => (2, 2),
+            MoveFlags::CastleQueenside => (2, 3),
+        }
+    }
+}
+
+/// Orders moves by origin square, then destination square, then promotion
+/// piece, with squares compared by index (see [`Coord`]'s `Ord`).
+impl Ord for Move {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.from, self.to, self.flags.sort_key()).cmp(&(
+            other.from,
+            other.to,
+            other.flags.sort_key(),
+        ))
+    }
+}
+
+impl PartialOrd for Move {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_sorted_start_moves() {
+        let mut moves = generate_legal_moves(&GameState::starting_position());
+        moves.sort();
+        let uci: Vec<String> = moves.iter().map(Move::to_uci).collect();
+        assert_eq!(
+            uci,
+            [
+                "b1a3", "b1c3", "g1f3", "g1h3", "a2a3", "a2a4", "b2b3", "b2b4", "c2c3", "c2c4",
+                "d2d3", "d2d4", "e2e3", "e2e4", "f2f3", "f2f4", "g2g3", "g2g4", "h2h3", "h2h4",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_promotion_ordering() {
+        let from = Coord::new(4, 6);
+        let to = Coord::new(4, 7);
+        let mut moves = [
+            Move::promotion(from, to, PieceType::Queen),
+            Move::promotion(from, to, PieceType::Knight),
+            Move::new(from, to),
+            Move::promotion(from, to, PieceType::Rook),
+        ];
+        moves.sort();
+        assert_eq!(moves[0], Move::new(from, to));
+        assert_eq!(moves[1].promoted_piece(), Some(PieceType::Knight));
+        assert_eq!(moves[3].promoted_piece(), Some(PieceType::Queen));
+    }
+
     #[test]
     fn test_normal_move() {
         let m = Move::new(Coord::new(4, 1), Coord::new(4, 3));
@@ -204,6 +439,140 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_normalize_san() {
+        assert_eq!(normalize_san("Qxf7#!"), "Qf7");
+        assert_eq!(normalize_san("exd6e.p."), "ed6");
+        assert_eq!(normalize_san("exd6 e.p.+"), "ed6");
+        assert_eq!(normalize_san("Nf3!?"), "Nf3");
+        assert_eq!(normalize_san("e8=Q+"), "e8=Q");
+        assert_eq!(normalize_san("O-O-O"), "O-O-O");
+    }
+
+    #[test]
+    fn test_u16_roundtrip() {
+        let e1 = Coord::new(4, 0);
+        let moves = vec![
+            Move::new(Coord::new(6, 0), Coord::new(5, 2)),
+            Move::with_flags(
+                Coord::new(4, 1),
+                Coord::new(4, 3),
+                MoveFlags::DoublePawnPush,
+            ),
+            Move::with_flags(Coord::new(4, 4), Coord::new(3, 5), MoveFlags::EnPassant),
+            Move::with_flags(e1, Coord::new(6, 0), MoveFlags::CastleKingside),
+            Move::with_flags(e1, Coord::new(2, 0), MoveFlags::CastleQueenside),
+            Move::promotion(Coord::new(0, 6), Coord::new(0, 7), PieceType::Queen),
+            Move::promotion(Coord::new(0, 6), Coord::new(1, 7), PieceType::Rook),
+            Move::promotion(Coord::new(7, 1), Coord::new(7, 0), PieceType::Bishop),
+            Move::promotion(Coord::new(7, 1), Coord::new(6, 0), PieceType::Knight),
+            Move::new(Coord::new(7, 7), Coord::new(0, 0)),
+        ];
+
+        for m in moves {
+            assert_eq!(Move::from_u16(m.to_u16()), Some(m));
+        }
+        assert_eq!(Move::from_u16(0xF000), None);
+    }
+
+    fn san(fen: &str, uci: &str) -> String {
+        let game = GameState::from_fen(fen).unwrap();
+        let mv = crate::movegen::find_legal_move(&game, &Move::from_uci(uci).unwrap()).unwrap();
+        mv.to_san(&game)
+    }
+
+    #[test]
+    fn test_to_san() {
+        let start = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1";
+        assert_eq!(san(start, "g1f3"), "Nf3");
+        assert_eq!(san(start, "e2e4"), "e4");
+
+        let open = "rnbqkbnr/ppp1pppp/8/3p4/4P3/8/PPPP1PPP/RNBQKBNR w KQkq - 0 2";
+        assert_eq!(san(open, "e4d5"), "exd5");
+
+        assert_eq!(san("5k2/8/8/8/8/8/8/4K2R w K - 0 1", "e1g1"), "O-O+");
+        assert_eq!(san("k7/4P3/8/8/8/8/8/K7 w - - 0 1", "e7e8q"), "e8=Q+");
+        assert_eq!(san("k7/8/1K6/8/8/8/8/7R w - - 0 1", "h1h8"), "Rh8#");
+    }
+
+    #[test]
+    fn test_to_san_disambiguation() {
+        // Both knights reach d5
+        assert_eq!(san("6k1/8/8/8/8/2N1N3/8/4K3 w - - 0 1", "c3d5"), "Ncd5");
+        // Rooks on the same file
+        assert_eq!(san("6k1/8/8/R7/8/8/8/R3K3 w - - 0 1", "a1a3"), "R1a3");
+    }
+
+    #[test]
+    fn test_to_san_ignores_pinned_rival() {
+        // The e3 knight is pinned to the king, so only the c3 knight can go to d5
+        assert_eq!(san("4r1k1/8/8/8/8/2N1N3/8/4K3 w - - 0 1", "c3d5"), "Nd5");
+    }
+
+    #[test]
+    fn test_to_san_full_square_disambiguation() {
+        // Rivals share both the file (a3) and the rank (c1)
+        assert_eq!(san("k7/8/8/8/8/Q7/8/Q1Q4K w - - 0 1", "a1b2"), "Qa1b2#");
+    }
+
+    #[test]
+    fn test_san_round_trip() {
+        let fens = [
+            // Kiwipete: castling, captures, pins, double pawn pushes
+            "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+            // Promotions with and without capture, en passant
+            "1r2k3/P1P5/8/3pP3/8/8/8/4K3 w - d6 0 1",
+            // Knights and queens needing every kind of disambiguation
+            "8/8/6k1/1N3N2/8/Q1N5/8/Q1Q4K w - - 0 1",
+        ];
+        for fen in fens {
+            let game = GameState::from_fen(fen).unwrap();
+            for mv in generate_legal_moves(&game) {
+                let san = mv.to_san(&game);
+                assert_eq!(Move::from_san(&san, &game), Some(mv), "{} in {}", san, fen);
+            }
+        }
+    }
+
+    #[test]
+    fn test_from_san_lenient_forms() {
+        let start = GameState::starting_position();
+        assert_eq!(Move::from_san("Nf3!", &start), Move::from_uci("g1f3"));
+        assert_eq!(Move::from_san("Nf6", &start), None);
+
+        let castle = GameState::from_fen("r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1").unwrap();
+        assert!(Move::from_san("0-0-0", &castle).is_some_and(|m| m.is_castling()));
+
+        let promo = GameState::from_fen("1r2k3/P7/8/8/8/8/8/4K3 w - - 0 1").unwrap();
+        let queen = Move::from_uci("a7b8q");
+        assert_eq!(Move::from_san("axb8Q", &promo), queen);
+        assert_eq!(Move::from_san("ab8=Q+", &promo), queen);
+
+        // Two knights reach d5, so the bare form is ambiguous
+        let knights = GameState::from_fen("6k1/8/8/8/8/2N1N3/8/4K3 w - - 0 1").unwrap();
+        assert_eq!(Move::from_san("Nd5", &knights), None);
+        assert_eq!(Move::from_san("Nexd5", &knights), Move::from_uci("e3d5"));
+    }
+
+    #[test]
+    fn test_from_san_annotated() {
+        let scholar = GameState::from_fen(
+            "r1bqkb1r/pppp1ppp/2n2n2/4p2Q/2B1P3/8/PPPP1PPP/RNB1K1NR w KQkq - 4 4",
+        )
+        .unwrap();
+        assert_eq!(Move::from_san("Qxf7#!", &scholar), Move::from_uci("h5f7"));
+
+        let ep = GameState::from_fen("4k3/8/8/3pP3/8/8/8/4K3 w - d6 0 1").unwrap();
+        let capture = Move::from_san("exd6e.p.", &ep).unwrap();
+        assert_eq!(
+            capture,
+            Move::with_flags(Coord::new(4, 4), Coord::new(3, 5), MoveFlags::EnPassant)
+        );
+
+        let start = GameState::starting_position();
+        assert_eq!(Move::from_san("Nf3!?", &start), Move::from_uci("g1f3"));
+    }
+
     #[test]
     fn test_display() {
         let m = Move::new(Coord::new(6, 0), Coord::new(5, 2));
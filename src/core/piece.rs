@@ -1,7 +1,8 @@
-use super::{Color, Delta};
+use super::{Color, Coord, Delta};
 
 /// Type of chess piece.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PieceType {
     Pawn,
     Knight,
@@ -11,8 +12,25 @@ pub enum PieceType {
     King,
 }
 
+impl PieceType {
+    /// Returns the standard centipawn value of the piece type.
+    ///
+    /// The king has no material value since it can never be traded.
+    pub const fn value(self) -> i32 {
+        match self {
+            PieceType::Pawn => 100,
+            PieceType::Knight => 320,
+            PieceType::Bishop => 330,
+            PieceType::Rook => 500,
+            PieceType::Queen => 900,
+            PieceType::King => 0,
+        }
+    }
+}
+
 /// A chess piece with color and type.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Piece {
     pub piece_type: PieceType,
     pub color: Color,
@@ -42,6 +60,24 @@ impl Piece {
         }
     }
 
+    /// Returns the Unicode chess glyph (e.g., '♔' for white king, '♟' for black pawn).
+    pub fn to_unicode(&self) -> char {
+        match (self.color, self.piece_type) {
+            (Color::White, PieceType::King) => '♔',
+            (Color::White, PieceType::Queen) => '♕',
+            (Color::White, PieceType::Rook) => '♖',
+            (Color::White, PieceType::Bishop) => '♗',
+            (Color::White, PieceType::Knight) => '♘',
+            (Color::White, PieceType::Pawn) => '♙',
+            (Color::Black, PieceType::King) => '♚',
+            (Color::Black, PieceType::Queen) => '♛',
+            (Color::Black, PieceType::Rook) => '♜',
+            (Color::Black, PieceType::Bishop) => '♝',
+            (Color::Black, PieceType::Knight) => '♞',
+            (Color::Black, PieceType::Pawn) => '♟',
+        }
+    }
+
     /// Parses a piece from character (e.g., 'P', 'n').
     pub fn from_char(ch: char) -> Option<Self> {
         let color = if ch.is_uppercase() {
@@ -88,6 +124,17 @@ pub enum MovementType {
         offsets: Vec<Delta>,
     },
 
+    /// Repeated leaping movement (Nightrider).
+    ///
+    /// The piece repeats a leap in the same direction until blocked; only
+    /// the landing squares are visited, so squares jumped over don't block.
+    Ride {
+        /// Leap offsets that repeat (e.g., the knight offsets).
+        offsets: Vec<Delta>,
+        /// Maximum number of leaps (None = unlimited).
+        max_distance: Option<u8>,
+    },
+
     /// Pawn-like movement.
     ///
     /// Special rules for pawns: move forward, capture diagonally.
@@ -133,6 +180,143 @@ impl PieceDefinition {
         offsets
     }
 
+    /// Returns every square this piece attacks from `from`.
+    ///
+    /// `occupied_query` reports whether a square holds a piece (sliders stop
+    /// on the first one, including it), and `geom_valid` whether a square is
+    /// on the board. Pawn movement contributes only its capture squares,
+    /// since a forward push never attacks.
+    pub fn attacks(
+        &self,
+        from: Coord,
+        occupied_query: impl Fn(Coord) -> bool,
+        geom_valid: impl Fn(Coord) -> bool,
+    ) -> Vec<Coord> {
+        let step = |coord: Coord, delta: Delta| coord.try_offset(delta).filter(|&c| geom_valid(c));
+        let mut attacked = Vec::new();
+
+        for movement in &self.movements {
+            match movement {
+                MovementType::Slide {
+                    directions: steps,
+                    max_distance,
+                }
+                | MovementType::Ride {
+                    offsets: steps,
+                    max_distance,
+                } => {
+                    for &delta in steps {
+                        let mut current = from;
+                        let mut distance = 0;
+                        while max_distance.is_none_or(|max| distance < max) {
+                            let Some(next) = step(current, delta) else {
+                                break;
+                            };
+                            attacked.push(next);
+                            if occupied_query(next) {
+                                break;
+                            }
+                            current = next;
+                            distance += 1;
+                        }
+                    }
+                }
+                MovementType::Leap { offsets } => {
+                    attacked.extend(offsets.iter().filter_map(|&delta| step(from, delta)));
+                }
+                MovementType::Pawn { captures, .. } => {
+                    attacked.extend(captures.iter().filter_map(|&delta| step(from, delta)));
+                }
+            }
+        }
+
+        attacked.sort_by_key(|c| (c.rank, c.file));
+        attacked.dedup();
+        attacked
+    }
+
+    /// Parses a piece from Betza (funny) notation, e.g. `"N"`, `"RN"`, `"R4"`.
+    ///
+    /// Supported atoms are the leapers W, F, N, D, A, C, Z, the king K, and
+    /// the sliders B, R, Q. A doubled leaper (`"NN"`) becomes a rider, and a
+    /// number after an atom limits its range (`"R4"`, `"N2"`). Modifiers such
+    /// as `m`, `c`, or `f` are not supported and yield None.
+    pub fn from_betza(name: &'static str, notation: &str) -> Option<PieceDefinition> {
+        let mut movements = Vec::new();
+        let mut chars = notation.chars().peekable();
+
+        while let Some(atom) = chars.next() {
+            let (offsets, slider) = match atom {
+                'W' => (Self::unique_leaper_offsets(1, 0), false),
+                'F' => (Self::unique_leaper_offsets(1, 1), false),
+                'N' => (Self::unique_leaper_offsets(1, 2), false),
+                'D' => (Self::unique_leaper_offsets(2, 0), false),
+                'A' => (Self::unique_leaper_offsets(2, 2), false),
+                'C' => (Self::unique_leaper_offsets(1, 3), false),
+                'Z' => (Self::unique_leaper_offsets(2, 3), false),
+                'K' => {
+                    let mut offsets = Self::unique_leaper_offsets(1, 0);
+                    offsets.extend(Self::unique_leaper_offsets(1, 1));
+                    (offsets, false)
+                }
+                'B' => (Self::unique_leaper_offsets(1, 1), true),
+                'R' => (Self::unique_leaper_offsets(1, 0), true),
+                'Q' => {
+                    let mut offsets = Self::unique_leaper_offsets(1, 0);
+                    offsets.extend(Self::unique_leaper_offsets(1, 1));
+                    (offsets, true)
+                }
+                _ => return None,
+            };
+
+            let doubled = !slider && chars.next_if_eq(&atom).is_some();
+            let mut digits = String::new();
+            while let Some(d) = chars.next_if(|c| c.is_ascii_digit()) {
+                digits.push(d);
+            }
+            let range = if digits.is_empty() {
+                None
+            } else {
+                Some(digits.parse::<u8>().ok().filter(|&n| n > 0)?)
+            };
+
+            movements.push(if slider {
+                MovementType::Slide {
+                    directions: offsets,
+                    max_distance: range,
+                }
+            } else if doubled || range.is_some() {
+                MovementType::Ride {
+                    offsets,
+                    max_distance: range,
+                }
+            } else {
+                MovementType::Leap { offsets }
+            });
+        }
+
+        if movements.is_empty() {
+            return None;
+        }
+        Some(Self {
+            name,
+            movements,
+            is_royal: false,
+        })
+    }
+
+    /// Like [`PieceDefinition::generate_leaper_offsets`], without the
+    /// duplicates produced when a component is zero.
+    fn unique_leaper_offsets(dx: i8, dy: i8) -> Vec<Delta> {
+        let mut offsets = Vec::new();
+        for delta in Self::generate_leaper_offsets(dx, dy) {
+            if !offsets.contains(&delta) {
+                offsets.push(delta);
+            }
+        }
+        offsets
+    }
+
     /// Standard chess knight.
     pub fn knight() -> Self {
         Self {
@@ -278,6 +462,79 @@ impl PieceDefinition {
         }
     }
 
+    /// Chancellor: Rook + Knight movement.
+    pub fn chancellor() -> Self {
+        Self {
+            name: "Chancellor",
+            movements: vec![
+                Self::rook().movements.remove(0),
+                MovementType::Leap {
+                    offsets: Self::generate_leaper_offsets(1, 2),
+                },
+            ],
+            is_royal: false,
+        }
+    }
+
+    /// Archbishop (Cardinal): Bishop + Knight movement.
+    pub fn archbishop() -> Self {
+        Self {
+            name: "Archbishop",
+            movements: vec![
+                Self::bishop().movements.remove(0),
+                MovementType::Leap {
+                    offsets: Self::generate_leaper_offsets(1, 2),
+                },
+            ],
+            is_royal: false,
+        }
+    }
+
+    /// Zebra piece: (2, 3) leaper.
+    pub fn zebra() -> Self {
+        Self {
+            name: "Zebra",
+            movements: vec![MovementType::Leap {
+                offsets: Self::generate_leaper_offsets(2, 3),
+            }],
+            is_royal: false,
+        }
+    }
+
+    /// Nightrider: repeats knight leaps in one direction until blocked.
+    pub fn nightrider() -> Self {
+        Self {
+            name: "Nightrider",
+            movements: vec![MovementType::Ride {
+                offsets: Self::generate_leaper_offsets(1, 2),
+                max_distance: None,
+            }],
+            is_royal: false,
+        }
+    }
+
+    /// Wazir: (1, 0) leaper, one step orthogonally.
+    pub fn wazir() -> Self {
+        Self {
+            name: "Wazir",
+            movements: vec![MovementType::Leap {
+                offsets: Self::unique_leaper_offsets(1, 0),
+            }],
+            is_royal: false,
+        }
+    }
+
+    /// Ferz: (1, 1) leaper, one step diagonally.
+    pub fn ferz() -> Self {
+        Self {
+            name: "Ferz",
+            movements: vec![MovementType::Leap {
+                offsets: Self::unique_leaper_offsets(1, 1),
+            }],
+            is_royal: false,
+        }
+    }
+
     /// Camel piece: (1, 3) leaper.
     pub fn camel() -> Self {
         Self {
@@ -354,6 +611,166 @@ mod tests {
         assert_eq!(amazon.movements.len(), 2); // Queen + Knight
     }
 
+    #[test]
+    fn test_attacks_match_hardcoded_tables() {
+        use crate::core::StandardBoard;
+        use crate::movegen::{bishop_attacks, knight_attacks, pawn_attacks, Bitboard64};
+
+        let to_bitboard = |coords: Vec<Coord>| {
+            coords.iter().fold(Bitboard64::EMPTY, |mut acc, c| {
+                acc.set(StandardBoard::to_index(c).unwrap());
+                acc
+            })
+        };
+        let occupied = Bitboard64::from_squares(&[9, 20, 27, 45, 50, 62]);
+        let is_occupied = |c: Coord| occupied.get(StandardBoard::to_index(&c).unwrap());
+        let on_board = |c: Coord| StandardBoard::is_valid(&c);
+
+        let knight = PieceDefinition::knight();
+        let bishop = PieceDefinition::bishop();
+        let pawn = PieceDefinition::pawn_white();
+        for from in StandardBoard::all_coords() {
+            let sq = StandardBoard::to_index(&from).unwrap();
+            assert_eq!(
+                to_bitboard(knight.attacks(from, is_occupied, on_board)),
+                knight_attacks(sq)
+            );
+            assert_eq!(
+                to_bitboard(bishop.attacks(from, is_occupied, on_board)),
+                bishop_attacks(sq, occupied)
+            );
+            assert_eq!(
+                to_bitboard(pawn.attacks(from, is_occupied, on_board)),
+                pawn_attacks(sq, Color::White as usize)
+            );
+        }
+    }
+
+    #[test]
+    fn test_attacks_respect_max_distance() {
+        let king = PieceDefinition::king();
+        let attacks = king.attacks(Coord::new(0, 0), |_| false, |c| c.file < 8 && c.rank < 8);
+        assert_eq!(
+            attacks,
+            vec![Coord::new(1, 0), Coord::new(0, 1), Coord::new(1, 1)]
+        );
+    }
+
+    #[test]
+    fn test_from_betza() {
+        let knight = PieceDefinition::from_betza("Knight", "N").unwrap();
+        assert_eq!(knight.name, "Knight");
+        assert_eq!(knight.movements, PieceDefinition::knight().movements);
+
+        let queen = PieceDefinition::from_betza("Queen", "Q").unwrap();
+        match &queen.movements[..] {
+            [MovementType::Slide {
+                directions,
+                max_distance: None,
+            }] => {
+                assert_eq!(directions.len(), 8);
+                for delta in [Delta::new(1, 0), Delta::new(-1, -1), Delta::new(0, 1)] {
+                    assert!(directions.contains(&delta));
+                }
+            }
+            other => panic!("Expected a single unlimited slide, got {:?}", other),
+        }
+
+        // Chancellor: rook slides plus knight leaps
+        let chancellor = PieceDefinition::from_betza("Chancellor", "RN").unwrap();
+        assert_eq!(chancellor.movements.len(), 2);
+        assert!(matches!(chancellor.movements[1], MovementType::Leap { .. }));
+
+        let wazir = PieceDefinition::from_betza("Wazir", "W").unwrap();
+        assert!(
+            matches!(&wazir.movements[0], MovementType::Leap { offsets } if offsets.len() == 4)
+        );
+    }
+
+    #[test]
+    fn test_from_betza_range_and_riders() {
+        let short_rook = PieceDefinition::from_betza("Short rook", "R4").unwrap();
+        assert!(matches!(
+            short_rook.movements[0],
+            MovementType::Slide {
+                max_distance: Some(4),
+                ..
+            }
+        ));
+
+        let nightrider = PieceDefinition::from_betza("Nightrider", "NN").unwrap();
+        assert!(matches!(
+            &nightrider.movements[0],
+            MovementType::Ride { offsets, max_distance: None } if offsets.len() == 8
+        ));
+
+        // The king atom matches the hand-written king's squares
+        let king = PieceDefinition::from_betza("King", "K").unwrap();
+        let on_board = |c: Coord| c.file < 8 && c.rank < 8;
+        assert_eq!(
+            king.attacks(Coord::new(4, 3), |_| false, on_board),
+            PieceDefinition::king().attacks(Coord::new(4, 3), |_| false, on_board)
+        );
+
+        for invalid in ["", "X", "mR", "R0"] {
+            assert!(
+                PieceDefinition::from_betza("Invalid", invalid).is_none(),
+                "{}",
+                invalid
+            );
+        }
+    }
+
+    #[test]
+    fn test_fairy_piece_definitions() {
+        let leap_count = |def: &PieceDefinition, i: usize| match &def.movements[i] {
+            MovementType::Leap { offsets } => offsets.len(),
+            other => panic!("Expected Leap for {}, got {:?}", def.name, other),
+        };
+
+        let chancellor = PieceDefinition::chancellor();
+        assert!(matches!(
+            &chancellor.movements[0],
+            MovementType::Slide { directions, max_distance: None } if directions.len() == 4
+        ));
+        assert_eq!(leap_count(&chancellor, 1), 8);
+
+        let archbishop = PieceDefinition::archbishop();
+        assert!(matches!(
+            &archbishop.movements[0],
+            MovementType::Slide { directions, .. } if directions.contains(&Delta::new(1, 1))
+        ));
+        assert_eq!(leap_count(&archbishop, 1), 8);
+
+        assert_eq!(leap_count(&PieceDefinition::zebra(), 0), 8);
+        assert_eq!(leap_count(&PieceDefinition::wazir(), 0), 4);
+        assert_eq!(leap_count(&PieceDefinition::ferz(), 0), 4);
+
+        let nightrider = PieceDefinition::nightrider();
+        assert!(matches!(
+            &nightrider.movements[0],
+            MovementType::Ride { offsets, max_distance: None } if offsets.len() == 8
+        ));
+    }
+
+    #[test]
+    fn test_nightrider_attacks() {
+        let on_board = |c: Coord| c.file < 8 && c.rank < 8;
+        let nightrider = PieceDefinition::nightrider();
+
+        // From a1 along (1, 2): b3, c5, d7
+        let attacks = nightrider.attacks(Coord::new(0, 0), |_| false, on_board);
+        for square in [(1, 2), (2, 4), (3, 6), (2, 1), (4, 2), (6, 3)] {
+            assert!(attacks.contains(&Coord::new(square.0, square.1)));
+        }
+        assert_eq!(attacks.len(), 6);
+
+        // A piece on c5 stops the ride there
+        let blocked = nightrider.attacks(Coord::new(0, 0), |c| c == Coord::new(2, 4), on_board);
+        assert!(blocked.contains(&Coord::new(2, 4)));
+        assert!(!blocked.contains(&Coord::new(3, 6)));
+    }
+
     #[test]
     fn test_camel_offsets() {
         let camel = PieceDefinition::camel();
@@ -0,0 +1,132 @@
+//! Zobrist hashing keys for positions.
+//!
+//! Every (piece, square) pair, the side to move, each castling right, and
+//! each en passant file gets a fixed pseudo-random key. A position's hash is
+//! the XOR of the keys of its features, so a move updates it by toggling only
+//! the keys that changed.
+
+use super::{CastlingRights, Color, Coord, GameState, Piece, StandardBoard};
+use std::sync::OnceLock;
+
+/// Seed for the key generator; fixed so hashes are stable across runs.
+const SEED: u64 = 0x1CE_C0FF_EE15_600D;
+
+/// All Zobrist keys.
+struct Keys {
+    /// Indexed by color, piece type, then square.
+    pieces: [[[u64; 64]; 6]; 2],
+    side: u64,
+    /// White kingside, white queenside, black kingside, black queenside.
+    castling: [u64; 4],
+    en_passant: [u64; 8],
+}
+
+/// Global key table (initialized once).
+static KEYS: OnceLock<Keys> = OnceLock::new();
+
+/// Fills the key table from a SplitMix64 stream.
+fn init_keys() -> Keys {
+    let mut state = SEED;
+    let mut next = || {
+        state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    };
+
+    let mut keys = Keys {
+        pieces: [[[0; 64]; 6]; 2],
+        side: 0,
+        castling: [0; 4],
+        en_passant: [0; 8],
+    };
+    for key in keys.pieces.iter_mut().flatten().flatten() {
+        *key = next();
+    }
+    keys.side = next();
+    for key in keys.castling.iter_mut().chain(keys.en_passant.iter_mut()) {
+        *key = next();
+    }
+    keys
+}
+
+fn keys() -> &'static Keys {
+    KEYS.get_or_init(init_keys)
+}
+
+/// Returns the key for `piece` standing on `coord`.
+pub fn piece_key(piece: Piece, coord: Coord) -> u64 {
+    let sq = StandardBoard::to_index(&coord).expect("Coordinate must be on the board");
+    keys().pieces[piece.color as usize][piece.piece_type as usize][sq]
+}
+
+/// Returns the key toggled when Black is to move.
+pub fn side_key() -> u64 {
+    keys().side
+}
+
+/// Returns the combined key for both sides' castling rights.
+pub fn castling_key(white: CastlingRights, black: CastlingRights) -> u64 {
+    let castling = &keys().castling;
+    [
+        white.kingside,
+        white.queenside,
+        black.kingside,
+        black.queenside,
+    ]
+    .iter()
+    .zip(castling)
+    .filter(|(&allowed, _)| allowed)
+    .fold(0, |acc, (_, key)| acc ^ key)
+}
+
+/// Returns the key for the en passant square's file, or 0 if there is none.
+pub fn en_passant_key(en_passant: Option<Coord>) -> u64 {
+    en_passant.map_or(0, |ep| keys().en_passant[ep.file as usize])
+}
+
+/// Computes a position's hash from scratch.
+pub fn hash_position(game: &GameState) -> u64 {
+    let pieces = game
+        .board()
+        .pieces()
+        .fold(0, |acc, (coord, piece)| acc ^ piece_key(piece, coord));
+    let side = match game.side_to_move() {
+        Color::White => 0,
+        Color::Black => side_key(),
+    };
+    pieces
+        ^ side
+        ^ castling_key(
+            game.castling_rights(Color::White),
+            game.castling_rights(Color::Black),
+        )
+        ^ en_passant_key(game.en_passant())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_keys_distinct() {
+        let keys = keys();
+        let mut all: Vec<u64> = keys.pieces.iter().flatten().flatten().copied().collect();
+        all.push(keys.side);
+        all.extend(keys.castling);
+        all.extend(keys.en_passant);
+        let count = all.len();
+        all.sort_unstable();
+        all.dedup();
+        assert_eq!(all.len(), count);
+        assert!(!all.contains(&0));
+    }
+
+    #[test]
+    fn test_side_to_move_changes_hash() {
+        let white = GameState::from_fen("4k3/8/8/8/8/8/8/4K3 w - - 0 1").unwrap();
+        let black = GameState::from_fen("4k3/8/8/8/8/8/8/4K3 b - - 0 1").unwrap();
+        assert_eq!(hash_position(&white) ^ hash_position(&black), side_key());
+    }
+}
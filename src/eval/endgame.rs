@@ -0,0 +1,105 @@
+//! Endgame-specific evaluation terms.
+
+use super::material::material;
+use super::phase::{game_phase, MAX_PHASE};
+use crate::core::{Color, GameState, StandardBoard};
+
+/// Centipawns awarded per step the king stands away from the edge.
+const KING_ACTIVITY_WEIGHT: i32 = 10;
+
+/// Material lead required before the mop-up term applies.
+const MOP_UP_MARGIN: i32 = 400;
+
+/// Centipawns awarded per step the losing king is pushed toward the edge.
+const MOP_UP_EDGE_WEIGHT: i32 = 20;
+
+/// Centipawns awarded per step the kings are brought closer together.
+const MOP_UP_PROXIMITY_WEIGHT: i32 = 5;
+
+/// Rewards a centralized king in the endgame.
+///
+/// The bonus grows with the king's distance from the nearest edge and is
+/// scaled by how far the game has simplified, so it vanishes in the opening
+/// where the king belongs in safety.
+pub fn king_activity(game: &GameState, color: Color) -> i32 {
+    let Some(king) = game.board().find_king(color) else {
+        return 0;
+    };
+    let centralization = StandardBoard::distance_to_edge(&king).unwrap_or(0) as i32;
+    let endgame_weight = MAX_PHASE - game_phase(game);
+
+    centralization * KING_ACTIVITY_WEIGHT * endgame_weight / MAX_PHASE
+}
+
+/// Rewards `winning` for driving the enemy king to the edge in a won endgame.
+///
+/// Applies only when `winning` is ahead by a clear material margin. The
+/// bonus grows as the losing king nears the edge and as the kings approach
+/// each other, and is scaled by how far the game has simplified.
+pub fn mop_up(game: &GameState, winning: Color) -> i32 {
+    let losing = winning.opposite();
+    if material(game, winning) - material(game, losing) < MOP_UP_MARGIN {
+        return 0;
+    }
+    let board = game.board();
+    let (Some(our_king), Some(their_king)) = (board.find_king(winning), board.find_king(losing))
+    else {
+        return 0;
+    };
+
+    let max_edge = (StandardBoard::WIDTH.min(StandardBoard::HEIGHT) as i32 - 1) / 2;
+    let edge = StandardBoard::distance_to_edge(&their_king).unwrap_or(0) as i32;
+    let max_distance = StandardBoard::WIDTH.max(StandardBoard::HEIGHT) as i32 - 1;
+    let distance = our_king.chebyshev_distance(&their_king) as i32;
+    let endgame_weight = MAX_PHASE - game_phase(game);
+
+    let bonus = (max_edge - edge) * MOP_UP_EDGE_WEIGHT
+        + (max_distance - distance) * MOP_UP_PROXIMITY_WEIGHT;
+    bonus * endgame_weight / MAX_PHASE
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_king_activity_endgame() {
+        let central = GameState::from_fen("8/8/8/8/3K4/8/4P3/k7 w - - 0 1").unwrap();
+        let corner = GameState::from_fen("8/8/8/8/8/8/4P3/K6k w - - 0 1").unwrap();
+
+        assert!(king_activity(&central, Color::White) > king_activity(&corner, Color::White));
+        assert_eq!(king_activity(&corner, Color::White), 0);
+    }
+
+    #[test]
+    fn test_king_activity_suppressed_in_middlegame() {
+        // Full material with a wandering king: no activity bonus
+        let game = GameState::from_fen("rnbqkbnr/pppppppp/8/8/3K4/8/PPPPPPPP/RNBQ1BNR w kq - 0 1")
+            .unwrap();
+        let endgame = GameState::from_fen("4k3/8/8/8/3K4/8/8/8 w - - 0 1").unwrap();
+
+        assert_eq!(king_activity(&game, Color::White), 0);
+        assert!(king_activity(&endgame, Color::White) > 0);
+    }
+
+    #[test]
+    fn test_mop_up_prefers_enemy_king_on_edge() {
+        let center = GameState::from_fen("8/8/8/8/3k4/8/8/3QK3 w - - 0 1").unwrap();
+        let edge = GameState::from_fen("8/8/8/8/8/8/8/k2QK3 w - - 0 1").unwrap();
+        let near_edge = GameState::from_fen("8/8/8/8/8/1k6/8/3QK3 w - - 0 1").unwrap();
+
+        let center_score = mop_up(&center, Color::White);
+        assert!(mop_up(&near_edge, Color::White) > center_score);
+        assert!(mop_up(&edge, Color::White) > mop_up(&near_edge, Color::White));
+
+        // The defending side gets nothing
+        assert_eq!(mop_up(&center, Color::Black), 0);
+    }
+
+    #[test]
+    fn test_mop_up_rewards_approaching_king() {
+        let far = GameState::from_fen("k7/8/8/8/8/8/8/3Q3K w - - 0 1").unwrap();
+        let close = GameState::from_fen("k7/8/1K6/8/8/8/8/3Q4 w - - 0 1").unwrap();
+        assert!(mop_up(&close, Color::White) > mop_up(&far, Color::White));
+    }
+}
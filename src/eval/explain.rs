@@ -0,0 +1,249 @@
+//! Human-readable breakdown of the evaluation into labeled terms.
+
+use super::endgame::{king_activity, mop_up};
+use super::fortress::is_likely_fortress;
+use super::king_safety::king_safety;
+use super::material::material;
+use super::mobility::mobility;
+use super::pawns::pawn_structure;
+use super::pst::piece_square;
+use super::space::space;
+use crate::core::{Color, GameState};
+
+/// Centipawns awarded per square of space.
+const SPACE_WEIGHT: i32 = 2;
+
+/// Centipawns awarded per square of mobility.
+const MOBILITY_WEIGHT: i32 = 2;
+
+/// Factor by which the evaluation of a likely fortress is scaled down.
+const FORTRESS_DAMPING: i32 = 8;
+
+/// How much detail explanation functions produce.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Verbosity {
+    /// A single sentence for beginners
+    Brief,
+    /// A summary plus the terms that matter, without numbers
+    #[default]
+    Normal,
+    /// Every term with its centipawn value
+    Detailed,
+}
+
+/// Options controlling explanation output.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ExplainOptions {
+    /// Amount of detail to produce
+    pub verbosity: Verbosity,
+}
+
+impl ExplainOptions {
+    /// Creates options with the given verbosity.
+    pub fn new(verbosity: Verbosity) -> Self {
+        ExplainOptions { verbosity }
+    }
+}
+
+/// A single labeled evaluation term.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EvalTerm {
+    /// Short name of the term, e.g. "material"
+    pub name: &'static str,
+    /// Signed centipawn contribution from the side to move's perspective
+    pub score: i32,
+}
+
+impl EvalTerm {
+    /// Returns the term in pawns with its name, e.g. "+3.0 material".
+    pub fn describe(&self) -> String {
+        format!("{:+.1} {}", self.score as f64 / 100.0, self.name)
+    }
+}
+
+/// The evaluation split into the terms that make it up.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EvalBreakdown {
+    /// Contributing terms, in a fixed order
+    pub terms: Vec<EvalTerm>,
+}
+
+impl EvalBreakdown {
+    /// Returns the sum of all terms, which equals `evaluate`.
+    pub fn total(&self) -> i32 {
+        self.terms.iter().map(|term| term.score).sum()
+    }
+
+    /// Returns the breakdown from the other side's perspective.
+    pub fn flipped(&self) -> EvalBreakdown {
+        EvalBreakdown {
+            terms: self
+                .terms
+                .iter()
+                .map(|term| EvalTerm {
+                    name: term.name,
+                    score: -term.score,
+                })
+                .collect(),
+        }
+    }
+
+    /// Returns a one-line summary in pawns listing the non-zero terms,
+    /// e.g. "+1.2: +3.0 material, -1.8 king safety".
+    pub fn one_line(&self) -> String {
+        let terms: Vec<String> = self
+            .terms
+            .iter()
+            .filter(|term| term.score != 0)
+            .map(EvalTerm::describe)
+            .collect();
+        let total = format!("{:+.1}", self.total() as f64 / 100.0);
+        if terms.is_empty() {
+            total
+        } else {
+            format!("{}: {}", total, terms.join(", "))
+        }
+    }
+
+    /// Returns the term contributing the most in absolute value.
+    fn dominant_term(&self) -> Option<&EvalTerm> {
+        self.terms
+            .iter()
+            .filter(|term| term.score != 0)
+            .max_by_key(|term| term.score.abs())
+    }
+
+    /// Renders the breakdown as text at the requested verbosity.
+    pub fn explain(&self, options: &ExplainOptions) -> String {
+        let mut text = self.summary();
+        match options.verbosity {
+            Verbosity::Brief => {}
+            Verbosity::Normal => {
+                for term in self.terms.iter().filter(|term| term.score != 0) {
+                    let side = if term.score > 0 { "Ahead" } else { "Behind" };
+                    text.push_str(&format!(" {} in {}.", side, term.name));
+                }
+            }
+            Verbosity::Detailed => {
+                for term in &self.terms {
+                    text.push_str(&format!("\n  {}: {:+} cp", term.name, term.score));
+                }
+                text.push_str(&format!("\n  total: {:+} cp", self.total()));
+            }
+        }
+        text
+    }
+
+    /// Returns a one-sentence summary of the evaluation.
+    fn summary(&self) -> String {
+        let total = self.total();
+        let Some(main) = self.dominant_term() else {
+            return "The position is balanced.".to_string();
+        };
+        match total {
+            0 => "The position is balanced.".to_string(),
+            t if t > 0 => format!("The side to move is better, mainly due to {}.", main.name),
+            _ => format!("The side to move is worse, mainly due to {}.", main.name),
+        }
+    }
+}
+
+/// Breaks the evaluation down into labeled terms.
+///
+/// All terms are from the side to move's perspective and sum to `evaluate`.
+pub fn explain(game: &GameState) -> EvalBreakdown {
+    let us = game.side_to_move();
+    let them = us.opposite();
+    let term = |name, f: fn(&GameState, Color) -> i32, weight: i32| EvalTerm {
+        name,
+        score: (f(game, us) - f(game, them)) * weight,
+    };
+
+    let mut breakdown = EvalBreakdown {
+        terms: vec![
+            term("material", material, 1),
+            term("mobility", mobility, MOBILITY_WEIGHT),
+            term("king safety", king_safety, 1),
+            term(
+                "pawn structure",
+                |game, color| pawn_structure(game, color).score,
+                1,
+            ),
+            term("piece activity", piece_square, 1),
+            term("king activity", king_activity, 1),
+            term("mop-up", mop_up, 1),
+            term("space", space, SPACE_WEIGHT),
+        ],
+    };
+
+    // Pull fortress positions toward a draw despite the material imbalance
+    if is_likely_fortress(game) {
+        let total = breakdown.total();
+        breakdown.terms.push(EvalTerm {
+            name: "fortress",
+            score: total / FORTRESS_DAMPING - total,
+        });
+    }
+    breakdown
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::eval::evaluate;
+
+    #[test]
+    fn test_total_matches_evaluate() {
+        for fen in [
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+            "4k3/8/8/8/8/8/8/3QK3 b - - 0 1",
+            "8/4P3/8/8/3K4/8/8/k7 w - - 0 1",
+            "7k/8/6K1/7P/8/8/8/5B2 w - - 0 1",
+            "5rk1/5p1p/8/7Q/8/8/5P1P/6RK b - - 0 1",
+            "r1bq1rk1/pp2bppp/2n1pn2/3p4/2PP4/2N2N2/PP2BPPP/R2QKB1R w KQ - 0 8",
+            "4k3/8/8/8/2P5/1PP4P/8/4K3 w - - 0 1",
+        ] {
+            let game = GameState::from_fen(fen).unwrap();
+            assert_eq!(explain(&game).total(), evaluate(&game));
+        }
+    }
+
+    #[test]
+    fn test_one_line_summary() {
+        let game = GameState::from_fen("4k3/8/8/8/8/8/8/3QK3 w - - 0 1").unwrap();
+        let line = explain(&game).one_line();
+        assert!(line.starts_with('+'));
+        assert!(line.contains("+9.0 material"));
+
+        let term = EvalTerm {
+            name: "king safety",
+            score: -180,
+        };
+        assert_eq!(term.describe(), "-1.8 king safety");
+        let balanced = explain(&GameState::starting_position());
+        assert_eq!(balanced.one_line(), "+0.0");
+    }
+
+    #[test]
+    fn test_verbosity() {
+        let game = GameState::from_fen("4k3/8/8/8/8/8/8/3QK3 w - - 0 1").unwrap();
+        let breakdown = explain(&game);
+
+        let brief = breakdown.explain(&ExplainOptions::new(Verbosity::Brief));
+        assert_eq!(brief.matches('.').count(), 1);
+        assert!(brief.ends_with('.'));
+        assert!(brief.contains("material"));
+
+        let detailed = breakdown.explain(&ExplainOptions::new(Verbosity::Detailed));
+        assert!(detailed.contains("material: +900 cp"));
+        assert!(detailed.contains(&format!("total: {:+} cp", breakdown.total())));
+    }
+
+    #[test]
+    fn test_fortress_damps_evaluation() {
+        let fortress = GameState::from_fen("7k/8/6K1/7P/8/8/8/5B2 w - - 0 1").unwrap();
+        let breakdown = explain(&fortress);
+        assert!(breakdown.terms.iter().any(|t| t.name == "fortress"));
+        assert!(breakdown.total() < 100);
+    }
+}
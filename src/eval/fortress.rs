@@ -0,0 +1,165 @@
+//! Heuristic detection of fortress-like drawn positions.
+
+use super::material::material;
+use super::pawns::passed_pawns;
+use crate::core::{Color, Coord, GameState, Piece, PieceType, StandardBoard};
+use crate::movegen::piece_attacks;
+
+/// Returns the non-king, non-pawn pieces of `color` with their squares.
+fn minor_and_major_pieces(game: &GameState, color: Color) -> Vec<(Coord, Piece)> {
+    game.board()
+        .pieces()
+        .filter(|(_, p)| p.color == color)
+        .filter(|(_, p)| !matches!(p.piece_type, PieceType::King | PieceType::Pawn))
+        .collect()
+}
+
+/// Returns the squares of `color`'s pawns.
+fn pawns(game: &GameState, color: Color) -> Vec<Coord> {
+    let pawn = Piece::new(PieceType::Pawn, color);
+    game.board()
+        .pieces()
+        .filter(|(_, p)| *p == pawn)
+        .map(|(coord, _)| coord)
+        .collect()
+}
+
+/// Rook pawns with a bishop that cannot control the promotion square.
+///
+/// The defending king only needs to reach the corner; the stronger side can
+/// never drive it out without stalemating it.
+fn wrong_bishop_rook_pawn(game: &GameState, strong: Color) -> bool {
+    let weak = strong.opposite();
+    if !minor_and_major_pieces(game, weak).is_empty() || !pawns(game, weak).is_empty() {
+        return false;
+    }
+    let strong_pawns = pawns(game, strong);
+    let Some(first) = strong_pawns.first() else {
+        return false;
+    };
+    let file = first.file;
+    if !(file == 0 || file == StandardBoard::WIDTH - 1)
+        || strong_pawns.iter().any(|p| p.file != file)
+    {
+        return false;
+    }
+
+    let promotion_rank = match strong {
+        Color::White => StandardBoard::HEIGHT - 1,
+        Color::Black => 0,
+    };
+    let promotion = Coord::new(file, promotion_rank);
+    let wrong_bishops = minor_and_major_pieces(game, strong)
+        .iter()
+        .all(|(coord, piece)| {
+            piece.piece_type == PieceType::Bishop
+                && coord.square_color() != promotion.square_color()
+        });
+    let king_in_corner = game
+        .board()
+        .find_king(weak)
+        .is_some_and(|king| king.chebyshev_distance(&promotion) <= 1);
+
+    wrong_bishops && king_in_corner
+}
+
+/// Opposite-colored bishops with every passed pawn blockaded.
+///
+/// With one bishop each on different colors and at most a pawn more, the
+/// defender holds as long as its king or bishop stops each passer.
+fn blockaded_opposite_bishops(game: &GameState, strong: Color) -> bool {
+    let weak = strong.opposite();
+    let (strong_pieces, weak_pieces) = (
+        minor_and_major_pieces(game, strong),
+        minor_and_major_pieces(game, weak),
+    );
+    let ([(strong_bishop, sp)], [(weak_bishop, wp)]) = (&strong_pieces[..], &weak_pieces[..])
+    else {
+        return false;
+    };
+    if sp.piece_type != PieceType::Bishop
+        || wp.piece_type != PieceType::Bishop
+        || strong_bishop.square_color() == weak_bishop.square_color()
+        || pawns(game, strong).len() > pawns(game, weak).len() + 1
+    {
+        return false;
+    }
+
+    let board = game.board();
+    let occupied = board.occupied();
+    let defenders: Vec<(Coord, Piece)> = board
+        .pieces()
+        .filter(|(_, p)| {
+            p.color == weak && matches!(p.piece_type, PieceType::King | PieceType::Bishop)
+        })
+        .collect();
+    let stops = |square: &Coord| {
+        let sq = StandardBoard::to_index(square).unwrap();
+        defenders.iter().any(|(coord, piece)| {
+            coord == square
+                || piece_attacks(*piece, StandardBoard::to_index(coord).unwrap(), occupied).get(sq)
+        })
+    };
+
+    passed_pawns(game, strong).iter().all(|pawn| {
+        let path: Vec<Coord> = (1..StandardBoard::HEIGHT)
+            .filter_map(|step| match strong {
+                Color::White => pawn.rank.checked_add(step),
+                Color::Black => pawn.rank.checked_sub(step),
+            })
+            .map(|rank| Coord::new(pawn.file, rank))
+            .filter(StandardBoard::is_valid)
+            .collect();
+        path.iter().any(stops)
+    })
+}
+
+/// Returns true if the position looks like a fortress the defender can hold.
+///
+/// Recognizes a rook pawn with the wrong-colored bishop (or no bishop) against
+/// a king in the corner, and opposite-colored bishops where every passed pawn
+/// is blockaded. The checks are conservative to avoid damping real wins.
+pub fn is_likely_fortress(game: &GameState) -> bool {
+    let (white, black) = (material(game, Color::White), material(game, Color::Black));
+    let strong = match white.cmp(&black) {
+        std::cmp::Ordering::Greater => Color::White,
+        std::cmp::Ordering::Less => Color::Black,
+        std::cmp::Ordering::Equal => return false,
+    };
+    wrong_bishop_rook_pawn(game, strong) || blockaded_opposite_bishops(game, strong)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wrong_bishop_fortress() {
+        // Light-squared bishop cannot control the dark h8 corner
+        let game = GameState::from_fen("7k/8/6K1/7P/8/8/8/5B2 w - - 0 1").unwrap();
+        assert!(is_likely_fortress(&game));
+        assert!(is_likely_fortress(&game.mirrored()));
+    }
+
+    #[test]
+    fn test_opposite_bishops_blockade() {
+        // White's extra d-pawn is blockaded by the dark-squared bishop
+        let game = GameState::from_fen("8/5k2/3b4/8/3P4/4K3/4B3/8 w - - 0 1").unwrap();
+        assert!(is_likely_fortress(&game));
+    }
+
+    #[test]
+    fn test_winning_positions_are_not_fortresses() {
+        // Right-colored bishop
+        let game = GameState::from_fen("7k/8/6K1/7P/8/8/8/4B3 w - - 0 1").unwrap();
+        assert!(!is_likely_fortress(&game));
+
+        // Extra queen
+        let game = GameState::from_fen("4k3/8/8/8/8/8/8/3QK3 w - - 0 1").unwrap();
+        assert!(!is_likely_fortress(&game));
+
+        // Defending king far from the corner
+        let game = GameState::from_fen("8/8/8/7P/8/2k5/8/K4B2 w - - 0 1").unwrap();
+        assert!(!is_likely_fortress(&game));
+    }
+}
@@ -0,0 +1,84 @@
+//! King safety: enemy pressure on the king zone, weighted toward the middlegame.
+
+use super::phase::{game_phase, taper};
+use crate::core::{Color, GameState, PieceType, StandardBoard};
+use crate::movegen::{attackers_to, Bitboard64};
+
+/// Weight of one attack on a king-zone square by each piece type.
+fn attack_weight(piece_type: PieceType) -> i32 {
+    match piece_type {
+        PieceType::Pawn => 1,
+        PieceType::Knight | PieceType::Bishop => 2,
+        PieceType::Rook => 3,
+        PieceType::Queen => 5,
+        PieceType::King => 0,
+    }
+}
+
+/// Percentage of the pressure that counts, by number of distinct attackers.
+///
+/// A lone attacker rarely breaks through; several together do.
+const ATTACKER_SCALING: [i32; 8] = [0, 50, 75, 88, 94, 97, 99, 100];
+
+/// Centipawns lost per point of scaled king-zone pressure in the middlegame.
+const KING_PRESSURE_WEIGHT: i32 = 4;
+
+/// Returns the king-safety score of `color`: zero when unattacked, negative
+/// as enemy pieces bear down on the squares around the king.
+///
+/// Every attack on a zone square adds its piece-type weight, and the sum is
+/// scaled up with the number of distinct attackers. The penalty fades out
+/// toward the endgame, where the king is expected to come forward.
+pub fn king_safety(game: &GameState, color: Color) -> i32 {
+    let board = game.board();
+    let Some(king) = board.find_king(color) else {
+        return 0;
+    };
+    let occupied = board.occupied();
+    let enemies = board.pieces_of_color(color.opposite());
+
+    let mut pressure = 0;
+    let mut attackers = Bitboard64::EMPTY;
+    for coord in StandardBoard::neighbors(king, 1) {
+        let sq = StandardBoard::to_index(&coord).unwrap();
+        let hits = attackers_to(board, sq, occupied) & enemies;
+        attackers |= hits;
+        pressure += hits
+            .iter()
+            .filter_map(|from| StandardBoard::from_index(from).and_then(|c| board.piece_at(&c)))
+            .map(|piece| attack_weight(piece.piece_type))
+            .sum::<i32>();
+    }
+
+    let count = (attackers.popcount() as usize).min(ATTACKER_SCALING.len() - 1);
+    let scaled = pressure * ATTACKER_SCALING[count] / 100;
+    taper(-scaled * KING_PRESSURE_WEIGHT, 0, game_phase(game))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_attacked_king_is_less_safe() {
+        let attack = GameState::from_fen("5rk1/5p1p/8/7Q/8/8/5P1P/6RK w - - 0 1").unwrap();
+        assert!(king_safety(&attack, Color::Black) < 0);
+        assert_eq!(
+            king_safety(&GameState::starting_position(), Color::White),
+            0
+        );
+    }
+
+    #[test]
+    fn test_exposed_king_worse_than_castled() {
+        // Same material; the d1 king sits on open files facing the queen and
+        // rook, while the g1 king hides behind intact pawns
+        let exposed = GameState::from_fen("3qr1k1/ppp2ppp/8/8/8/8/PP3PPP/R2K3R w - - 0 1").unwrap();
+        let castled = GameState::from_fen("3qr1k1/ppp2ppp/8/8/8/8/PP3PPP/R4RK1 w - - 0 1").unwrap();
+
+        let exposed_score = king_safety(&exposed, Color::White);
+        let castled_score = king_safety(&castled, Color::White);
+        assert!(exposed_score < castled_score);
+        assert_eq!(castled_score, 0);
+    }
+}
@@ -0,0 +1,28 @@
+//! Material counting.
+
+use crate::core::{Color, GameState, PieceType};
+
+/// Returns the standard centipawn value of a piece type.
+///
+/// The king has no material value since it can never be traded.
+pub fn piece_value(piece_type: PieceType) -> i32 {
+    piece_type.value()
+}
+
+/// Returns the total material of `color` in centipawns.
+pub fn material(game: &GameState, color: Color) -> i32 {
+    game.board().material(color)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_starting_material() {
+        let game = GameState::starting_position();
+        // 8 pawns, 2 knights, 2 bishops, 2 rooks, 1 queen
+        assert_eq!(material(&game, Color::White), 800 + 640 + 660 + 1000 + 900);
+        assert_eq!(material(&game, Color::White), material(&game, Color::Black));
+    }
+}
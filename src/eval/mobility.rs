@@ -0,0 +1,85 @@
+//! Mobility: how many legal destinations a side's pieces have.
+
+use crate::core::{Color, GameState, PieceType, StandardBoard};
+use crate::movegen::{generate_legal_moves, piece_attacks};
+
+/// Counts the legal destinations of all of `color`'s pieces of type `pt`.
+///
+/// If `color` is not to move, the turn is passed first so its moves can be
+/// generated. A promotion counts once however many piece choices it offers.
+pub fn piece_type_mobility(game: &GameState, color: Color, pt: PieceType) -> u32 {
+    let mut position = game.clone();
+    if position.side_to_move() != color {
+        position.make_null_move();
+    }
+
+    let board = position.board();
+    generate_legal_moves(&position)
+        .iter()
+        .filter(|mv| matches!(mv.promoted_piece(), None | Some(PieceType::Queen)))
+        .filter(|mv| board.piece_at(&mv.from).is_some_and(|p| p.piece_type == pt))
+        .count() as u32
+}
+
+/// Counts the squares `color`'s knights, bishops, rooks and queens attack
+/// that are not occupied by their own pieces.
+///
+/// A cheap pseudo-legal measure for the evaluation; pins and checks are
+/// ignored.
+pub fn mobility(game: &GameState, color: Color) -> i32 {
+    let board = game.board();
+    let occupied = board.occupied();
+    let own = board.pieces_of_color(color);
+    board
+        .pieces()
+        .filter(|(_, piece)| {
+            piece.color == color && !matches!(piece.piece_type, PieceType::Pawn | PieceType::King)
+        })
+        .map(|(coord, piece)| {
+            let sq = StandardBoard::to_index(&coord).unwrap();
+            (piece_attacks(piece, sq, occupied) & !own).popcount() as i32
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_knight_mobility_start() {
+        let game = GameState::starting_position();
+        assert_eq!(
+            piece_type_mobility(&game, Color::White, PieceType::Knight),
+            4
+        );
+        assert_eq!(
+            piece_type_mobility(&game, Color::Black, PieceType::Knight),
+            4
+        );
+        assert_eq!(
+            piece_type_mobility(&game, Color::White, PieceType::Bishop),
+            0
+        );
+        assert_eq!(
+            piece_type_mobility(&game, Color::White, PieceType::Pawn),
+            16
+        );
+    }
+
+    #[test]
+    fn test_pseudo_legal_mobility() {
+        let game = GameState::starting_position();
+        assert_eq!(mobility(&game, Color::White), 4);
+        assert_eq!(mobility(&game, Color::Black), 4);
+
+        let game = GameState::from_fen("4k3/8/8/8/3Q4/8/8/4K3 w - - 0 1").unwrap();
+        assert_eq!(mobility(&game, Color::White), 27);
+    }
+
+    #[test]
+    fn test_promotion_counted_once() {
+        let game = GameState::from_fen("k7/4P3/8/8/8/8/8/K7 w - - 0 1").unwrap();
+        assert_eq!(piece_type_mobility(&game, Color::White, PieceType::Pawn), 1);
+    }
+}
@@ -1,3 +1,84 @@
 //! Position evaluation module.
 
-// TODO: Implement evaluation function
+pub mod endgame;
+pub mod explain;
+pub mod fortress;
+pub mod king_safety;
+pub mod material;
+pub mod mobility;
+pub mod pawns;
+pub mod phase;
+pub mod pst;
+pub mod space;
+
+pub use explain::{explain, EvalBreakdown, EvalTerm, ExplainOptions, Verbosity};
+pub use fortress::is_likely_fortress;
+pub use king_safety::king_safety;
+pub use material::{material, piece_value};
+pub use mobility::{mobility, piece_type_mobility};
+pub use pawns::{passed_pawn_score, passed_pawns, pawn_structure, PawnScore};
+pub use phase::{game_phase, taper, MAX_PHASE};
+pub use pst::{piece_square, square_bonus};
+pub use space::space;
+
+use crate::core::{GameState, Move};
+
+/// Evaluates the position in centipawns from the side to move's perspective.
+///
+/// Positive scores favor the side to move, not White. This is the sum of
+/// the terms reported by [`explain`]: material, mobility, king safety, pawn
+/// structure, tapered piece-square tables, and the endgame and space terms.
+pub fn evaluate(game: &GameState) -> i32 {
+    explain(game).total()
+}
+
+/// Returns how much `mv` changes the static evaluation for the side playing it.
+///
+/// Positive values mean the move improves the mover's position. The move is
+/// assumed to be legal; it is made and unmade in place, leaving `game` as it
+/// was.
+pub fn move_delta(game: &mut GameState, mv: &Move) -> i32 {
+    let before = evaluate(game);
+    let undo = game.make_move(mv);
+    let after = -evaluate(game);
+    game.unmake_move(mv, undo);
+    after - before
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_evaluate_side_to_move_perspective() {
+        assert_eq!(evaluate(&GameState::starting_position()), 0);
+
+        let white = GameState::from_fen("4k3/8/8/8/8/8/8/3QK3 w - - 0 1").unwrap();
+        let black = GameState::from_fen("4k3/8/8/8/8/8/8/3QK3 b - - 0 1").unwrap();
+        assert!(evaluate(&white) >= 900);
+        assert_eq!(evaluate(&black), -evaluate(&white));
+    }
+
+    #[test]
+    fn test_evaluate_queen_up() {
+        let game = GameState::from_fen("rnb1kbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1")
+            .unwrap();
+        let score = evaluate(&game);
+        assert!((850..=1000).contains(&score), "score {}", score);
+
+        let flipped = game.mirrored();
+        assert_eq!(evaluate(&flipped), score);
+    }
+
+    #[test]
+    fn test_move_delta_free_queen() {
+        let mut game = GameState::from_fen("4k3/8/8/3q4/8/8/8/3QK3 w - - 0 1").unwrap();
+        let fen = game.to_fen();
+        let capture = Move::from_uci("d1d5").unwrap();
+        assert!(move_delta(&mut game, &capture) > 800);
+
+        let quiet = Move::from_uci("e1f1").unwrap();
+        assert!(move_delta(&mut game, &quiet).abs() < 100);
+        assert_eq!(game.to_fen(), fen);
+    }
+}
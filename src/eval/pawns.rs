@@ -0,0 +1,210 @@
+//! Pawn evaluation terms.
+
+use super::phase::{game_phase, taper};
+use crate::core::{Color, Coord, GameState, Piece, PieceType, StandardBoard};
+use crate::movegen::Bitboard64;
+
+/// Middlegame passed-pawn bonus by relative rank (0 = own back rank).
+const PASSED_PAWN_MG: [i32; 8] = [0, 5, 10, 15, 25, 40, 60, 0];
+
+/// Endgame passed-pawn bonus by relative rank.
+const PASSED_PAWN_EG: [i32; 8] = [0, 10, 20, 35, 60, 100, 150, 0];
+
+/// Penalty for each pawn beyond the first on a file.
+const DOUBLED_PAWN_PENALTY: i32 = 15;
+
+/// Penalty for each pawn with no friendly pawn on an adjacent file.
+const ISOLATED_PAWN_PENALTY: i32 = 10;
+
+/// Returns the rank of `coord` counted from `color`'s back rank.
+fn relative_rank(coord: &Coord, color: Color) -> u8 {
+    match color {
+        Color::White => coord.rank,
+        Color::Black => StandardBoard::HEIGHT - 1 - coord.rank,
+    }
+}
+
+/// Returns true if the pawn of `color` on `coord` is passed.
+///
+/// A pawn is passed when no enemy pawn stands ahead of it on its own file or
+/// an adjacent file.
+pub fn is_passed_pawn(game: &GameState, coord: &Coord, color: Color) -> bool {
+    let enemy_pawn = Piece::new(PieceType::Pawn, color.opposite());
+    let own_rank = relative_rank(coord, color);
+
+    !game.board().pieces().any(|(other, piece)| {
+        piece == enemy_pawn
+            && other.file.abs_diff(coord.file) <= 1
+            && relative_rank(&other, color) > own_rank
+    })
+}
+
+/// Returns the squares of all passed pawns of `color`.
+pub fn passed_pawns(game: &GameState, color: Color) -> Vec<Coord> {
+    let own_pawn = Piece::new(PieceType::Pawn, color);
+    game.board()
+        .pieces()
+        .filter(|(coord, piece)| *piece == own_pawn && is_passed_pawn(game, coord, color))
+        .map(|(coord, _)| coord)
+        .collect()
+}
+
+/// Returns the promotion-distance bonus for `color`'s passed pawns.
+///
+/// The bonus grows as each passer advances and is tapered by game phase, so
+/// a passer on the seventh rank in an endgame is worth far more than one on
+/// the fourth rank in the middlegame. Only the most advanced passer on each
+/// file is scored, so stacked pawns are not counted twice.
+pub fn passed_pawn_score(game: &GameState, color: Color) -> i32 {
+    let phase = game_phase(game);
+    let mut best_by_file = [None::<usize>; StandardBoard::WIDTH as usize];
+
+    for coord in passed_pawns(game, color) {
+        let rank = (relative_rank(&coord, color) as usize).min(PASSED_PAWN_MG.len() - 1);
+        let best = &mut best_by_file[coord.file as usize];
+        *best = Some(best.map_or(rank, |r| r.max(rank)));
+    }
+
+    best_by_file
+        .iter()
+        .flatten()
+        .map(|&rank| taper(PASSED_PAWN_MG[rank], PASSED_PAWN_EG[rank], phase))
+        .fold(0i32, i32::saturating_add)
+}
+
+/// Pawn-structure features of one side and their centipawn value.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PawnScore {
+    /// Pawns beyond the first on each file
+    pub doubled: u32,
+    /// Pawns with no friendly pawn on an adjacent file
+    pub isolated: u32,
+    /// Pawns with no enemy pawn ahead on their own or an adjacent file
+    pub passed: u32,
+    /// Doubled and isolated penalties plus the passed-pawn bonus
+    pub score: i32,
+}
+
+/// Analyzes `color`'s pawn structure with file-fill bitboards.
+///
+/// Doubled pawns count once per extra pawn on a file. Passed pawns match
+/// [`is_passed_pawn`], and their bonus is [`passed_pawn_score`].
+pub fn pawn_structure(game: &GameState, color: Color) -> PawnScore {
+    let board = game.board();
+    let own = board.pieces_of(color, PieceType::Pawn);
+    let enemy = board.pieces_of(color.opposite(), PieceType::Pawn);
+
+    let doubled: u32 = (0..StandardBoard::WIDTH as usize)
+        .map(|file| {
+            (own & Bitboard64::file_mask(file))
+                .popcount()
+                .saturating_sub(1)
+        })
+        .sum();
+
+    let own_files = own.file_fill();
+    let isolated = (own & !(own_files.east() | own_files.west())).popcount();
+
+    // Squares strictly behind each enemy pawn, from our side, on its file
+    // and both neighbors: an own pawn there has an enemy pawn ahead of it
+    let behind = match color {
+        Color::White => enemy.south().fill_south(Bitboard64::ALL),
+        Color::Black => enemy.north().fill_north(Bitboard64::ALL),
+    };
+    let blocked = behind | behind.east() | behind.west();
+    let passed = (own & !blocked).popcount();
+
+    let score = passed_pawn_score(game, color)
+        - doubled as i32 * DOUBLED_PAWN_PENALTY
+        - isolated as i32 * ISOLATED_PAWN_PENALTY;
+
+    PawnScore {
+        doubled,
+        isolated,
+        passed,
+        score,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_passed_pawn_detection() {
+        // a2 and h5 are passed; d4 is not because of the e5 pawn
+        let game = GameState::from_fen("4k3/8/8/4p2P/3P4/8/P7/4K3 w - - 0 1").unwrap();
+        let passed = passed_pawns(&game, Color::White);
+        assert!(passed.contains(&Coord::new(0, 1)));
+        assert!(passed.contains(&Coord::new(7, 4)));
+        assert!(!passed.contains(&Coord::new(3, 3)));
+
+        // Black's e5 pawn is held back by the d4 pawn on an adjacent file
+        assert!(!is_passed_pawn(&game, &Coord::new(4, 4), Color::Black));
+    }
+
+    #[test]
+    fn test_endgame_seventh_rank_passer_outweighs_middlegame_fourth() {
+        let endgame = GameState::from_fen("4k3/P7/8/8/8/8/8/4K3 w - - 0 1").unwrap();
+        let middlegame =
+            GameState::from_fen("rnbqkbnr/2pppppp/8/8/P7/8/1PPPPPPP/RNBQKBNR w KQkq - 0 1")
+                .unwrap();
+
+        let endgame_score = passed_pawn_score(&endgame, Color::White);
+        let middlegame_score = passed_pawn_score(&middlegame, Color::White);
+        assert!(middlegame_score > 0);
+        assert!(endgame_score > 5 * middlegame_score);
+    }
+
+    #[test]
+    fn test_pawn_structure() {
+        let start = pawn_structure(&GameState::starting_position(), Color::White);
+        assert_eq!(start, PawnScore::default());
+
+        // Doubled c-pawns next to the b-pawn, and an isolated h-pawn
+        let game = GameState::from_fen("4k3/8/8/8/2P5/1PP4P/8/4K3 w - - 0 1").unwrap();
+        let white = pawn_structure(&game, Color::White);
+        assert_eq!(white.doubled, 1);
+        assert_eq!(white.isolated, 1);
+        assert_eq!(white.passed, 4);
+        assert_eq!(
+            white.score,
+            passed_pawn_score(&game, Color::White) - DOUBLED_PAWN_PENALTY - ISOLATED_PAWN_PENALTY
+        );
+    }
+
+    #[test]
+    fn test_pawn_structure_tripled_and_passed() {
+        // Three a-pawns count as two extra; the e5 pawn is clearly passed,
+        // while the h-pawns block each other
+        let game = GameState::from_fen("4k3/8/7p/P3P3/P7/P6P/8/4K3 w - - 0 1").unwrap();
+        let white = pawn_structure(&game, Color::White);
+        assert_eq!(white.doubled, 2);
+        assert_eq!(white.isolated, 5);
+        assert_eq!(white.passed, 4);
+        assert_eq!(
+            white.passed as usize,
+            passed_pawns(&game, Color::White).len()
+        );
+
+        let black = pawn_structure(&game, Color::Black);
+        assert_eq!(black.passed, 0);
+        assert_eq!(black.isolated, 1);
+    }
+
+    #[test]
+    fn test_stacked_pawns_counted_once() {
+        // Five white pawns stacked on the a-file
+        let stacked = GameState::from_fen("4k3/P7/P7/P7/P7/P7/8/4K3 w - - 0 1").unwrap();
+        let single = GameState::from_fen("4k3/P7/8/8/8/8/8/4K3 w - - 0 1").unwrap();
+
+        let score = passed_pawn_score(&stacked, Color::White);
+        assert_eq!(score, passed_pawn_score(&single, Color::White));
+        assert_eq!(passed_pawn_score(&stacked, Color::Black), 0);
+
+        let eval = crate::eval::evaluate(&stacked);
+        assert!(eval > 0 && eval < 10_000);
+        let mirrored = crate::eval::evaluate(&stacked.mirrored());
+        assert_eq!(mirrored, eval);
+    }
+}
@@ -0,0 +1,57 @@
+//! Game phase estimation for tapered evaluation.
+
+use crate::core::{GameState, PieceType};
+
+/// Phase value of the full starting material.
+pub const MAX_PHASE: i32 = 24;
+
+/// Returns the phase contribution of a piece type.
+fn phase_weight(piece_type: PieceType) -> i32 {
+    match piece_type {
+        PieceType::Knight | PieceType::Bishop => 1,
+        PieceType::Rook => 2,
+        PieceType::Queen => 4,
+        PieceType::Pawn | PieceType::King => 0,
+    }
+}
+
+/// Returns the game phase, from `MAX_PHASE` (opening) down to 0 (bare kings).
+///
+/// Computed from the non-pawn material left on the board. Extra material from
+/// promotions is clamped so the result never exceeds `MAX_PHASE`.
+pub fn game_phase(game: &GameState) -> i32 {
+    let phase: i32 = game
+        .board()
+        .pieces()
+        .map(|(_, piece)| phase_weight(piece.piece_type))
+        .sum();
+    phase.min(MAX_PHASE)
+}
+
+/// Blends a middlegame and an endgame score by phase.
+pub fn taper(middlegame: i32, endgame: i32, phase: i32) -> i32 {
+    (middlegame * phase + endgame * (MAX_PHASE - phase)) / MAX_PHASE
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_game_phase() {
+        assert_eq!(game_phase(&GameState::starting_position()), MAX_PHASE);
+
+        let kings_only = GameState::from_fen("4k3/8/8/8/8/8/8/4K3 w - - 0 1").unwrap();
+        assert_eq!(game_phase(&kings_only), 0);
+
+        let rook_ending = GameState::from_fen("4k3/r7/8/8/8/8/R7/4K3 w - - 0 1").unwrap();
+        assert_eq!(game_phase(&rook_ending), 4);
+    }
+
+    #[test]
+    fn test_taper() {
+        assert_eq!(taper(100, 0, MAX_PHASE), 100);
+        assert_eq!(taper(100, 0, 0), 0);
+        assert_eq!(taper(100, 0, MAX_PHASE / 2), 50);
+    }
+}
@@ -0,0 +1,180 @@
+//! Piece-square tables: bonuses for where each piece stands.
+//!
+//! Tables are written from White's point of view with rank 8 on the first
+//! row, so they read like a diagram. Black uses the same tables mirrored.
+
+use super::phase::{game_phase, taper};
+use crate::core::{Color, Coord, GameState, PieceType};
+
+#[rustfmt::skip]
+const PAWN_MG: [i32; 64] = [
+      0,   0,   0,   0,   0,   0,   0,   0,
+     50,  50,  50,  50,  50,  50,  50,  50,
+     10,  10,  20,  30,  30,  20,  10,  10,
+      5,   5,  10,  25,  25,  10,   5,   5,
+      0,   0,   0,  20,  20,   0,   0,   0,
+      5,  -5, -10,   0,   0, -10,  -5,   5,
+      5,  10,  10, -20, -20,  10,  10,   5,
+      0,   0,   0,   0,   0,   0,   0,   0,
+];
+
+#[rustfmt::skip]
+const PAWN_EG: [i32; 64] = [
+      0,   0,   0,   0,   0,   0,   0,   0,
+     80,  80,  80,  80,  80,  80,  80,  80,
+     50,  50,  50,  50,  50,  50,  50,  50,
+     30,  30,  30,  30,  30,  30,  30,  30,
+     15,  15,  15,  15,  15,  15,  15,  15,
+      5,   5,   5,   5,   5,   5,   5,   5,
+      0,   0,   0,   0,   0,   0,   0,   0,
+      0,   0,   0,   0,   0,   0,   0,   0,
+];
+
+#[rustfmt::skip]
+const KNIGHT: [i32; 64] = [
+    -50, -40, -30, -30, -30, -30, -40, -50,
+    -40, -20,   0,   0,   0,   0, -20, -40,
+    -30,   0,  10,  15,  15,  10,   0, -30,
+    -30,   5,  15,  20,  20,  15,   5, -30,
+    -30,   0,  15,  20,  20,  15,   0, -30,
+    -30,   5,  10,  15,  15,  10,   5, -30,
+    -40, -20,   0,   5,   5,   0, -20, -40,
+    -50, -40, -30, -30, -30, -30, -40, -50,
+];
+
+#[rustfmt::skip]
+const BISHOP: [i32; 64] = [
+    -20, -10, -10, -10, -10, -10, -10, -20,
+    -10,   0,   0,   0,   0,   0,   0, -10,
+    -10,   0,   5,  10,  10,   5,   0, -10,
+    -10,   5,   5,  10,  10,   5,   5, -10,
+    -10,   0,  10,  10,  10,  10,   0, -10,
+    -10,  10,  10,  10,  10,  10,  10, -10,
+    -10,   5,   0,   0,   0,   0,   5, -10,
+    -20, -10, -10, -10, -10, -10, -10, -20,
+];
+
+#[rustfmt::skip]
+const ROOK: [i32; 64] = [
+      0,   0,   0,   0,   0,   0,   0,   0,
+      5,  10,  10,  10,  10,  10,  10,   5,
+     -5,   0,   0,   0,   0,   0,   0,  -5,
+     -5,   0,   0,   0,   0,   0,   0,  -5,
+     -5,   0,   0,   0,   0,   0,   0,  -5,
+     -5,   0,   0,   0,   0,   0,   0,  -5,
+     -5,   0,   0,   0,   0,   0,   0,  -5,
+      0,   0,   0,   5,   5,   0,   0,   0,
+];
+
+#[rustfmt::skip]
+const QUEEN: [i32; 64] = [
+    -20, -10, -10,  -5,  -5, -10, -10, -20,
+    -10,   0,   0,   0,   0,   0,   0, -10,
+    -10,   0,   5,   5,   5,   5,   0, -10,
+     -5,   0,   5,   5,   5,   5,   0,  -5,
+      0,   0,   5,   5,   5,   5,   0,  -5,
+    -10,   5,   5,   5,   5,   5,   0, -10,
+    -10,   0,   5,   0,   0,   0,   0, -10,
+    -20, -10, -10,  -5,  -5, -10, -10, -20,
+];
+
+#[rustfmt::skip]
+const KING_MG: [i32; 64] = [
+    -30, -40, -40, -50, -50, -40, -40, -30,
+    -30, -40, -40, -50, -50, -40, -40, -30,
+    -30, -40, -40, -50, -50, -40, -40, -30,
+    -30, -40, -40, -50, -50, -40, -40, -30,
+    -20, -30, -30, -40, -40, -30, -30, -20,
+    -10, -20, -20, -20, -20, -20, -20, -10,
+     20,  20,   0,   0,   0,   0,  20,  20,
+     20,  30,  10,   0,   0,  10,  30,  20,
+];
+
+#[rustfmt::skip]
+const KING_EG: [i32; 64] = [
+    -50, -40, -30, -20, -20, -30, -40, -50,
+    -30, -20, -10,   0,   0, -10, -20, -30,
+    -30, -10,  20,  30,  30,  20, -10, -30,
+    -30, -10,  30,  40,  40,  30, -10, -30,
+    -30, -10,  30,  40,  40,  30, -10, -30,
+    -30, -10,  20,  30,  30,  20, -10, -30,
+    -30, -30,   0,   0,   0,   0, -30, -30,
+    -50, -30, -30, -30, -30, -30, -30, -50,
+];
+
+/// Returns the (middlegame, endgame) tables for a piece type.
+fn tables(piece_type: PieceType) -> (&'static [i32; 64], &'static [i32; 64]) {
+    match piece_type {
+        PieceType::Pawn => (&PAWN_MG, &PAWN_EG),
+        PieceType::Knight => (&KNIGHT, &KNIGHT),
+        PieceType::Bishop => (&BISHOP, &BISHOP),
+        PieceType::Rook => (&ROOK, &ROOK),
+        PieceType::Queen => (&QUEEN, &QUEEN),
+        PieceType::King => (&KING_MG, &KING_EG),
+    }
+}
+
+/// Returns the table index of `coord` for a piece of `color`.
+///
+/// Row 0 of each table is rank 8 from White's side; Black reads it mirrored.
+fn table_index(coord: Coord, color: Color) -> usize {
+    let rank = match color {
+        Color::White => 7 - coord.rank,
+        Color::Black => coord.rank,
+    };
+    rank as usize * 8 + coord.file as usize
+}
+
+/// Returns the (middlegame, endgame) bonus for a piece on `coord`.
+pub fn square_bonus(piece_type: PieceType, color: Color, coord: Coord) -> (i32, i32) {
+    let (mg, eg) = tables(piece_type);
+    let index = table_index(coord, color);
+    (mg[index], eg[index])
+}
+
+/// Sums the piece-square bonuses of `color`'s pieces, tapered by game phase.
+pub fn piece_square(game: &GameState, color: Color) -> i32 {
+    let (mg, eg) = game
+        .board()
+        .pieces()
+        .filter(|(_, piece)| piece.color == color)
+        .fold((0, 0), |(mg, eg), (coord, piece)| {
+            let (m, e) = square_bonus(piece.piece_type, color, coord);
+            (mg + m, eg + e)
+        });
+    taper(mg, eg, game_phase(game))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tables_mirrored_for_black() {
+        let e4 = Coord::new(4, 3);
+        let e5 = Coord::new(4, 4);
+        assert_eq!(
+            square_bonus(PieceType::Pawn, Color::White, e4),
+            square_bonus(PieceType::Pawn, Color::Black, e5)
+        );
+        assert_eq!(
+            square_bonus(PieceType::Knight, Color::White, Coord::new(0, 0)).0,
+            -50
+        );
+
+        let start = GameState::starting_position();
+        assert_eq!(
+            piece_square(&start, Color::White),
+            piece_square(&start, Color::Black)
+        );
+    }
+
+    #[test]
+    fn test_king_tables_taper() {
+        // Sheltered in the middlegame, centralized in the endgame
+        let g1 = square_bonus(PieceType::King, Color::White, Coord::new(6, 0));
+        let e4 = square_bonus(PieceType::King, Color::White, Coord::new(4, 3));
+        assert!(g1.0 > e4.0);
+        assert!(e4.1 > g1.1);
+    }
+}
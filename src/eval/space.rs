@@ -0,0 +1,60 @@
+//! Space advantage: control of the opponent's half of the board.
+
+use crate::core::{Color, GameState, PieceType, StandardBoard};
+use crate::movegen::{attacked_squares, pawn_attacks, Bitboard64};
+
+/// Returns the squares attacked by the pawns of `color`.
+fn pawn_attacked_by(game: &GameState, color: Color) -> Bitboard64 {
+    game.board()
+        .pieces()
+        .filter(|(_, piece)| piece.color == color && piece.piece_type == PieceType::Pawn)
+        .fold(Bitboard64::EMPTY, |acc, (coord, _)| {
+            let sq = StandardBoard::to_index(&coord).unwrap();
+            acc | pawn_attacks(sq, color as usize)
+        })
+}
+
+/// Returns the mask of the opponent's half of the board from `color`'s view.
+fn enemy_half(color: Color) -> Bitboard64 {
+    let half = StandardBoard::HEIGHT / 2;
+    StandardBoard::all_coords()
+        .filter(|coord| match color {
+            Color::White => coord.rank >= half,
+            Color::Black => coord.rank < half,
+        })
+        .fold(Bitboard64::EMPTY, |mut acc, coord| {
+            acc.set(StandardBoard::to_index(&coord).unwrap());
+            acc
+        })
+}
+
+/// Counts the squares in the opponent's half that `color` controls.
+///
+/// A square counts when one of `color`'s pieces attacks it and no enemy pawn
+/// does, since a pawn-guarded square cannot be occupied safely.
+pub fn space(game: &GameState, color: Color) -> i32 {
+    let controlled = attacked_squares(game.board(), color)
+        & enemy_half(color)
+        & !pawn_attacked_by(game, color.opposite());
+    controlled.popcount() as i32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_space_symmetric_start() {
+        let game = GameState::starting_position();
+        assert_eq!(space(&game, Color::White), space(&game, Color::Black));
+    }
+
+    #[test]
+    fn test_advanced_pawn_chain_gains_space() {
+        // White's c4-d5-e4 chain cramps Black's position
+        let game =
+            GameState::from_fen("rnbqkbnr/ppp2ppp/3p4/3Pp3/2P1P3/8/PP3PPP/RNBQKBNR w KQkq - 0 4")
+                .unwrap();
+        assert!(space(&game, Color::White) > space(&game, Color::Black));
+    }
+}
@@ -0,0 +1,94 @@
+//! Attack graph: which squares each piece attacks.
+
+use crate::core::{Coord, GameState, StandardBoard};
+use crate::movegen::{piece_attacks, Bitboard64};
+
+/// Directed graph from each piece to the squares it attacks.
+///
+/// Nodes are squares; an edge `a -> b` means the piece on `a` attacks `b`.
+/// Empty squares have no outgoing edges.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AttackGraph {
+    /// Attack set of the piece on each square (empty if unoccupied)
+    attacks: [Bitboard64; 64],
+}
+
+impl AttackGraph {
+    /// Builds the attack graph for a position from scratch.
+    pub fn new(game: &GameState) -> Self {
+        let mut graph = Self {
+            attacks: [Bitboard64::EMPTY; 64],
+        };
+        for sq in 0..64 {
+            graph.recompute(game, sq);
+        }
+        graph
+    }
+
+    /// Recomputes the outgoing edges of a single square.
+    pub(crate) fn recompute(&mut self, game: &GameState, sq: usize) {
+        let board = game.board();
+        self.attacks[sq] = StandardBoard::from_index(sq)
+            .and_then(|coord| board.piece_at(&coord))
+            .map_or(Bitboard64::EMPTY, |piece| {
+                piece_attacks(piece, sq, board.occupied())
+            });
+    }
+
+    /// Returns the squares attacked by the piece on `coord`.
+    pub fn attacks_from(&self, coord: &Coord) -> Bitboard64 {
+        StandardBoard::to_index(coord).map_or(Bitboard64::EMPTY, |sq| self.attacks[sq])
+    }
+
+    /// Returns the squares holding pieces that attack `coord`.
+    pub fn attackers_of(&self, coord: &Coord) -> Bitboard64 {
+        let Some(target) = StandardBoard::to_index(coord) else {
+            return Bitboard64::EMPTY;
+        };
+        let mut attackers = Bitboard64::EMPTY;
+        for (sq, attacks) in self.attacks.iter().enumerate() {
+            if attacks.get(target) {
+                attackers.set(sq);
+            }
+        }
+        attackers
+    }
+
+    /// Returns an iterator over all (attacker, target) edges.
+    pub fn edges(&self) -> impl Iterator<Item = (Coord, Coord)> + '_ {
+        self.attacks.iter().enumerate().flat_map(|(from, attacks)| {
+            let from = StandardBoard::from_index(from).unwrap();
+            attacks
+                .iter()
+                .map(move |to| (from, StandardBoard::from_index(to).unwrap()))
+        })
+    }
+
+    /// Returns the total number of edges.
+    pub fn edge_count(&self) -> u32 {
+        self.attacks.iter().map(|a| a.popcount()).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_starting_position_edges() {
+        let game = GameState::starting_position();
+        let graph = AttackGraph::new(&game);
+
+        // Knight on b1 attacks a3, c3, d2
+        let b1 = Coord::new(1, 0);
+        assert_eq!(graph.attacks_from(&b1).popcount(), 3);
+
+        // f3 is attacked by the g1 knight and the e2 and g2 pawns
+        let f3 = Coord::new(5, 2);
+        assert_eq!(graph.attackers_of(&f3).popcount(), 3);
+
+        // Empty squares have no outgoing edges
+        assert!(graph.attacks_from(&Coord::new(4, 3)).is_empty());
+        assert_eq!(graph.edges().count() as u32, graph.edge_count());
+    }
+}
@@ -0,0 +1,53 @@
+//! Control of the light and dark square complexes.
+
+use super::AttackGraph;
+use crate::core::{Color, GameState, SquareColor};
+
+/// Returns `color`'s control of the (light, dark) square complexes.
+///
+/// Control is the number of attack edges from `color`'s pieces landing on
+/// squares of each color, so losing a bishop shows up as a drop on its
+/// complex ("Black is weak on the dark squares").
+pub fn color_complex_control(game: &GameState, color: Color) -> (i32, i32) {
+    let graph = AttackGraph::new(game);
+    let board = game.board();
+
+    graph
+        .edges()
+        .filter(|(from, _)| board.piece_at(from).is_some_and(|p| p.color == color))
+        .fold((0, 0), |(light, dark), (_, to)| {
+            if to.square_color() == SquareColor::Light {
+                (light + 1, dark)
+            } else {
+                (light, dark + 1)
+            }
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_color_complex_symmetric_start() {
+        // Mirroring the board swaps square colors
+        let game = GameState::starting_position();
+        let (white_light, white_dark) = color_complex_control(&game, Color::White);
+        let (black_light, black_dark) = color_complex_control(&game, Color::Black);
+        assert_eq!(white_light, black_dark);
+        assert_eq!(white_dark, black_light);
+    }
+
+    #[test]
+    fn test_missing_dark_bishop() {
+        // Black has lost the dark-squared bishop from f8
+        let game =
+            GameState::from_fen("rnbqk1nr/pppp1ppp/8/4p3/4P3/8/PPPP1PPP/RNBQKBNR w KQkq - 0 1")
+                .unwrap();
+        let (white_light, white_dark) = color_complex_control(&game, Color::White);
+        let (black_light, black_dark) = color_complex_control(&game, Color::Black);
+
+        assert_eq!(black_light, white_dark);
+        assert!(black_dark < white_light);
+    }
+}
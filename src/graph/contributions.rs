@@ -0,0 +1,53 @@
+//! Readable listing of the pieces attacking a square.
+
+use crate::core::{Coord, GameState, PieceType, StandardBoard};
+use crate::movegen::attackers_to;
+
+/// Lists every piece, of either color, attacking `target` with its type.
+///
+/// This is the readable form of `attackers_to`, letting an explanation say
+/// "d5 is attacked by the knight on f4 and the pawn on e4". Results are in
+/// square index order.
+pub fn attack_contributions(game: &GameState, target: Coord) -> Vec<(Coord, PieceType)> {
+    let Some(sq) = StandardBoard::to_index(&target) else {
+        return Vec::new();
+    };
+    let board = game.board();
+
+    attackers_to(board, sq, board.occupied())
+        .iter()
+        .filter_map(|from| {
+            let coord = StandardBoard::from_index(from)?;
+            Some((coord, board.piece_at(&coord)?.piece_type))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_attack_contributions() {
+        // d5 is attacked by Nf4, Pe4, Bb3 (White) and Pc6, Rd8 (Black)
+        let game = GameState::from_fen("3rk3/8/2p5/8/4PN2/1B6/8/4K3 w - - 0 1").unwrap();
+        let attackers = attack_contributions(&game, Coord::new(3, 4));
+
+        assert_eq!(
+            attackers,
+            vec![
+                (Coord::new(1, 2), PieceType::Bishop),
+                (Coord::new(4, 3), PieceType::Pawn),
+                (Coord::new(5, 3), PieceType::Knight),
+                (Coord::new(2, 5), PieceType::Pawn),
+                (Coord::new(3, 7), PieceType::Rook),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unattacked_square() {
+        let game = GameState::starting_position();
+        assert!(attack_contributions(&game, Coord::new(4, 4)).is_empty());
+    }
+}
@@ -0,0 +1,125 @@
+//! Defense graph: which friendly pieces each piece protects.
+
+use super::AttackGraph;
+use crate::core::{Color, Coord, GameState, PieceType, StandardBoard};
+use crate::eval::piece_value;
+use crate::movegen::{attackers_to, Bitboard64};
+
+/// Directed graph from each piece to the friendly pieces it defends.
+///
+/// An edge `a -> b` means the piece on `a` attacks `b` and both belong to
+/// the same side, so it could recapture there.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DefenseGraph {
+    /// Friendly pieces defended by the piece on each square
+    defends: [Bitboard64; 64],
+}
+
+impl DefenseGraph {
+    /// Builds the defense graph for a position.
+    pub fn new(game: &GameState) -> Self {
+        let board = game.board();
+        let attacks = AttackGraph::new(game);
+        let mut defends = [Bitboard64::EMPTY; 64];
+
+        for (coord, piece) in board.pieces() {
+            let sq = StandardBoard::to_index(&coord).unwrap();
+            defends[sq] = attacks.attacks_from(&coord) & board.pieces_of_color(piece.color);
+        }
+
+        Self { defends }
+    }
+
+    /// Returns the squares of friendly pieces defended by the piece on `coord`.
+    pub fn defended_by(&self, coord: &Coord) -> Bitboard64 {
+        StandardBoard::to_index(coord).map_or(Bitboard64::EMPTY, |sq| self.defends[sq])
+    }
+
+    /// Returns the squares of the pieces defending the piece on `coord`.
+    pub fn defenders_of(&self, coord: &Coord) -> Bitboard64 {
+        let Some(target) = StandardBoard::to_index(coord) else {
+            return Bitboard64::EMPTY;
+        };
+        let mut defenders = Bitboard64::EMPTY;
+        for (sq, defends) in self.defends.iter().enumerate() {
+            if defends.get(target) {
+                defenders.set(sq);
+            }
+        }
+        defenders
+    }
+
+    /// Returns an iterator over all (defender, defended) edges.
+    pub fn edges(&self) -> impl Iterator<Item = (Coord, Coord)> + '_ {
+        self.defends.iter().enumerate().flat_map(|(from, defends)| {
+            let from = StandardBoard::from_index(from).unwrap();
+            defends
+                .iter()
+                .map(move |to| (from, StandardBoard::from_index(to).unwrap()))
+        })
+    }
+
+    /// Returns the total number of edges.
+    pub fn edge_count(&self) -> u32 {
+        self.defends.iter().map(|d| d.popcount()).sum()
+    }
+}
+
+/// Returns the pieces of `color` that are en prise.
+///
+/// A piece is hanging when the enemy attacks it and either nothing defends
+/// it or one of the attackers is worth less than it. The king is never
+/// listed. Results are in square index order.
+pub fn hanging_pieces(game: &GameState, color: Color) -> Vec<Coord> {
+    let board = game.board();
+    let defense = DefenseGraph::new(game);
+    let enemy = board.pieces_of_color(color.opposite());
+
+    board
+        .pieces()
+        .filter(|(_, piece)| piece.color == color && piece.piece_type != PieceType::King)
+        .filter(|(coord, piece)| {
+            let sq = StandardBoard::to_index(coord).unwrap();
+            let attackers = attackers_to(board, sq, board.occupied()) & enemy;
+            if attackers.is_empty() {
+                return false;
+            }
+            let cheapest = attackers
+                .iter()
+                .filter_map(|from| board.piece_at(&StandardBoard::from_index(from)?))
+                .map(|attacker| piece_value(attacker.piece_type))
+                .min()
+                .unwrap_or(0);
+            defense.defenders_of(coord).is_empty() || cheapest < piece_value(piece.piece_type)
+        })
+        .map(|(coord, _)| coord)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_defense_edges() {
+        let game = GameState::starting_position();
+        let graph = DefenseGraph::new(&game);
+
+        // The e2 pawn is defended by the king, queen, bishop and knight
+        let e2 = Coord::new(4, 1);
+        assert_eq!(graph.defenders_of(&e2).popcount(), 4);
+        // The b1 knight defends d2 only (a3 and c3 are empty)
+        assert_eq!(graph.defended_by(&Coord::new(1, 0)).popcount(), 1);
+        assert_eq!(graph.edges().count() as u32, graph.edge_count());
+    }
+
+    #[test]
+    fn test_hanging_queen() {
+        // The queen on d5 is attacked by the e4 pawn; the c6 knight is
+        // attacked by the c1 rook but defended by the queen, and the e4 pawn
+        // is defended by f3
+        let game = GameState::from_fen("4k3/8/2n5/3q4/4P3/5P2/8/2R1K3 b - - 0 1").unwrap();
+        assert_eq!(hanging_pieces(&game, Color::Black), vec![Coord::new(3, 4)]);
+        assert!(hanging_pieces(&game, Color::White).is_empty());
+    }
+}
@@ -0,0 +1,103 @@
+//! Incremental maintenance of the attack graph across moves.
+
+use super::AttackGraph;
+use crate::core::{GameState, MoveOutcome, StandardBoard};
+use crate::movegen::Bitboard64;
+
+/// An attack graph that is updated move by move instead of rebuilt.
+///
+/// Only the squares whose occupant changed, plus any piece that attacked one
+/// of those squares (sliders whose lines open or close), are recomputed.
+#[derive(Clone, Debug)]
+pub struct IncrementalGraph {
+    graph: AttackGraph,
+}
+
+impl IncrementalGraph {
+    /// Creates an incremental graph for the given position.
+    pub fn new(game: &GameState) -> Self {
+        Self {
+            graph: AttackGraph::new(game),
+        }
+    }
+
+    /// Returns the current attack graph.
+    pub fn graph(&self) -> &AttackGraph {
+        &self.graph
+    }
+
+    /// Updates the graph after a move.
+    ///
+    /// `game` must be the position *after* the move that produced `outcome`.
+    pub fn apply(&mut self, game: &GameState, outcome: &MoveOutcome) {
+        let mut dirty = Bitboard64::EMPTY;
+
+        for coord in outcome.changed_squares() {
+            if let Some(sq) = StandardBoard::to_index(&coord) {
+                dirty.set(sq);
+            }
+            // Any piece that reached a changed square may now see further or less far
+            dirty |= self.graph.attackers_of(&coord);
+        }
+
+        for sq in dirty.iter() {
+            self.graph.recompute(game, sq);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Move;
+    use crate::movegen::generate_legal_moves;
+
+    #[test]
+    fn test_matches_rebuild_after_sequence() {
+        let mut game = GameState::starting_position();
+        let mut incremental = IncrementalGraph::new(&game);
+
+        for uci in [
+            "e2e4", "d7d5", "e4d5", "d8d5", "b1c3", "d5a5", "g1f3", "c8g4",
+        ] {
+            let mv = Move::from_uci(uci).unwrap();
+            let outcome = game.make_move(&mv).outcome;
+            incremental.apply(&game, &outcome);
+            assert_eq!(
+                incremental.graph(),
+                &AttackGraph::new(&game),
+                "mismatch after {}",
+                uci
+            );
+        }
+    }
+
+    #[test]
+    fn test_matches_rebuild_for_special_moves() {
+        // Castling, en passant, and promotions are all available here
+        let fens = [
+            "r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1",
+            "rnbqkbnr/pppp1ppp/8/4pP2/8/8/PPPPP1PP/RNBQKBNR w KQkq e6 0 3",
+            "1n2k3/P7/8/8/8/8/8/4K2R w K - 0 1",
+        ];
+
+        for fen in fens {
+            let game = GameState::from_fen(fen).unwrap();
+            let base = IncrementalGraph::new(&game);
+
+            for mv in generate_legal_moves(&game) {
+                let mut next = game.clone();
+                let outcome = next.make_move(&mv).outcome;
+                let mut incremental = base.clone();
+                incremental.apply(&next, &outcome);
+                assert_eq!(
+                    incremental.graph(),
+                    &AttackGraph::new(&next),
+                    "mismatch after {} in {}",
+                    mv,
+                    fen
+                );
+            }
+        }
+    }
+}
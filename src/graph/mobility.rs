@@ -0,0 +1,64 @@
+//! Per-piece mobility: the out-degree of each piece in the legal move graph.
+
+use crate::core::{Color, Coord, GameState, Move, PieceType};
+use crate::movegen::generate_legal_moves;
+
+/// Returns the legal moves of `color`, passing the turn first if needed.
+fn legal_moves_for(game: &GameState, color: Color) -> Vec<Move> {
+    let mut position = game.clone();
+    if position.side_to_move() != color {
+        position.make_null_move();
+    }
+    generate_legal_moves(&position)
+        .into_iter()
+        .filter(|mv| matches!(mv.promoted_piece(), None | Some(PieceType::Queen)))
+        .collect()
+}
+
+/// Counts the legal destinations of the piece on `sq`.
+///
+/// Pins and checks are respected since only legal moves are counted. If the
+/// piece's side is not to move the turn is passed first. A promotion counts
+/// once however many piece choices it offers. Empty squares have mobility 0.
+pub fn piece_mobility(game: &GameState, sq: Coord) -> u32 {
+    let Some(piece) = game.board().piece_at(&sq) else {
+        return 0;
+    };
+    legal_moves_for(game, piece.color)
+        .iter()
+        .filter(|mv| mv.from == sq)
+        .count() as u32
+}
+
+/// Returns the total (White, Black) mobility over all pieces.
+pub fn mobility_by_color(game: &GameState) -> (u32, u32) {
+    (
+        legal_moves_for(game, Color::White).len() as u32,
+        legal_moves_for(game, Color::Black).len() as u32,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trapped_and_central_pieces() {
+        // The c1 bishop is boxed in by the b2 and d2 pawns; the queen on d4
+        // sweeps an open board
+        let game = GameState::from_fen("4k3/8/8/8/3Q4/8/1P1P4/2B1K3 w - - 0 1").unwrap();
+        assert_eq!(piece_mobility(&game, Coord::new(2, 0)), 0);
+        assert!(piece_mobility(&game, Coord::new(3, 3)) >= 20);
+        assert_eq!(piece_mobility(&game, Coord::new(4, 4)), 0);
+    }
+
+    #[test]
+    fn test_pinned_piece_and_totals() {
+        let game = GameState::starting_position();
+        assert_eq!(mobility_by_color(&game), (20, 20));
+
+        // The e2 knight is pinned by the e8 rook
+        let game = GameState::from_fen("4r1k1/8/8/8/8/8/4N3/4K3 b - - 0 1").unwrap();
+        assert_eq!(piece_mobility(&game, Coord::new(4, 1)), 0);
+    }
+}
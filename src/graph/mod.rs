@@ -1,3 +1,15 @@
 //! Graph-theoretic position analysis.
 
-// TODO: Implement graph analysis
+pub mod attack_graph;
+pub mod color_complex;
+pub mod contributions;
+pub mod defense;
+pub mod incremental;
+pub mod mobility;
+
+pub use attack_graph::AttackGraph;
+pub use color_complex::color_complex_control;
+pub use contributions::attack_contributions;
+pub use defense::{hanging_pieces, DefenseGraph};
+pub use incremental::IncrementalGraph;
+pub use mobility::{mobility_by_color, piece_mobility};
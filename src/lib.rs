@@ -22,10 +22,12 @@
 //! assert!(LargeBoard::is_valid(&j10));
 //! ```
 
+pub mod analysis;
 pub mod core;
 pub mod eval;
 pub mod graph;
 pub mod movegen;
+pub mod pgn;
 pub mod search;
 pub mod threats;
 pub mod uci;
@@ -0,0 +1,8 @@
+//! UCI engine binary.
+
+use interpretable_chess_engine::uci;
+use std::io;
+
+fn main() -> io::Result<()> {
+    uci::run(io::stdin().lock(), io::stdout().lock())
+}
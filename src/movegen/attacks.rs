@@ -1,6 +1,7 @@
 use super::bitboard::Bitboard64;
 use super::magic_constants::*;
 use super::rays::{bishop_attacks_slow, blocker_permutations, rook_attacks_slow};
+use crate::core::{Board, Color, Piece, PieceType, StandardBoard};
 use std::sync::OnceLock;
 
 /// Global rook attack table (initialized once).
@@ -207,6 +208,60 @@ pub fn pawn_attacks(sq: usize, color: usize) -> Bitboard64 {
     PAWN_ATTACKS.get_or_init(init_pawn_attacks)[color][sq]
 }
 
+/// Returns the squares attacked by `piece` standing on `sq`.
+///
+/// Pawns attack diagonally only; their forward pushes are not attacks.
+#[inline]
+pub fn piece_attacks(piece: Piece, sq: usize, occupied: Bitboard64) -> Bitboard64 {
+    match piece.piece_type {
+        PieceType::Pawn => pawn_attacks(sq, piece.color as usize),
+        PieceType::Knight => knight_attacks(sq),
+        PieceType::Bishop => bishop_attacks(sq, occupied),
+        PieceType::Rook => rook_attacks(sq, occupied),
+        PieceType::Queen => queen_attacks(sq, occupied),
+        PieceType::King => king_attacks(sq),
+    }
+}
+
+/// Returns every square attacked by the pieces of `color`.
+pub fn attacked_squares(board: &Board, color: Color) -> Bitboard64 {
+    let occupied = board.occupied();
+    board
+        .pieces()
+        .filter(|(_, piece)| piece.color == color)
+        .fold(Bitboard64::EMPTY, |acc, (coord, piece)| {
+            let sq = StandardBoard::to_index(&coord).unwrap();
+            acc | piece_attacks(piece, sq, occupied)
+        })
+}
+
+/// Returns the squares of all pieces, of both colors, attacking `sq`.
+///
+/// Candidates are found by looking up each attack pattern in reverse from
+/// `sq`, then confirmed against the piece actually standing there. Sliders
+/// see through squares missing from `occupied`.
+pub fn attackers_to(board: &Board, sq: usize, occupied: Bitboard64) -> Bitboard64 {
+    let candidates = (rook_attacks(sq, occupied)
+        | bishop_attacks(sq, occupied)
+        | knight_attacks(sq)
+        | king_attacks(sq)
+        | pawn_attacks(sq, Color::White as usize)
+        | pawn_attacks(sq, Color::Black as usize))
+        & board.occupied();
+
+    candidates
+        .iter()
+        .filter(|&from| {
+            StandardBoard::from_index(from)
+                .and_then(|coord| board.piece_at(&coord))
+                .is_some_and(|piece| piece_attacks(piece, from, occupied).get(sq))
+        })
+        .fold(Bitboard64::EMPTY, |mut acc, from| {
+            acc.set(from);
+            acc
+        })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -272,6 +327,21 @@ mod tests {
         assert_eq!(attacks.popcount(), 2); // c2, b3
     }
 
+    #[test]
+    fn test_attack_tables_symmetric() {
+        for sq in 0..64 {
+            let mirrored = sq ^ 56; // same file, opposite rank
+            assert_eq!(knight_attacks(sq).flip_vertical(), knight_attacks(mirrored));
+            assert_eq!(king_attacks(sq).flip_vertical(), king_attacks(mirrored));
+            assert_eq!(knight_attacks(sq).flip_horizontal(), knight_attacks(sq ^ 7));
+            let transposed = (sq % 8) * 8 + sq / 8;
+            assert_eq!(
+                knight_attacks(sq).flip_diagonal(),
+                knight_attacks(transposed)
+            );
+        }
+    }
+
     #[test]
     fn test_king_attacks() {
         // e4 (28) king
@@ -309,6 +379,56 @@ mod tests {
         assert!(attacks.get(33)); // b5
     }
 
+    #[test]
+    fn test_piece_attacks() {
+        use crate::core::Color;
+
+        let occupied = Bitboard64::from_squares(&[36]);
+        let rook = Piece::new(PieceType::Rook, Color::White);
+        assert_eq!(
+            piece_attacks(rook, 28, occupied),
+            rook_attacks(28, occupied)
+        );
+
+        let black_pawn = Piece::new(PieceType::Pawn, Color::Black);
+        assert_eq!(piece_attacks(black_pawn, 28, occupied), pawn_attacks(28, 1));
+    }
+
+    #[test]
+    fn test_attacked_squares() {
+        let mut board = Board::empty();
+        board.set_piece(
+            &crate::core::Coord::new(0, 0),
+            Piece::new(PieceType::Rook, Color::White),
+        );
+        assert_eq!(attacked_squares(&board, Color::White).popcount(), 14);
+        assert!(attacked_squares(&board, Color::Black).is_empty());
+    }
+
+    #[test]
+    fn test_attackers_to_every_piece_type() {
+        // Both colors bear down on d5 with a pawn, knight, bishop, rook, queen
+        let game = crate::core::GameState::from_fen("b2r3k/8/1n2p3/q6Q/2P2N2/8/8/K2R3B w - - 0 1")
+            .unwrap();
+        let board = game.board();
+        let d5 = 35;
+
+        let attackers = attackers_to(board, d5, board.occupied());
+        let expected = Bitboard64::from_squares(&[
+            26, 29, 7, 3, 39, // c4 pawn, f4 knight, h1 bishop, d1 rook, h5 queen
+            44, 41, 56, 59, 32, // e6 pawn, b6 knight, a8 bishop, d8 rook, a5 queen
+        ]);
+        assert_eq!(attackers, expected);
+        assert_eq!(
+            attackers & board.pieces_of_color(Color::White),
+            Bitboard64::from_squares(&[26, 29, 7, 3, 39])
+        );
+
+        // Pawns only attack forward: c4 does not hit d3, e6 does not hit d7
+        assert!(!attackers_to(board, 19, board.occupied()).get(26));
+        assert!(!attackers_to(board, 51, board.occupied()).get(44));
+    }
+
     #[test]
     fn test_magic_consistency() {
         // Test that magic lookups give same results as slow raycast
@@ -117,11 +117,36 @@ impl Bitboard64 {
         }
     }
 
+    /// Pops the MSB and returns its index, or None if empty.
+    #[inline(always)]
+    pub fn pop_msb(&mut self) -> Option<usize> {
+        let sq = self.msb()?;
+        self.0 ^= 1u64 << sq; // Clear MSB
+        Some(sq)
+    }
+
     /// Returns an iterator over all set bit indices.
     pub fn iter(&self) -> BitboardIter {
         BitboardIter { bb: *self }
     }
 
+    /// Returns an iterator over all set bit indices, highest first.
+    pub fn iter_rev(&self) -> BitboardRevIter {
+        BitboardRevIter { bb: *self }
+    }
+
+    /// Returns an iterator over every subset of this bitboard.
+    ///
+    /// Uses the carry-rippler trick `sub = (sub - mask) & mask`, yielding
+    /// subsets from the empty set up to the full mask without allocating.
+    /// The n-th subset has the bits of `n` scattered onto the mask's squares.
+    pub fn subsets(self) -> SubsetIter {
+        SubsetIter {
+            mask: self.0,
+            next: Some(0),
+        }
+    }
+
     /// Shifts the bitboard north (up one rank).
     #[inline(always)]
     pub const fn north(&self) -> Self {
@@ -146,6 +171,142 @@ impl Bitboard64 {
         Self((self.0 >> 1) & !Self::FILE_H.0)
     }
 
+    /// Shifts the bitboard north-east (up one rank, right one file).
+    #[inline(always)]
+    pub const fn north_east(&self) -> Self {
+        Self((self.0 << 9) & !Self::FILE_A.0)
+    }
+
+    /// Shifts the bitboard north-west (up one rank, left one file).
+    #[inline(always)]
+    pub const fn north_west(&self) -> Self {
+        Self((self.0 << 7) & !Self::FILE_H.0)
+    }
+
+    /// Shifts the bitboard south-east (down one rank, right one file).
+    #[inline(always)]
+    pub const fn south_east(&self) -> Self {
+        Self((self.0 >> 7) & !Self::FILE_A.0)
+    }
+
+    /// Shifts the bitboard south-west (down one rank, left one file).
+    #[inline(always)]
+    pub const fn south_west(&self) -> Self {
+        Self((self.0 >> 9) & !Self::FILE_H.0)
+    }
+
+    /// Floods the set bits north through `empty` squares (Kogge-Stone fill).
+    ///
+    /// The result contains the original bits plus every empty square reached
+    /// before the first square not in `empty`.
+    pub const fn fill_north(self, empty: Self) -> Self {
+        let mut gen = self.0;
+        let mut pro = empty.0;
+        gen |= pro & (gen << 8);
+        pro &= pro << 8;
+        gen |= pro & (gen << 16);
+        pro &= pro << 16;
+        gen |= pro & (gen << 32);
+        Self(gen)
+    }
+
+    /// Floods the set bits south through `empty` squares (Kogge-Stone fill).
+    pub const fn fill_south(self, empty: Self) -> Self {
+        let mut gen = self.0;
+        let mut pro = empty.0;
+        gen |= pro & (gen >> 8);
+        pro &= pro >> 8;
+        gen |= pro & (gen >> 16);
+        pro &= pro >> 16;
+        gen |= pro & (gen >> 32);
+        Self(gen)
+    }
+
+    /// Floods the set bits north-east through `empty` squares (Kogge-Stone fill).
+    pub const fn fill_north_east(self, empty: Self) -> Self {
+        let mut gen = self.0;
+        let mut pro = empty.0 & !Self::FILE_A.0;
+        gen |= pro & (gen << 9);
+        pro &= pro << 9;
+        gen |= pro & (gen << 18);
+        pro &= pro << 18;
+        gen |= pro & (gen << 36);
+        Self(gen)
+    }
+
+    /// Floods the set bits north-west through `empty` squares (Kogge-Stone fill).
+    pub const fn fill_north_west(self, empty: Self) -> Self {
+        let mut gen = self.0;
+        let mut pro = empty.0 & !Self::FILE_H.0;
+        gen |= pro & (gen << 7);
+        pro &= pro << 7;
+        gen |= pro & (gen << 14);
+        pro &= pro << 14;
+        gen |= pro & (gen << 28);
+        Self(gen)
+    }
+
+    /// Floods the set bits south-east through `empty` squares (Kogge-Stone fill).
+    pub const fn fill_south_east(self, empty: Self) -> Self {
+        let mut gen = self.0;
+        let mut pro = empty.0 & !Self::FILE_A.0;
+        gen |= pro & (gen >> 7);
+        pro &= pro >> 7;
+        gen |= pro & (gen >> 14);
+        pro &= pro >> 14;
+        gen |= pro & (gen >> 28);
+        Self(gen)
+    }
+
+    /// Floods the set bits south-west through `empty` squares (Kogge-Stone fill).
+    pub const fn fill_south_west(self, empty: Self) -> Self {
+        let mut gen = self.0;
+        let mut pro = empty.0 & !Self::FILE_H.0;
+        gen |= pro & (gen >> 9);
+        pro &= pro >> 9;
+        gen |= pro & (gen >> 18);
+        pro &= pro >> 18;
+        gen |= pro & (gen >> 36);
+        Self(gen)
+    }
+
+    /// Returns every square on a file containing a set bit.
+    pub const fn file_fill(self) -> Self {
+        Self(self.fill_north(Self::ALL).0 | self.fill_south(Self::ALL).0)
+    }
+
+    /// Mirrors the board top to bottom (rank 1 <-> rank 8).
+    pub const fn flip_vertical(self) -> Self {
+        Self(self.0.swap_bytes())
+    }
+
+    /// Mirrors the board left to right (a-file <-> h-file).
+    pub const fn flip_horizontal(self) -> Self {
+        const K1: u64 = 0x5555555555555555;
+        const K2: u64 = 0x3333333333333333;
+        const K4: u64 = 0x0f0f0f0f0f0f0f0f;
+        let mut x = self.0;
+        x = ((x >> 1) & K1) | ((x & K1) << 1);
+        x = ((x >> 2) & K2) | ((x & K2) << 2);
+        x = ((x >> 4) & K4) | ((x & K4) << 4);
+        Self(x)
+    }
+
+    /// Transposes the board about the a1-h8 diagonal (a2 <-> b1).
+    pub const fn flip_diagonal(self) -> Self {
+        const K1: u64 = 0x5500550055005500;
+        const K2: u64 = 0x3333000033330000;
+        const K4: u64 = 0x0f0f0f0f00000000;
+        let mut x = self.0;
+        let mut t = K4 & (x ^ (x << 28));
+        x ^= t ^ (t >> 28);
+        t = K2 & (x ^ (x << 14));
+        x ^= t ^ (t >> 14);
+        t = K1 & (x ^ (x << 7));
+        x ^= t ^ (t >> 7);
+        Self(x)
+    }
+
     // File masks
     pub const FILE_A: Self = Self(0x0101010101010101);
     pub const FILE_B: Self = Self(0x0202020202020202);
@@ -197,6 +358,43 @@ impl Iterator for BitboardIter {
 
 impl ExactSizeIterator for BitboardIter {}
 
+/// Iterator over set bits in a bitboard, from the highest index down.
+pub struct BitboardRevIter {
+    bb: Bitboard64,
+}
+
+impl Iterator for BitboardRevIter {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.bb.pop_msb()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let count = self.bb.popcount() as usize;
+        (count, Some(count))
+    }
+}
+
+impl ExactSizeIterator for BitboardRevIter {}
+
+/// Iterator over all subsets of a bitboard.
+pub struct SubsetIter {
+    mask: u64,
+    next: Option<u64>,
+}
+
+impl Iterator for SubsetIter {
+    type Item = Bitboard64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.next?;
+        let following = current.wrapping_sub(self.mask) & self.mask;
+        self.next = (following != 0).then_some(following);
+        Some(Bitboard64(current))
+    }
+}
+
 // Bitwise operators
 impl BitOr for Bitboard64 {
     type Output = Self;
@@ -366,6 +564,24 @@ mod tests {
         assert_eq!(bb.pop_lsb(), None);
     }
 
+    #[test]
+    fn test_pop_msb() {
+        let mut bb = Bitboard64::from_squares(&[1, 5, 63]);
+
+        assert_eq!(bb.pop_msb(), Some(63));
+        assert_eq!(bb.pop_msb(), Some(5));
+        assert_eq!(bb.pop_msb(), Some(1));
+        assert_eq!(bb.pop_msb(), None);
+    }
+
+    #[test]
+    fn test_reverse_iterator() {
+        let bb = Bitboard64::from_squares(&[1, 5, 10]);
+        let iter = bb.iter_rev();
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.collect::<Vec<_>>(), vec![10, 5, 1]);
+    }
+
     #[test]
     fn test_iterator() {
         let bb = Bitboard64::from_squares(&[0, 5, 10, 20]);
@@ -373,6 +589,27 @@ mod tests {
         assert_eq!(squares, vec![0, 5, 10, 20]);
     }
 
+    #[test]
+    fn test_subsets() {
+        let mask = Bitboard64::from_squares(&[1, 4, 6]);
+        let subsets: Vec<_> = mask.subsets().collect();
+        assert_eq!(
+            subsets,
+            vec![
+                Bitboard64::EMPTY,
+                Bitboard64::from_squares(&[1]),
+                Bitboard64::from_squares(&[4]),
+                Bitboard64::from_squares(&[1, 4]),
+                Bitboard64::from_squares(&[6]),
+                Bitboard64::from_squares(&[1, 6]),
+                Bitboard64::from_squares(&[4, 6]),
+                Bitboard64::from_squares(&[1, 4, 6]),
+            ]
+        );
+
+        assert_eq!(Bitboard64::EMPTY.subsets().count(), 1);
+    }
+
     #[test]
     fn test_bitwise_ops() {
         let a = Bitboard64::from_squares(&[0, 1, 2]);
@@ -408,6 +645,104 @@ mod tests {
         assert!(west.get(27)); // d4
     }
 
+    #[test]
+    fn test_diagonal_shifts() {
+        let bb = Bitboard64::from_square(28); // e4
+        assert_eq!(bb.north_east(), Bitboard64::from_square(37)); // f5
+        assert_eq!(bb.north_west(), Bitboard64::from_square(35)); // d5
+        assert_eq!(bb.south_east(), Bitboard64::from_square(21)); // f3
+        assert_eq!(bb.south_west(), Bitboard64::from_square(19)); // d3
+    }
+
+    #[test]
+    fn test_diagonal_shifts_do_not_wrap() {
+        // h4 shifted north-east must not wrap into a6
+        let h4 = Bitboard64::from_square(31);
+        assert!(h4.north_east().is_empty());
+        assert!(h4.south_east().is_empty());
+
+        // a4 shifted west-wards must not wrap into the h-file
+        let a4 = Bitboard64::from_square(24);
+        assert!(a4.north_west().is_empty());
+        assert!(a4.south_west().is_empty());
+
+        // Corner bits disappear off the board
+        assert!(Bitboard64::from_square(63).north_east().is_empty()); // h8
+        assert!(Bitboard64::from_square(56).north_west().is_empty()); // a8
+        assert!(Bitboard64::from_square(7).south_east().is_empty()); // h1
+        assert!(Bitboard64::from_square(0).south_west().is_empty()); // a1
+    }
+
+    #[test]
+    fn test_fill_orthogonal_stops_at_blockers() {
+        // Rook on a1, blocker on a5
+        let occupied = Bitboard64::from_squares(&[0, 32]);
+        let north = Bitboard64::from_square(0).fill_north(!occupied);
+        assert_eq!(north, Bitboard64::from_squares(&[0, 8, 16, 24]));
+
+        // Rook on e8 sliding down an empty file
+        let south = Bitboard64::from_square(60).fill_south(Bitboard64::ALL);
+        assert_eq!(south, Bitboard64::FILE_E);
+    }
+
+    #[test]
+    fn test_fill_diagonals() {
+        let c1 = Bitboard64::from_square(2);
+        let empty = Bitboard64::ALL;
+        assert_eq!(
+            c1.fill_north_east(empty),
+            Bitboard64::from_squares(&[2, 11, 20, 29, 38, 47])
+        );
+        assert_eq!(
+            c1.fill_north_west(empty),
+            Bitboard64::from_squares(&[2, 9, 16])
+        );
+
+        let f8 = Bitboard64::from_square(61);
+        assert_eq!(
+            f8.fill_south_east(empty),
+            Bitboard64::from_squares(&[61, 54, 47])
+        );
+        // Blocker on c5 stops the south-west fill
+        let blocked = !Bitboard64::from_square(34);
+        assert_eq!(
+            f8.fill_south_west(blocked),
+            Bitboard64::from_squares(&[61, 52, 43])
+        );
+    }
+
+    #[test]
+    fn test_file_fill() {
+        let pawns = Bitboard64::from_squares(&[28, 8]); // e4, a2
+        assert_eq!(pawns.file_fill(), Bitboard64::FILE_E | Bitboard64::FILE_A);
+        assert!(Bitboard64::EMPTY.file_fill().is_empty());
+    }
+
+    #[test]
+    fn test_flips() {
+        let bb = Bitboard64::from_squares(&[0, 9, 28]); // a1, b2, e4
+        assert_eq!(
+            bb.flip_vertical(),
+            Bitboard64::from_squares(&[56, 49, 36]) // a8, b7, e5
+        );
+        assert_eq!(
+            bb.flip_horizontal(),
+            Bitboard64::from_squares(&[7, 14, 27]) // h1, g2, d4
+        );
+        assert_eq!(Bitboard64::FILE_A.flip_diagonal(), Bitboard64::RANK_1);
+        assert_eq!(
+            Bitboard64::from_square(8).flip_diagonal(), // a2
+            Bitboard64::from_square(1)                  // b1
+        );
+
+        for bits in [0u64, !0, 0x0123_4567_89ab_cdef, 0x8000_0000_0000_0001] {
+            let bb = Bitboard64(bits);
+            assert_eq!(bb.flip_vertical().flip_vertical(), bb);
+            assert_eq!(bb.flip_horizontal().flip_horizontal(), bb);
+            assert_eq!(bb.flip_diagonal().flip_diagonal(), bb);
+        }
+    }
+
     #[test]
     fn test_file_masks() {
         assert_eq!(Bitboard64::FILE_A.popcount(), 8);
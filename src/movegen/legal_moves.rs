@@ -4,10 +4,11 @@
 //! It uses the attack tables from the attacks module for efficient computation.
 
 use super::{
-    bishop_attacks, king_attacks, knight_attacks, pawn_attacks, queen_attacks, rook_attacks,
-    Bitboard64,
+    attackers_to, between, bishop_attacks, king_attacks, knight_attacks, line_through,
+    pawn_attacks, piece_attacks, queen_attacks, rook_attacks, Bitboard64,
 };
 use crate::core::{Color, Coord, GameState, Move, MoveFlags, Piece, PieceType, StandardBoard};
+use std::collections::HashMap;
 
 /// Direction rays for slider pin detection.
 const DIRECTIONS: [(i32, i32); 8] = [
@@ -32,8 +33,8 @@ pub struct MoveGenerator<'a> {
     them: Bitboard64,
     /// Our color
     color: Color,
-    /// King position (as square index)
-    king_sq: usize,
+    /// King position (as square index); None in kingless variants like Horde
+    king_sq: Option<usize>,
     /// Squares attacked by the enemy
     enemy_attacks: Bitboard64,
     /// Number of attackers on our king
@@ -54,8 +55,9 @@ impl<'a> MoveGenerator<'a> {
         let them = board.pieces_of_color(color.opposite());
 
         // Find our king
-        let king_coord = board.find_king(color).expect("King must exist");
-        let king_sq = StandardBoard::to_index(&king_coord).unwrap();
+        let king_sq = board
+            .find_king(color)
+            .and_then(|king| StandardBoard::to_index(&king));
 
         // Initialize with empty values; will be computed in analyze()
         let mut gen = Self {
@@ -76,20 +78,26 @@ impl<'a> MoveGenerator<'a> {
     }
 
     /// Analyzes the position to compute attacks, checks, and pins.
+    ///
+    /// Without a king there is nothing to attack, check, or pin against.
     fn analyze(&mut self) {
-        self.compute_enemy_attacks();
-        self.compute_checkers();
-        self.compute_pins();
+        let Some(king_sq) = self.king_sq else {
+            return;
+        };
+        self.compute_enemy_attacks(king_sq);
+        self.compute_checkers(king_sq);
+        self.compute_pins(king_sq);
     }
 
     /// Computes all squares attacked by enemy pieces.
-    fn compute_enemy_attacks(&mut self) {
+    fn compute_enemy_attacks(&mut self, king_sq: usize) {
         let board = self.game.board();
         let enemy_color = self.color.opposite();
 
         // Remove our king from occupied for slider attacks
         // (king must not block attacks that go through him)
-        let occupied_no_king = self.occupied & !Bitboard64::from_square(self.king_sq);
+        let king = StandardBoard::from_index(king_sq).unwrap();
+        let occupied_no_king = board.occupied_without(&king);
 
         let mut attacks = Bitboard64::EMPTY;
 
@@ -99,72 +107,15 @@ impl<'a> MoveGenerator<'a> {
             }
 
             let sq = StandardBoard::to_index(&coord).unwrap();
-
-            let piece_attacks = match piece.piece_type {
-                PieceType::Pawn => pawn_attacks(sq, enemy_color as usize),
-                PieceType::Knight => knight_attacks(sq),
-                PieceType::Bishop => bishop_attacks(sq, occupied_no_king),
-                PieceType::Rook => rook_attacks(sq, occupied_no_king),
-                PieceType::Queen => queen_attacks(sq, occupied_no_king),
-                PieceType::King => king_attacks(sq),
-            };
-
-            attacks |= piece_attacks;
+            attacks |= piece_attacks(piece, sq, occupied_no_king);
         }
 
         self.enemy_attacks = attacks;
     }
 
     /// Computes pieces that are giving check to our king.
-    fn compute_checkers(&mut self) {
-        let enemy_color = self.color.opposite();
-
-        let mut checkers = Bitboard64::EMPTY;
-
-        // Pawns
-        let pawn_attacks_to_king = pawn_attacks(self.king_sq, self.color as usize);
-        for sq in pawn_attacks_to_king.iter() {
-            if let Some(piece) = self.piece_at_sq(sq) {
-                if piece.color == enemy_color && piece.piece_type == PieceType::Pawn {
-                    checkers.set(sq);
-                }
-            }
-        }
-
-        // Knights
-        let knight_attacks_to_king = knight_attacks(self.king_sq);
-        for sq in knight_attacks_to_king.iter() {
-            if let Some(piece) = self.piece_at_sq(sq) {
-                if piece.color == enemy_color && piece.piece_type == PieceType::Knight {
-                    checkers.set(sq);
-                }
-            }
-        }
-
-        // Bishops and Queens (diagonal)
-        let bishop_attacks_to_king = bishop_attacks(self.king_sq, self.occupied);
-        for sq in bishop_attacks_to_king.iter() {
-            if let Some(piece) = self.piece_at_sq(sq) {
-                if piece.color == enemy_color
-                    && (piece.piece_type == PieceType::Bishop
-                        || piece.piece_type == PieceType::Queen)
-                {
-                    checkers.set(sq);
-                }
-            }
-        }
-
-        // Rooks and Queens (orthogonal)
-        let rook_attacks_to_king = rook_attacks(self.king_sq, self.occupied);
-        for sq in rook_attacks_to_king.iter() {
-            if let Some(piece) = self.piece_at_sq(sq) {
-                if piece.color == enemy_color
-                    && (piece.piece_type == PieceType::Rook || piece.piece_type == PieceType::Queen)
-                {
-                    checkers.set(sq);
-                }
-            }
-        }
+    fn compute_checkers(&mut self, king_sq: usize) {
+        let checkers = attackers_to(self.game.board(), king_sq, self.occupied) & self.them;
 
         self.checkers = checkers;
 
@@ -183,8 +134,7 @@ impl<'a> MoveGenerator<'a> {
                     || piece.piece_type == PieceType::Rook
                     || piece.piece_type == PieceType::Queen
                 {
-                    let between = Self::between_squares(checker_sq, self.king_sq);
-                    self.check_mask |= between;
+                    self.check_mask |= between(checker_sq, king_sq);
                 }
             }
         } else {
@@ -194,19 +144,17 @@ impl<'a> MoveGenerator<'a> {
     }
 
     /// Computes pinned pieces and their movement masks.
-    fn compute_pins(&mut self) {
-        let king_file = self.king_sq % 8;
-        let king_rank = self.king_sq / 8;
+    fn compute_pins(&mut self, king_sq: usize) {
+        let king_file = king_sq % 8;
+        let king_rank = king_sq / 8;
 
         for (df, dr) in DIRECTIONS {
-            let mut ray = Bitboard64::EMPTY;
             let mut pinned_sq: Option<usize> = None;
             let mut f = king_file as i32 + df;
             let mut r = king_rank as i32 + dr;
 
             while (0..8).contains(&f) && (0..8).contains(&r) {
                 let sq = (r * 8 + f) as usize;
-                ray.set(sq);
 
                 if self.occupied.get(sq) {
                     if let Some(piece) = self.piece_at_sq(sq) {
@@ -235,9 +183,8 @@ impl<'a> MoveGenerator<'a> {
                             if is_pinner {
                                 if let Some(pinned) = pinned_sq {
                                     // The piece at pinned_sq is pinned
-                                    // It can only move along the pin ray
-                                    self.pin_masks[pinned] =
-                                        ray | Bitboard64::from_square(self.king_sq);
+                                    // It can only move along the pin line
+                                    self.pin_masks[pinned] = line_through(king_sq, sq);
                                 }
                             }
                             break;
@@ -251,33 +198,6 @@ impl<'a> MoveGenerator<'a> {
         }
     }
 
-    /// Returns the squares between two squares (exclusive).
-    fn between_squares(sq1: usize, sq2: usize) -> Bitboard64 {
-        let f1 = sq1 % 8;
-        let r1 = sq1 / 8;
-        let f2 = sq2 % 8;
-        let r2 = sq2 / 8;
-
-        let df = (f2 as i32 - f1 as i32).signum();
-        let dr = (r2 as i32 - r1 as i32).signum();
-
-        let mut between = Bitboard64::EMPTY;
-        let mut f = f1 as i32 + df;
-        let mut r = r1 as i32 + dr;
-
-        while f != f2 as i32 || r != r2 as i32 {
-            if !(0..8).contains(&f) || !(0..8).contains(&r) {
-                break;
-            }
-            let sq = (r * 8 + f) as usize;
-            between.set(sq);
-            f += df;
-            r += dr;
-        }
-
-        between
-    }
-
     /// Helper to get piece at square index.
     fn piece_at_sq(&self, sq: usize) -> Option<Piece> {
         StandardBoard::from_index(sq).and_then(|coord| self.game.board().piece_at(&coord))
@@ -295,45 +215,121 @@ impl<'a> MoveGenerator<'a> {
 
     /// Generates all legal moves.
     pub fn generate_moves(&self) -> Vec<Move> {
+        self.generate_filtered(Bitboard64::ALL)
+    }
+
+    /// Generates only the legal captures, including en passant and
+    /// promotions that capture.
+    pub fn generate_captures(&self) -> Vec<Move> {
+        self.generate_filtered(self.them)
+    }
+
+    /// Generates only the legal non-captures, including castling and quiet
+    /// promotions.
+    pub fn generate_quiets(&self) -> Vec<Move> {
+        self.generate_filtered(!self.them)
+    }
+
+    /// Generates only the legal promotions that do not capture.
+    pub fn generate_quiet_promotions(&self) -> Vec<Move> {
+        let promo_rank = if self.color == Color::White {
+            Bitboard64::RANK_8
+        } else {
+            Bitboard64::RANK_1
+        };
+        let mut moves = self.generate_filtered(promo_rank & !self.them);
+        moves.retain(|mv| mv.is_promotion());
+        moves
+    }
+
+    /// Lazily yields the legal moves in stages: captures first, then quiets.
+    ///
+    /// Each stage is only generated once the previous one is exhausted, so a
+    /// search that cuts off on an early capture never generates quiet moves.
+    pub fn iter_moves(&self) -> impl Iterator<Item = Move> + '_ {
+        let captures = self.them;
+        [captures, !captures]
+            .into_iter()
+            .flat_map(move |targets| self.generate_filtered(targets))
+    }
+
+    /// Generates only the moves that get out of check.
+    ///
+    /// King moves come first, then (in single check) captures of the checker
+    /// and interpositions; castling and other quiet work is skipped. Outside
+    /// of check this is the same as [`MoveGenerator::generate_moves`].
+    pub fn generate_evasions(&self) -> Vec<Move> {
+        if !self.in_check() {
+            return self.generate_moves();
+        }
+
+        let mut moves = Vec::with_capacity(16);
+        self.generate_king_moves(&mut moves, Bitboard64::ALL);
+        if !self.in_double_check() {
+            let blocks = self.check_mask;
+            self.generate_pawn_moves(&mut moves, blocks);
+            self.generate_knight_moves(&mut moves, blocks);
+            self.generate_bishop_moves(&mut moves, blocks);
+            self.generate_rook_moves(&mut moves, blocks);
+            self.generate_queen_moves(&mut moves, blocks);
+        }
+        moves
+    }
+
+    /// Groups the legal moves by their origin square.
+    pub fn moves_by_piece(&self) -> HashMap<Coord, Vec<Move>> {
+        let mut groups: HashMap<Coord, Vec<Move>> = HashMap::new();
+        for mv in self.generate_moves() {
+            groups.entry(mv.from).or_default().push(mv);
+        }
+        groups
+    }
+
+    /// Generates the legal moves whose destination lies in `targets`.
+    ///
+    /// En passant is filtered by the captured pawn's square instead, so it
+    /// counts as a capture.
+    fn generate_filtered(&self, targets: Bitboard64) -> Vec<Move> {
         let mut moves = Vec::with_capacity(64);
 
         // In double check, only king can move
         if self.in_double_check() {
-            self.generate_king_moves(&mut moves);
+            self.generate_king_moves(&mut moves, targets);
             return moves;
         }
 
         // Generate all piece moves
-        self.generate_pawn_moves(&mut moves);
-        self.generate_knight_moves(&mut moves);
-        self.generate_bishop_moves(&mut moves);
-        self.generate_rook_moves(&mut moves);
-        self.generate_queen_moves(&mut moves);
-        self.generate_king_moves(&mut moves);
+        self.generate_pawn_moves(&mut moves, targets);
+        self.generate_knight_moves(&mut moves, targets);
+        self.generate_bishop_moves(&mut moves, targets);
+        self.generate_rook_moves(&mut moves, targets);
+        self.generate_queen_moves(&mut moves, targets);
+        self.generate_king_moves(&mut moves, targets);
 
         // Castling (only when not in check)
         if !self.in_check() {
-            self.generate_castling_moves(&mut moves);
+            self.generate_castling_moves(&mut moves, targets);
         }
 
         moves
     }
 
     /// Generates pawn moves.
-    fn generate_pawn_moves(&self, moves: &mut Vec<Move>) {
+    fn generate_pawn_moves(&self, moves: &mut Vec<Move>, targets: Bitboard64) {
         let board = self.game.board();
         let forward = if self.color == Color::White { 8i32 } else { -8i32 };
-        let start_rank = if self.color == Color::White { 1 } else { 6 };
+        // White pawns on the first rank (Horde) may also advance two squares
+        let start_ranks: &[u8] = if self.color == Color::White {
+            &[0, 1]
+        } else {
+            &[6]
+        };
         let promo_rank = if self.color == Color::White { 7 } else { 0 };
         let ep_rank = if self.color == Color::White { 4 } else { 3 };
 
-        for (coord, piece) in board.pieces() {
-            if piece.color != self.color || piece.piece_type != PieceType::Pawn {
-                continue;
-            }
-
-            let sq = StandardBoard::to_index(&coord).unwrap();
-            let pin_mask = self.pin_masks[sq];
+        for sq in board.pieces_of(self.color, PieceType::Pawn).iter() {
+            let coord = StandardBoard::from_index(sq).unwrap();
+            let pin_mask = self.pin_masks[sq] & targets;
 
             // Single push
             let target_sq = (sq as i32 + forward) as usize;
@@ -360,7 +356,7 @@ impl<'a> MoveGenerator<'a> {
             }
 
             // Double push
-            if coord.rank == start_rank {
+            if start_ranks.contains(&coord.rank) {
                 let single_sq = (sq as i32 + forward) as usize;
                 let double_sq = (sq as i32 + forward * 2) as usize;
                 if !self.occupied.get(single_sq) && !self.occupied.get(double_sq) {
@@ -409,7 +405,8 @@ impl<'a> MoveGenerator<'a> {
                             let captured_sq = (ep_sq as i32 - forward) as usize;
                             let captured_bb = Bitboard64::from_square(captured_sq);
                             if (self.check_mask & (target | captured_bb)).0 != 0
-                                && (pin_mask & target).0 != 0
+                                && (self.pin_masks[sq] & target).0 != 0
+                                && targets.get(captured_sq)
                             {
                                 moves.push(Move::with_flags(coord, ep_target, MoveFlags::EnPassant));
                             }
@@ -422,6 +419,9 @@ impl<'a> MoveGenerator<'a> {
 
     /// Checks if en passant is legal (doesn't expose king to discovered check).
     fn is_en_passant_legal(&self, pawn_sq: usize, ep_sq: usize) -> bool {
+        let Some(king_sq) = self.king_sq else {
+            return true;
+        };
         let forward = if self.color == Color::White { 8i32 } else { -8i32 };
         let captured_sq = (ep_sq as i32 - forward) as usize;
 
@@ -431,33 +431,28 @@ impl<'a> MoveGenerator<'a> {
         new_occupied.clear(captured_sq);
         new_occupied.set(ep_sq);
 
-        // Check if king is attacked horizontally (the most common discovered check in EP)
-        let rook_attacks_to_king = rook_attacks(self.king_sq, new_occupied);
+        // Removing both pawns can open a rank (rook/queen) or, through the
+        // captured pawn's square, a diagonal (bishop/queen) onto the king
         let enemy_color = self.color.opposite();
+        let exposed_by = |attacks: Bitboard64, slider: PieceType| {
+            attacks.iter().any(|sq| {
+                self.piece_at_sq(sq).is_some_and(|piece| {
+                    piece.color == enemy_color
+                        && (piece.piece_type == slider || piece.piece_type == PieceType::Queen)
+                })
+            })
+        };
 
-        for sq in rook_attacks_to_king.iter() {
-            if let Some(piece) = self.piece_at_sq(sq) {
-                if piece.color == enemy_color
-                    && (piece.piece_type == PieceType::Rook || piece.piece_type == PieceType::Queen)
-                {
-                    return false;
-                }
-            }
-        }
-
-        true
+        !exposed_by(rook_attacks(king_sq, new_occupied), PieceType::Rook)
+            && !exposed_by(bishop_attacks(king_sq, new_occupied), PieceType::Bishop)
     }
 
     /// Generates knight moves.
-    fn generate_knight_moves(&self, moves: &mut Vec<Move>) {
+    fn generate_knight_moves(&self, moves: &mut Vec<Move>, targets: Bitboard64) {
         let board = self.game.board();
 
-        for (coord, piece) in board.pieces() {
-            if piece.color != self.color || piece.piece_type != PieceType::Knight {
-                continue;
-            }
-
-            let sq = StandardBoard::to_index(&coord).unwrap();
+        for sq in board.pieces_of(self.color, PieceType::Knight).iter() {
+            let coord = StandardBoard::from_index(sq).unwrap();
             let pin_mask = self.pin_masks[sq];
 
             // Pinned knight can never move (can't stay on pin ray)
@@ -466,9 +461,9 @@ impl<'a> MoveGenerator<'a> {
             }
 
             let attacks = knight_attacks(sq);
-            let targets = attacks & !self.us & self.check_mask;
+            let destinations = attacks & !self.us & self.check_mask & targets;
 
-            for target_sq in targets.iter() {
+            for target_sq in destinations.iter() {
                 let to = StandardBoard::from_index(target_sq).unwrap();
                 moves.push(Move::new(coord, to));
             }
@@ -476,39 +471,40 @@ impl<'a> MoveGenerator<'a> {
     }
 
     /// Generates bishop moves.
-    fn generate_bishop_moves(&self, moves: &mut Vec<Move>) {
-        self.generate_slider_moves(moves, PieceType::Bishop, bishop_attacks);
+    fn generate_bishop_moves(&self, moves: &mut Vec<Move>, targets: Bitboard64) {
+        self.generate_slider_moves(moves, targets, PieceType::Bishop, bishop_attacks);
     }
 
     /// Generates rook moves.
-    fn generate_rook_moves(&self, moves: &mut Vec<Move>) {
-        self.generate_slider_moves(moves, PieceType::Rook, rook_attacks);
+    fn generate_rook_moves(&self, moves: &mut Vec<Move>, targets: Bitboard64) {
+        self.generate_slider_moves(moves, targets, PieceType::Rook, rook_attacks);
     }
 
     /// Generates queen moves.
-    fn generate_queen_moves(&self, moves: &mut Vec<Move>) {
-        self.generate_slider_moves(moves, PieceType::Queen, queen_attacks);
+    fn generate_queen_moves(&self, moves: &mut Vec<Move>, targets: Bitboard64) {
+        self.generate_slider_moves(moves, targets, PieceType::Queen, queen_attacks);
     }
 
     /// Generic slider move generation.
-    fn generate_slider_moves<F>(&self, moves: &mut Vec<Move>, piece_type: PieceType, attacks_fn: F)
-    where
+    fn generate_slider_moves<F>(
+        &self,
+        moves: &mut Vec<Move>,
+        targets: Bitboard64,
+        piece_type: PieceType,
+        attacks_fn: F,
+    ) where
         F: Fn(usize, Bitboard64) -> Bitboard64,
     {
         let board = self.game.board();
 
-        for (coord, piece) in board.pieces() {
-            if piece.color != self.color || piece.piece_type != piece_type {
-                continue;
-            }
-
-            let sq = StandardBoard::to_index(&coord).unwrap();
+        for sq in board.pieces_of(self.color, piece_type).iter() {
+            let coord = StandardBoard::from_index(sq).unwrap();
             let pin_mask = self.pin_masks[sq];
 
             let attacks = attacks_fn(sq, self.occupied);
-            let targets = attacks & !self.us & self.check_mask & pin_mask;
+            let destinations = attacks & !self.us & self.check_mask & pin_mask & targets;
 
-            for target_sq in targets.iter() {
+            for target_sq in destinations.iter() {
                 let to = StandardBoard::from_index(target_sq).unwrap();
                 moves.push(Move::new(coord, to));
             }
@@ -516,12 +512,15 @@ impl<'a> MoveGenerator<'a> {
     }
 
     /// Generates king moves.
-    fn generate_king_moves(&self, moves: &mut Vec<Move>) {
-        let king_coord = StandardBoard::from_index(self.king_sq).unwrap();
-        let attacks = king_attacks(self.king_sq);
+    fn generate_king_moves(&self, moves: &mut Vec<Move>, targets: Bitboard64) {
+        let Some(king_sq) = self.king_sq else {
+            return;
+        };
+        let king_coord = StandardBoard::from_index(king_sq).unwrap();
+        let attacks = king_attacks(king_sq);
 
         // King can move to squares not attacked by enemy and not occupied by our pieces
-        let safe_squares = attacks & !self.enemy_attacks & !self.us;
+        let safe_squares = attacks & !self.enemy_attacks & !self.us & targets;
 
         for target_sq in safe_squares.iter() {
             let to = StandardBoard::from_index(target_sq).unwrap();
@@ -530,41 +529,18 @@ impl<'a> MoveGenerator<'a> {
     }
 
     /// Generates castling moves.
-    fn generate_castling_moves(&self, moves: &mut Vec<Move>) {
-        let rights = self.game.castling_rights(self.color);
-        let rank: u8 = if self.color == Color::White { 0 } else { 7 };
-        let rank_offset = rank as usize * 8;
-
-        // Kingside castling
-        if rights.kingside {
-            let f_sq = rank_offset + 5; // f1 or f8
-            let g_sq = rank_offset + 6; // g1 or g8
-
-            // Check squares are empty
-            if !self.occupied.get(f_sq) && !self.occupied.get(g_sq) {
-                // Check squares are not attacked
-                if !self.enemy_attacks.get(f_sq) && !self.enemy_attacks.get(g_sq) {
-                    let from = Coord::new(4, rank);
-                    let to = Coord::new(6, rank);
-                    moves.push(Move::with_flags(from, to, MoveFlags::CastleKingside));
-                }
-            }
-        }
-
-        // Queenside castling
-        if rights.queenside {
-            let b_sq = rank_offset + 1; // b1 or b8
-            let c_sq = rank_offset + 2; // c1 or c8
-            let d_sq = rank_offset + 3; // d1 or d8
-
-            // Check squares are empty (b, c, d must be empty)
-            if !self.occupied.get(b_sq) && !self.occupied.get(c_sq) && !self.occupied.get(d_sq) {
-                // Check king doesn't pass through attacked squares (c, d)
-                if !self.enemy_attacks.get(c_sq) && !self.enemy_attacks.get(d_sq) {
-                    let from = Coord::new(4, rank);
-                    let to = Coord::new(2, rank);
-                    moves.push(Move::with_flags(from, to, MoveFlags::CastleQueenside));
-                }
+    ///
+    /// The rules live in [`GameState::legal_castling`] so that standard and
+    /// Chess960 castling share one implementation. The king's destination
+    /// is matched against `targets`.
+    fn generate_castling_moves(&self, moves: &mut Vec<Move>, targets: Bitboard64) {
+        for kingside in [true, false] {
+            let (king_to, _, _) = self.game.castling_squares(self.color, kingside);
+            let Some(sq) = StandardBoard::to_index(&king_to) else {
+                continue;
+            };
+            if targets.get(sq) {
+                moves.extend(self.game.legal_castling(kingside));
             }
         }
     }
@@ -575,13 +551,96 @@ pub fn generate_legal_moves(game: &GameState) -> Vec<Move> {
     MoveGenerator::new(game).generate_moves()
 }
 
+/// Finds the legal move matching `mv`'s squares and promotion piece.
+///
+/// Moves parsed from UCI carry no special flags (castling, en passant, double
+/// push), so they are matched against the generated moves to recover them.
+/// Returns None if no such legal move exists.
+pub fn find_legal_move(game: &GameState, mv: &Move) -> Option<Move> {
+    generate_legal_moves(game).into_iter().find(|legal| {
+        legal.from == mv.from && legal.to == mv.to && legal.promoted_piece() == mv.promoted_piece()
+    })
+}
+
 /// Returns true if the position is in check.
 pub fn is_in_check(game: &GameState) -> bool {
     MoveGenerator::new(game).in_check()
 }
 
+/// Returns true if any piece of color `by` attacks `sq`.
+///
+/// Stops at the first attacker found, checking pawns, then knights, then
+/// sliders, then the king, so it is cheaper than building a full attack set.
+pub fn is_square_attacked(game: &GameState, sq: Coord, by: Color) -> bool {
+    let Some(index) = StandardBoard::to_index(&sq) else {
+        return false;
+    };
+    let board = game.board();
+    let occupied = board.occupied();
+    let attackers = board.pieces_of_color(by);
+    let has = |candidates: Bitboard64, types: &[PieceType]| {
+        (candidates & attackers).iter().any(|from| {
+            StandardBoard::from_index(from)
+                .and_then(|coord| board.piece_at(&coord))
+                .is_some_and(|piece| types.contains(&piece.piece_type))
+        })
+    };
+
+    has(
+        pawn_attacks(index, by.opposite() as usize),
+        &[PieceType::Pawn],
+    ) || has(knight_attacks(index), &[PieceType::Knight])
+        || has(
+            bishop_attacks(index, occupied),
+            &[PieceType::Bishop, PieceType::Queen],
+        )
+        || has(
+            rook_attacks(index, occupied),
+            &[PieceType::Rook, PieceType::Queen],
+        )
+        || has(king_attacks(index), &[PieceType::King])
+}
+
 /// Counts legal moves (for perft).
 pub fn perft(game: &GameState, depth: u32) -> u64 {
+    perft_in_place(&mut game.clone(), depth)
+}
+
+/// Counts the perft nodes below each legal root move.
+///
+/// The counts sum to `perft(game, depth)` for any depth of at least 1.
+/// Depth 0 has no root moves and returns an empty list.
+pub fn perft_divide(game: &GameState, depth: u32) -> Vec<(Move, u64)> {
+    if depth == 0 {
+        return Vec::new();
+    }
+    let mut game = game.clone();
+    generate_legal_moves(&game)
+        .into_iter()
+        .map(|mv| {
+            let undo = game.make_move(&mv);
+            let nodes = perft_in_place(&mut game, depth - 1);
+            game.unmake_move(&mv, undo);
+            (mv, nodes)
+        })
+        .collect()
+}
+
+/// Formats a perft divide as `e2e4: 20` lines sorted by UCI string,
+/// followed by the total, for diffing against a reference engine.
+pub fn format_divide(divide: &[(Move, u64)]) -> String {
+    let mut lines: Vec<String> = divide
+        .iter()
+        .map(|(mv, nodes)| format!("{}: {}", mv.to_uci(), nodes))
+        .collect();
+    lines.sort();
+    let total: u64 = divide.iter().map(|(_, nodes)| nodes).sum();
+    lines.push(format!("\nNodes searched: {}", total));
+    lines.join("\n")
+}
+
+/// Perft on a single state, using make/unmake instead of cloning per node.
+fn perft_in_place(game: &mut GameState, depth: u32) -> u64 {
     if depth == 0 {
         return 1;
     }
@@ -594,9 +653,9 @@ pub fn perft(game: &GameState, depth: u32) -> u64 {
 
     let mut nodes = 0;
     for mv in moves {
-        let mut new_game = game.clone();
-        new_game.make_move(&mv);
-        nodes += perft(&new_game, depth - 1);
+        let undo = game.make_move(&mv);
+        nodes += perft_in_place(game, depth - 1);
+        game.unmake_move(&mv, undo);
     }
 
     nodes
@@ -638,6 +697,32 @@ mod tests {
         assert!(is_in_check(&game));
     }
 
+    #[test]
+    fn test_perft_divide_sums_to_perft() {
+        let kiwipete = GameState::from_fen(
+            "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+        )
+        .unwrap();
+        for game in [GameState::starting_position(), kiwipete] {
+            for depth in 1..=3 {
+                let divide = perft_divide(&game, depth);
+                let total: u64 = divide.iter().map(|(_, nodes)| nodes).sum();
+                assert_eq!(total, perft(&game, depth));
+            }
+        }
+        assert!(perft_divide(&GameState::starting_position(), 0).is_empty());
+    }
+
+    #[test]
+    fn test_format_divide() {
+        let divide = perft_divide(&GameState::starting_position(), 2);
+        let text = format_divide(&divide);
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines[0], "a2a3: 20");
+        assert!(lines.contains(&"g1f3: 20"));
+        assert_eq!(lines.last(), Some(&"Nodes searched: 400"));
+    }
+
     #[test]
     fn test_perft_depth_1() {
         let game = GameState::starting_position();
@@ -662,6 +747,71 @@ mod tests {
         assert_eq!(perft(&game, 4), 197281);
     }
 
+    #[test]
+    fn test_perft_kiwipete() {
+        let game = GameState::from_fen(
+            "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+        )
+        .unwrap();
+        assert_eq!(perft(&game, 1), 48);
+        assert_eq!(perft(&game, 2), 2039);
+        assert_eq!(perft(&game, 3), 97862);
+    }
+
+    #[test]
+    fn test_perft_position_3() {
+        let game = GameState::from_fen("8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1").unwrap();
+        assert_eq!(perft(&game, 1), 14);
+        assert_eq!(perft(&game, 2), 191);
+        assert_eq!(perft(&game, 3), 2812);
+        assert_eq!(perft(&game, 4), 43238);
+    }
+
+    #[test]
+    fn test_perft_position_4() {
+        let game =
+            GameState::from_fen("r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq - 0 1")
+                .unwrap();
+        assert_eq!(perft(&game, 1), 6);
+        assert_eq!(perft(&game, 2), 264);
+        assert_eq!(perft(&game, 3), 9467);
+    }
+
+    #[test]
+    fn test_perft_position_5() {
+        let game = GameState::from_fen("rnbq1k1r/pp1Pbppp/2p5/8/2B5/8/PPP1NnPP/RNBQK2R w KQ - 1 8")
+            .unwrap();
+        assert_eq!(perft(&game, 1), 44);
+        assert_eq!(perft(&game, 2), 1486);
+        assert_eq!(perft(&game, 3), 62379);
+    }
+
+    #[test]
+    fn test_perft_chess960() {
+        let game = GameState::from_fen(
+            "bqnb1rkr/pp3ppp/3ppn2/2p5/5P2/P2P4/NPP1P1PP/BQ1BNRKR w HFhf - 2 9",
+        )
+        .unwrap();
+        assert_eq!(perft(&game, 1), 21);
+        assert_eq!(perft(&game, 2), 528);
+        assert_eq!(perft(&game, 3), 12189);
+
+        let game =
+            GameState::from_fen("2nnrbkr/p1qppppp/8/1ppb4/6PP/3PP3/PPP2P2/BQNNRBKR w HEhe - 1 9")
+                .unwrap();
+        assert_eq!(perft(&game, 3), 18002);
+    }
+
+    #[test]
+    fn test_find_legal_move() {
+        let game = GameState::from_fen("r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1").unwrap();
+
+        let castle = find_legal_move(&game, &Move::from_uci("e1g1").unwrap()).unwrap();
+        assert!(matches!(castle.flags, MoveFlags::CastleKingside));
+
+        assert!(find_legal_move(&game, &Move::from_uci("e1e3").unwrap()).is_none());
+    }
+
     #[test]
     fn test_castling_available() {
         let game = GameState::from_fen("r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1").unwrap();
@@ -705,6 +855,21 @@ mod tests {
         assert_eq!(ep_moves.len(), 1);
     }
 
+    #[test]
+    fn test_en_passant_diagonal_discovered_check() {
+        // dxe6 e.p. removes the e5 pawn and opens the h8-a1 diagonal onto the king
+        let game = GameState::from_fen("4k2b/8/8/3Pp3/8/8/8/K7 w - e6 0 1").unwrap();
+        let moves = generate_legal_moves(&game);
+        assert!(!moves.iter().any(|m| m.is_en_passant()));
+        assert!(moves.contains(&Move::new(Coord::new(3, 4), Coord::new(3, 5))));
+
+        // Without the bishop the capture is fine
+        let game = GameState::from_fen("4k3/8/8/3Pp3/8/8/8/K7 w - e6 0 1").unwrap();
+        assert!(generate_legal_moves(&game)
+            .iter()
+            .any(|m| m.is_en_passant()));
+    }
+
     #[test]
     fn test_promotion() {
         let game = GameState::from_fen("8/P7/8/8/8/8/8/4K2k w - - 0 1").unwrap();
@@ -719,6 +884,69 @@ mod tests {
         assert_eq!(promo_moves.len(), 4);
     }
 
+    #[test]
+    fn test_is_square_attacked() {
+        // The a5 bishop covers e1, so the f1 king may not step there
+        let game = GameState::from_fen("4k3/8/8/b7/8/8/8/5K2 w - - 0 1").unwrap();
+        let e1 = Coord::new(4, 0);
+        assert!(is_square_attacked(&game, e1, Color::Black));
+        assert!(is_square_attacked(&game, e1, Color::White)); // f1 king
+        assert!(!is_square_attacked(&game, Coord::new(4, 1), Color::Black));
+        assert!(!is_square_attacked(&game, Coord::new(2, 0), Color::White));
+        assert!(!generate_legal_moves(&game).iter().any(|m| m.to == e1));
+
+        // A pawn on c3 blocks the diagonal
+        let blocked = GameState::from_fen("4k3/8/8/b7/8/2P5/8/5K2 w - - 0 1").unwrap();
+        assert!(!is_square_attacked(&blocked, e1, Color::Black));
+
+        // Pawns attack diagonally forward only
+        let start = GameState::starting_position();
+        assert!(is_square_attacked(&start, Coord::new(4, 2), Color::White));
+        assert!(!is_square_attacked(&start, Coord::new(4, 3), Color::White));
+        assert!(is_square_attacked(&start, Coord::new(4, 5), Color::Black));
+    }
+
+    #[test]
+    fn test_captures_and_quiets_partition_moves() {
+        let fens = [
+            // Kiwipete
+            "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+            // En passant and capture-promotions
+            "1r2k3/P1P5/8/3pP3/8/8/8/4K3 w - d6 0 1",
+            // In check: only captures of the checker and blocks
+            "4k3/8/8/8/8/8/3q4/R3K3 w Q - 0 1",
+        ];
+        for fen in fens {
+            let game = GameState::from_fen(fen).unwrap();
+            let gen = MoveGenerator::new(&game);
+            let all = gen.generate_moves();
+
+            let expected: Vec<Move> = all
+                .iter()
+                .copied()
+                .filter(|m| {
+                    m.is_en_passant() || gen.them.get(StandardBoard::to_index(&m.to).unwrap())
+                })
+                .collect();
+            let captures = gen.generate_captures();
+            assert_eq!(captures.len(), expected.len(), "{}", fen);
+            assert!(captures.iter().all(|m| expected.contains(m)), "{}", fen);
+
+            let quiets = gen.generate_quiets();
+            assert_eq!(captures.len() + quiets.len(), all.len(), "{}", fen);
+            assert!(quiets
+                .iter()
+                .all(|m| all.contains(m) && !captures.contains(m)));
+        }
+
+        let game = GameState::from_fen(fens[1]).unwrap();
+        let gen = MoveGenerator::new(&game);
+        let captures = gen.generate_captures();
+        assert!(captures.iter().any(|m| m.is_en_passant()));
+        assert_eq!(captures.iter().filter(|m| m.is_promotion()).count(), 8); // axb8, cxb8
+        assert_eq!(gen.generate_quiet_promotions().len(), 8); // a8, c8
+    }
+
     #[test]
     fn test_pin_restricts_movement() {
         // Knight pinned to king by rook
@@ -749,4 +977,92 @@ mod tests {
             piece.is_some_and(|p| p.piece_type == PieceType::King)
         }));
     }
+
+    #[test]
+    fn test_iter_moves_matches_generate_moves() {
+        let fens = [
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+            "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+            "rnbqkbnr/ppp2ppp/8/3ppP2/4P3/8/PPPP2PP/RNBQKBNR w KQkq e6 0 4",
+            "1r5k/P7/8/8/8/8/8/4K3 w - - 0 1",
+            "4k3/8/8/8/8/8/2q5/4K3 w - - 0 1",
+        ];
+        for fen in fens {
+            let game = GameState::from_fen(fen).unwrap();
+            let gen = MoveGenerator::new(&game);
+
+            let mut lazy: Vec<Move> = gen.iter_moves().collect();
+            let mut full = gen.generate_moves();
+            assert_eq!(lazy.len(), full.len(), "{}", fen);
+            lazy.sort_by_key(|m| m.to_u16());
+            full.sort_by_key(|m| m.to_u16());
+            assert_eq!(lazy, full, "{}", fen);
+        }
+    }
+
+    #[test]
+    fn test_iter_moves_yields_captures_first() {
+        let game =
+            GameState::from_fen("rnbqkbnr/ppp2ppp/8/3ppP2/4P3/8/PPPP2PP/RNBQKBNR w KQkq e6 0 4")
+                .unwrap();
+        let gen = MoveGenerator::new(&game);
+        let moves: Vec<Move> = gen.iter_moves().collect();
+
+        let first_quiet = moves.iter().position(|m| !game.is_capture(m)).unwrap();
+        assert_eq!(first_quiet, 2); // exd5 and fxe6 e.p.
+        assert!(moves[first_quiet..].iter().all(|m| !game.is_capture(m)));
+    }
+
+    #[test]
+    fn test_moves_by_piece() {
+        let game = GameState::from_fen(
+            "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+        )
+        .unwrap();
+        let gen = MoveGenerator::new(&game);
+        let groups = gen.moves_by_piece();
+
+        let total: usize = groups.values().map(Vec::len).sum();
+        assert_eq!(total, gen.generate_moves().len());
+        for (origin, moves) in &groups {
+            assert!(moves.iter().all(|m| m.from == *origin));
+        }
+
+        // Knight on e5 has 7 moves (Nxd7, Nxf7, Nxg6, Nc6, Nc4, Ng4, Nd3)
+        assert_eq!(groups[&Coord::new(4, 4)].len(), 7);
+    }
+
+    #[test]
+    fn test_generate_evasions() {
+        let fens = [
+            // Rook check: king moves, block with the bishop, capture with the knight
+            "4k3/8/8/8/4r3/8/2B2N2/4K3 w - - 0 1",
+            // Pawn check that can be answered en passant
+            "8/8/8/4k3/4pP2/8/8/4K3 b - f3 0 1",
+            // Double check by rook and knight: Bxd3 is not enough
+            "4r1k1/8/8/8/8/3n4/8/1B2K3 w - - 0 1",
+        ];
+        for fen in fens {
+            let game = GameState::from_fen(fen).unwrap();
+            let gen = MoveGenerator::new(&game);
+            assert!(gen.in_check(), "{}", fen);
+
+            let mut evasions = gen.generate_evasions();
+            let mut full = gen.generate_moves();
+            evasions.sort_by_key(|m| m.to_u16());
+            full.sort_by_key(|m| m.to_u16());
+            assert_eq!(evasions, full, "{}", fen);
+        }
+
+        let game = GameState::from_fen("8/8/8/4k3/4pP2/8/8/4K3 b - f3 0 1").unwrap();
+        let evasions = MoveGenerator::new(&game).generate_evasions();
+        assert!(evasions.iter().any(|m| m.is_en_passant()));
+
+        let game = GameState::from_fen(fens[2]).unwrap();
+        let gen = MoveGenerator::new(&game);
+        assert!(gen.in_double_check());
+        let evasions = gen.generate_evasions();
+        assert!(!evasions.is_empty());
+        assert!(evasions.iter().all(|m| m.from == Coord::new(4, 0)));
+    }
 }
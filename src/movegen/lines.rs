@@ -0,0 +1,113 @@
+//! Precomputed line segments between aligned squares.
+
+use super::attacks::{bishop_attacks, rook_attacks};
+use super::bitboard::Bitboard64;
+use std::sync::OnceLock;
+
+/// Global table of the squares strictly between two aligned squares.
+static BETWEEN: OnceLock<[[Bitboard64; 64]; 64]> = OnceLock::new();
+
+/// Global table of the full line through two aligned squares.
+static LINE: OnceLock<[[Bitboard64; 64]; 64]> = OnceLock::new();
+
+/// Initializes the between table from the slider attack tables.
+///
+/// Two squares are aligned when a rook or bishop on one attacks the other on
+/// an empty board; the segment is where their mutual attacks overlap.
+fn init_between() -> [[Bitboard64; 64]; 64] {
+    let mut table = [[Bitboard64::EMPTY; 64]; 64];
+
+    for (a, row) in table.iter_mut().enumerate() {
+        for (b, segment) in row.iter_mut().enumerate() {
+            let target = Bitboard64::from_square(b);
+            let origin = Bitboard64::from_square(a);
+            if (rook_attacks(a, Bitboard64::EMPTY) & target).is_not_empty() {
+                *segment = rook_attacks(a, target) & rook_attacks(b, origin);
+            } else if (bishop_attacks(a, Bitboard64::EMPTY) & target).is_not_empty() {
+                *segment = bishop_attacks(a, target) & bishop_attacks(b, origin);
+            }
+        }
+    }
+
+    table
+}
+
+/// Initializes the line table from the empty-board slider attacks.
+///
+/// The line through two aligned squares is where the empty-board rays from
+/// both squares overlap, plus the two squares themselves.
+fn init_line() -> [[Bitboard64; 64]; 64] {
+    let mut table = [[Bitboard64::EMPTY; 64]; 64];
+
+    for (a, row) in table.iter_mut().enumerate() {
+        for (b, line) in row.iter_mut().enumerate() {
+            let ends = Bitboard64::from_square(a) | Bitboard64::from_square(b);
+            let rook_a = rook_attacks(a, Bitboard64::EMPTY);
+            let bishop_a = bishop_attacks(a, Bitboard64::EMPTY);
+            if rook_a.get(b) {
+                *line = (rook_a & rook_attacks(b, Bitboard64::EMPTY)) | ends;
+            } else if bishop_a.get(b) {
+                *line = (bishop_a & bishop_attacks(b, Bitboard64::EMPTY)) | ends;
+            }
+        }
+    }
+
+    table
+}
+
+/// Returns the squares strictly between `a` and `b`.
+///
+/// Empty when the squares are not on a common rank, file, or diagonal.
+pub fn between(a: usize, b: usize) -> Bitboard64 {
+    BETWEEN.get_or_init(init_between)[a][b]
+}
+
+/// Returns the whole rank, file, or diagonal through `a` and `b`.
+///
+/// Empty when the squares are not aligned (or are the same square).
+pub fn line_through(a: usize, b: usize) -> Bitboard64 {
+    LINE.get_or_init(init_line)[a][b]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_between_diagonal() {
+        // a1-h8: b2, c3, d4, e5, f6, g7
+        assert_eq!(
+            between(0, 63),
+            Bitboard64::from_squares(&[9, 18, 27, 36, 45, 54])
+        );
+        assert_eq!(between(63, 0), between(0, 63));
+    }
+
+    #[test]
+    fn test_between_orthogonal() {
+        // a1-a4: a2, a3
+        assert_eq!(between(0, 24), Bitboard64::from_squares(&[8, 16]));
+        // e1-h1: f1, g1
+        assert_eq!(between(4, 7), Bitboard64::from_squares(&[5, 6]));
+    }
+
+    #[test]
+    fn test_between_unaligned_or_adjacent() {
+        assert!(between(0, 17).is_empty()); // a1-b3
+        assert!(between(0, 1).is_empty()); // a1-b1
+        assert!(between(0, 9).is_empty()); // a1-b2
+        assert!(between(5, 5).is_empty());
+        assert!(between(7, 8).is_empty()); // h1-a2 wraps, not a rank
+    }
+
+    #[test]
+    fn test_line_through() {
+        let long_diagonal = Bitboard64::from_squares(&[0, 9, 18, 27, 36, 45, 54, 63]);
+        assert_eq!(line_through(0, 18), long_diagonal); // a1, c3
+        assert_eq!(line_through(54, 27), long_diagonal); // g7, d4
+        assert_eq!(line_through(3, 5), Bitboard64::RANK_1); // d1, f1
+        assert_eq!(line_through(12, 60), Bitboard64::FILE_E); // e2, e8
+        assert!(line_through(0, 17).is_empty()); // a1, b3
+        assert!(line_through(9, 9).is_empty());
+    }
+}
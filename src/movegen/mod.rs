@@ -3,14 +3,20 @@
 pub mod attacks;
 pub mod bitboard;
 pub mod legal_moves;
+pub mod lines;
 pub mod magic_constants;
 pub mod masks;
 pub mod rays;
 
 pub use attacks::{
-    bishop_attacks, king_attacks, knight_attacks, pawn_attacks, queen_attacks, rook_attacks,
+    attacked_squares, attackers_to, bishop_attacks, king_attacks, knight_attacks, pawn_attacks,
+    piece_attacks, queen_attacks, rook_attacks,
 };
 pub use bitboard::Bitboard64;
-pub use legal_moves::{generate_legal_moves, is_in_check, perft, MoveGenerator};
+pub use legal_moves::{
+    find_legal_move, format_divide, generate_legal_moves, is_in_check, is_square_attacked, perft,
+    perft_divide, MoveGenerator,
+};
+pub use lines::{between, line_through};
 pub use masks::{BISHOP_MASKS, ROOK_MASKS};
 pub use rays::{bishop_attacks_slow, blocker_permutations, rook_attacks_slow};
@@ -120,19 +120,9 @@ fn ray_attacks_diagonal(sq: usize, delta: i32, blockers: Bitboard64) -> Bitboard
 /// Returns an iterator over (blocker_bitboard, index) pairs, where index
 /// is the subset index (0 to 2^popcount - 1).
 pub fn blocker_permutations(mask: Bitboard64) -> impl Iterator<Item = (Bitboard64, usize)> {
-    let squares: Vec<usize> = mask.iter().collect();
-    let n = squares.len();
-    let total = 1 << n;
-
-    (0..total).map(move |index| {
-        let mut blockers = Bitboard64::EMPTY;
-        for (bit, &sq) in squares.iter().enumerate() {
-            if (index & (1 << bit)) != 0 {
-                blockers.set(sq);
-            }
-        }
-        (blockers, index)
-    })
+    mask.subsets()
+        .enumerate()
+        .map(|(index, blockers)| (blockers, index))
 }
 
 #[cfg(test)]
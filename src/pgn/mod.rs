@@ -0,0 +1,140 @@
+//! PGN movetext parsing.
+//!
+//! Only the movetext section is handled: SAN moves with move numbers,
+//! comments, NAGs, variations, and a result token. Tag pairs are not parsed.
+
+use crate::core::{GameState, Move};
+
+/// Removes comments (`{...}`, `;` to end of line) and variations (`(...)`).
+fn strip_comments(movetext: &str) -> String {
+    let mut out = String::new();
+    let mut chars = movetext.chars();
+    let mut depth = 0;
+    while let Some(c) = chars.next() {
+        match c {
+            '{' => {
+                for c in chars.by_ref() {
+                    if c == '}' {
+                        break;
+                    }
+                }
+                out.push(' ');
+            }
+            ';' => {
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        break;
+                    }
+                }
+                out.push(' ');
+            }
+            '(' => depth += 1,
+            ')' => {
+                depth = (depth - 1).max(0);
+                out.push(' ');
+            }
+            _ if depth > 0 => {}
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+/// Returns true for result tokens that end the movetext.
+fn is_result(token: &str) -> bool {
+    matches!(token, "1-0" | "0-1" | "1/2-1/2" | "*")
+}
+
+/// Strips a move number (`12.`, `12...`) from the front of a token.
+///
+/// Digits only count as a move number when a `.` follows them, so castling
+/// written with zeros (`0-0`) is left intact.
+fn strip_move_number(token: &str) -> &str {
+    let rest = token.trim_start_matches(|c: char| c.is_ascii_digit());
+    if rest.len() < token.len() && rest.starts_with('.') {
+        rest.trim_start_matches('.')
+    } else {
+        token
+    }
+}
+
+/// Parses PGN movetext into the moves it plays from `start`.
+///
+/// Comments, variations, NAGs (`$1`), and move numbers (`12.`, `12...`) are
+/// skipped, and parsing stops at a result token. Fails with the 1-based ply
+/// index on the first move that is not legal in the position reached.
+pub fn parse_pgn_moves(start: &GameState, movetext: &str) -> Result<Vec<Move>, String> {
+    let mut game = start.clone();
+    let mut moves = Vec::new();
+
+    for token in strip_comments(movetext).split_whitespace() {
+        if is_result(token) {
+            break;
+        }
+        // Move numbers may be glued to the move, as in "1.e4"
+        let san = strip_move_number(token);
+        if san.is_empty() || san.starts_with('$') {
+            continue;
+        }
+
+        let mv = Move::from_san(san, &game)
+            .ok_or_else(|| format!("Illegal move {}: {}", moves.len() + 1, san))?;
+        game.make_move(&mv);
+        moves.push(mv);
+    }
+
+    Ok(moves)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn replay(movetext: &str) -> GameState {
+        let mut game = GameState::starting_position();
+        for mv in parse_pgn_moves(&game.clone(), movetext).unwrap() {
+            game.make_move(&mv);
+        }
+        game
+    }
+
+    #[test]
+    fn test_opera_game() {
+        let movetext = "1. e4 e5 2. Nf3 d6 3. d4 Bg4 {This is a weak move already.} \
+            4. dxe5 Bxf3 5. Qxf3 dxe5 6. Bc4 Nf6 7. Qb3 Qe7 8. Nc3 c6 9. Bg5 b5?! \
+            10. Nxb5 cxb5 11. Bxb5+ Nbd7 12. O-O-O Rd8 13. Rxd7! $1 Rxd7 14. Rd1 Qe6 \
+            15. Bxd7+ (15. Bxf6 gxf6) Nxd7 16. Qb8+ Nxb8 17. Rd8# 1-0";
+        let game = replay(movetext);
+        assert_eq!(
+            game.to_fen(),
+            "1n1Rkb1r/p4ppp/4q3/4p1B1/4P3/8/PPP2PPP/2K5 b k - 1 17"
+        );
+    }
+
+    #[test]
+    fn test_compact_numbers_and_line_comments() {
+        let game = replay("1.e4 e5 ; King's pawn\n2.Nf3 Nc6 3...");
+        assert_eq!(game.fullmove_number(), 3);
+    }
+
+    #[test]
+    fn test_castling_with_zeros() {
+        assert_eq!(
+            replay("1. e4 e5 2. Nf3 Nc6 3. Bc4 Bc5 4. 0-0 Nf6").to_fen(),
+            replay("1. e4 e5 2. Nf3 Nc6 3. Bc4 Bc5 4. O-O Nf6").to_fen()
+        );
+        assert_eq!(
+            replay("1. d4 d5 2. Nc3 Nc6 3. Bf4 Bf5 4. Qd2 Qd7 5. 0-0-0 0-0-0").to_fen(),
+            replay("1. d4 d5 2. Nc3 Nc6 3. Bf4 Bf5 4. Qd2 Qd7 5. O-O-O O-O-O").to_fen()
+        );
+    }
+
+    #[test]
+    fn test_illegal_move_reports_index() {
+        let start = GameState::starting_position();
+        assert_eq!(
+            parse_pgn_moves(&start, "1. e4 e5 2. Ke3"),
+            Err("Illegal move 3: Ke3".to_string())
+        );
+    }
+}
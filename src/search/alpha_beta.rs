@@ -0,0 +1,627 @@
+//! Fixed-depth negamax search with alpha-beta pruning.
+
+use super::ordering::{capture_score, order_moves, prune_underpromotions, SearchContext};
+use super::tt::{Bound, TranspositionTable, TtEntry};
+use crate::core::{GameState, Move, PieceType};
+use crate::eval::{evaluate, piece_value};
+use crate::movegen::{find_legal_move, MoveGenerator};
+use std::time::Instant;
+
+/// Score of a checkmate at the root; mates found deeper score lower.
+pub const MATE: i32 = 30_000;
+
+/// Bound larger than any reachable score.
+pub const INFINITY: i32 = 32_000;
+
+/// Scores beyond this magnitude are mates, stored relative to the node.
+pub(super) const MATE_THRESHOLD: i32 = MATE - 1_000;
+
+/// Margin above a capture's material gain under which quiescence skips it.
+const DELTA_MARGIN: i32 = 200;
+
+/// Material deficit beyond which a side is considered hopelessly lost.
+const WINNING_MARGIN: i32 = 400;
+
+/// Maximum number of legal moves for a position to count as near-stalemate.
+const NEAR_STALEMATE_MOVES: usize = 2;
+
+/// Score handed back to the losing side in a near-stalemate position.
+const NEAR_STALEMATE_PENALTY: i32 = 50;
+
+/// Result of a search.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SearchResult {
+    /// Best move found (None if the root has no legal moves)
+    pub best_move: Option<Move>,
+    /// Score in centipawns from the side to move's perspective
+    pub score: i32,
+    /// Depth searched in plies
+    pub depth: u32,
+    /// Number of nodes visited
+    pub nodes: u64,
+    /// Principal variation, starting with the best move
+    pub pv: Vec<Move>,
+}
+
+/// Search state shared across the recursion.
+pub(super) struct Searcher<'a> {
+    pub(super) nodes: u64,
+    /// Node budget after which the search stops
+    max_nodes: Option<u64>,
+    /// Wall-clock time after which the search stops
+    deadline: Option<Instant>,
+    /// Set once a limit is hit; scores returned afterwards are meaningless
+    pub(super) stopped: bool,
+    /// Triangular PV table: `pv[ply]` is the best line found from `ply`
+    pv: Vec<Vec<Move>>,
+    /// Table of earlier results, if the caller supplied one
+    tt: Option<&'a mut TranspositionTable>,
+    /// Killer and history tables, if the caller supplied them
+    context: Option<&'a mut SearchContext>,
+}
+
+impl<'a> Searcher<'a> {
+    pub(super) fn new() -> Self {
+        Self::with_limits(None, None)
+    }
+
+    pub(super) fn with_limits(max_nodes: Option<u64>, deadline: Option<Instant>) -> Self {
+        Self {
+            nodes: 0,
+            max_nodes,
+            deadline,
+            stopped: false,
+            pv: Vec::new(),
+            tt: None,
+            context: None,
+        }
+    }
+
+    /// Attaches killer and history tables to consult and update.
+    pub(super) fn with_context(mut self, context: &'a mut SearchContext) -> Self {
+        self.context = Some(context);
+        self
+    }
+
+    /// Attaches a transposition table to probe and fill.
+    pub(super) fn with_tt(mut self, tt: &'a mut TranspositionTable) -> Self {
+        self.tt = Some(tt);
+        self
+    }
+
+    /// Returns the principal variation from the root of the last search.
+    pub(super) fn root_pv(&self) -> Vec<Move> {
+        self.pv.first().cloned().unwrap_or_default()
+    }
+
+    /// Returns true once the node or time budget is exhausted.
+    fn out_of_budget(&self) -> bool {
+        self.max_nodes.is_some_and(|max| self.nodes >= max)
+            || (self.nodes.is_multiple_of(1024)
+                && self.deadline.is_some_and(|d| Instant::now() >= d))
+    }
+
+    /// Clears the PV line stored for `ply`.
+    fn clear_pv(&mut self, ply: u32) {
+        let ply = ply as usize;
+        if self.pv.len() <= ply + 1 {
+            self.pv.resize(ply + 2, Vec::new());
+        }
+        self.pv[ply].clear();
+    }
+
+    /// Negamax alpha-beta returning the score from the side to move's view.
+    fn negamax(&mut self, game: &GameState, depth: u32, ply: u32, alpha: i32, beta: i32) -> i32 {
+        if depth == 0 {
+            return self.quiescence(game, ply, alpha, beta);
+        }
+        self.clear_pv(ply);
+        if self.stopped || self.out_of_budget() {
+            self.stopped = true;
+            return 0;
+        }
+        self.nodes += 1;
+
+        let gen = MoveGenerator::new(game);
+        let mut moves = gen.generate_moves();
+        if moves.is_empty() {
+            return if gen.in_check() {
+                -MATE + ply as i32
+            } else {
+                0
+            };
+        }
+
+        let key = game.hash();
+        let entry = self.tt.as_ref().and_then(|tt| tt.probe(key));
+        if let Some(entry) = entry.filter(|e| e.depth >= depth) {
+            let score = score_from_tt(entry.score, ply);
+            let cutoff = match entry.bound {
+                Bound::Exact => true,
+                Bound::Lower => score >= beta,
+                Bound::Upper => score <= alpha,
+            };
+            if cutoff {
+                return score;
+            }
+        }
+
+        prune_underpromotions(game, &mut moves);
+        let tt_move = entry.and_then(|e| e.best_move);
+        match self.context.as_deref() {
+            Some(context) => context.order_moves(game, &mut moves, tt_move, ply),
+            None => order_moves(game, &mut moves, tt_move),
+        }
+
+        let (best_move, score) = self.search_moves(game, &moves, depth, ply, alpha, beta);
+        if let Some(tt) = self.tt.as_mut().filter(|_| !self.stopped) {
+            let bound = if score <= alpha {
+                Bound::Upper
+            } else if score >= beta {
+                Bound::Lower
+            } else {
+                Bound::Exact
+            };
+            tt.store(TtEntry {
+                key,
+                depth,
+                score: score_to_tt(score, ply),
+                bound,
+                best_move,
+            });
+        }
+        score
+    }
+
+    /// Searches captures and queen promotions until the position is quiet.
+    ///
+    /// The side to move may stand pat on the static evaluation unless in
+    /// check, in which case every evasion is searched. Captures that cannot
+    /// lift the score to `alpha` even with a margin are skipped.
+    fn quiescence(&mut self, game: &GameState, ply: u32, mut alpha: i32, beta: i32) -> i32 {
+        self.clear_pv(ply);
+        if self.stopped || self.out_of_budget() {
+            self.stopped = true;
+            return 0;
+        }
+        self.nodes += 1;
+
+        let gen = MoveGenerator::new(game);
+        let mut best_score = -INFINITY;
+        let mut candidates;
+        if gen.in_check() {
+            candidates = gen.generate_moves();
+            if candidates.is_empty() {
+                return -MATE + ply as i32;
+            }
+        } else {
+            if gen.iter_moves().next().is_none() {
+                return 0;
+            }
+            let eval = evaluate(game);
+            // Only a hopelessly lost side needs its few remaining moves counted
+            let mut stand_pat = eval;
+            if eval <= -WINNING_MARGIN {
+                let legal_moves = gen.iter_moves().take(NEAR_STALEMATE_MOVES + 1).count();
+                stand_pat += stalemate_trap_adjustment(eval, legal_moves, false);
+            }
+            if stand_pat >= beta {
+                return stand_pat;
+            }
+            alpha = alpha.max(stand_pat);
+            best_score = stand_pat;
+
+            candidates = gen.generate_captures();
+            candidates.retain(|mv| {
+                if mv.is_promotion() {
+                    return mv.promoted_piece() == Some(PieceType::Queen);
+                }
+                game.captured_piece(mv).is_some_and(|victim| {
+                    stand_pat + piece_value(victim.piece_type) + DELTA_MARGIN > alpha
+                })
+            });
+            candidates.extend(
+                gen.generate_quiet_promotions()
+                    .into_iter()
+                    .filter(|mv| mv.promoted_piece() == Some(PieceType::Queen)),
+            );
+        }
+        order_moves(game, &mut candidates, None);
+
+        for mv in &candidates {
+            let mut next = game.clone();
+            next.make_move(mv);
+            let score = -self.quiescence(&next, ply + 1, -beta, -alpha);
+            if self.stopped {
+                break;
+            }
+
+            best_score = best_score.max(score);
+            if score > alpha {
+                alpha = score;
+                let ply = ply as usize;
+                let mut line = vec![*mv];
+                line.extend_from_slice(&self.pv[ply + 1]);
+                self.pv[ply] = line;
+            }
+            if alpha >= beta {
+                break;
+            }
+        }
+
+        best_score
+    }
+
+    /// Searches the given moves, returning the best move and its score.
+    pub(super) fn search_moves(
+        &mut self,
+        game: &GameState,
+        moves: &[Move],
+        depth: u32,
+        ply: u32,
+        mut alpha: i32,
+        beta: i32,
+    ) -> (Option<Move>, i32) {
+        let mut best_move = None;
+        let mut best_score = -INFINITY;
+        self.clear_pv(ply);
+
+        for mv in moves {
+            let mut next = game.clone();
+            next.make_move(mv);
+            let score = -self.negamax(&next, depth - 1, ply + 1, -beta, -alpha);
+            if self.stopped {
+                break;
+            }
+
+            if score > best_score {
+                best_score = score;
+                best_move = Some(*mv);
+            }
+            if score > alpha {
+                alpha = score;
+                let ply = ply as usize;
+                let mut line = vec![*mv];
+                line.extend_from_slice(&self.pv[ply + 1]);
+                self.pv[ply] = line;
+            }
+            if alpha >= beta {
+                let quiet = !mv.is_promotion() && capture_score(game, mv).is_none();
+                if let Some(context) = self.context.as_mut().filter(|_| quiet) {
+                    context.record_cutoff(*mv, ply, depth);
+                }
+                break;
+            }
+        }
+
+        (best_move, best_score)
+    }
+}
+
+/// Converts a mate score from root-relative to node-relative for storage.
+fn score_to_tt(score: i32, ply: u32) -> i32 {
+    if score > MATE_THRESHOLD {
+        score + ply as i32
+    } else if score < -MATE_THRESHOLD {
+        score - ply as i32
+    } else {
+        score
+    }
+}
+
+/// Converts a stored mate score back to root-relative at `ply`.
+fn score_from_tt(score: i32, ply: u32) -> i32 {
+    if score > MATE_THRESHOLD {
+        score - ply as i32
+    } else if score < -MATE_THRESHOLD {
+        score + ply as i32
+    } else {
+        score
+    }
+}
+
+/// Returns a small bonus for a hopelessly lost side that is nearly stalemated.
+///
+/// When the winning side leaves the opponent with only a move or two, a
+/// shallow search can blunder into stalemate on the next move. Scoring such
+/// positions slightly worse for the winner steers it toward lines that keep
+/// the enemy king some room until mate is in reach.
+fn stalemate_trap_adjustment(score: i32, legal_moves: usize, in_check: bool) -> i32 {
+    if score <= -WINNING_MARGIN && legal_moves <= NEAR_STALEMATE_MOVES && !in_check {
+        NEAR_STALEMATE_PENALTY
+    } else {
+        0
+    }
+}
+
+/// Returns the negamax score of the position within the `(alpha, beta)` window.
+///
+/// Scores are from the side to move's view; mates are scored relative to the
+/// root so shorter mates score higher.
+pub fn alpha_beta(game: &GameState, depth: u32, alpha: i32, beta: i32) -> i32 {
+    Searcher::new().negamax(game, depth, 0, alpha, beta)
+}
+
+/// Returns the quiescence score of the position within `(alpha, beta)`.
+///
+/// Only captures and queen promotions are searched (all evasions when in
+/// check), so the score reflects the position once it is tactically quiet.
+pub fn quiescence(game: &GameState, alpha: i32, beta: i32) -> i32 {
+    Searcher::new().quiescence(game, 0, alpha, beta)
+}
+
+/// Returns the best move found by a fixed-depth search.
+pub fn best_move(game: &GameState, depth: u32) -> Option<Move> {
+    search(game, depth).best_move
+}
+
+/// Searches the position to a fixed depth.
+pub fn search(game: &GameState, depth: u32) -> SearchResult {
+    let mut moves = MoveGenerator::new(game).generate_moves();
+    prune_underpromotions(game, &mut moves);
+    search_root(game, depth, &moves)
+}
+
+/// Searches the position to a fixed depth, sharing results through `tt`.
+///
+/// The table cuts off positions already searched deep enough and seeds
+/// move ordering with their stored best move. It may be reused across calls.
+pub fn search_with_tt(game: &GameState, depth: u32, tt: &mut TranspositionTable) -> SearchResult {
+    let mut moves = MoveGenerator::new(game).generate_moves();
+    prune_underpromotions(game, &mut moves);
+    if let Some(tt_move) = tt.probe(game.hash()).and_then(|e| e.best_move) {
+        if let Some(index) = moves.iter().position(|mv| *mv == tt_move) {
+            moves[..=index].rotate_right(1);
+        }
+    }
+    run_root(Searcher::new().with_tt(tt), game, depth, &moves)
+}
+
+/// Searches the position to a fixed depth using killer and history tables.
+///
+/// The context is updated by the search and may be reused, so a later
+/// search of the same or a nearby position starts with warm move ordering.
+pub fn search_with_context(
+    game: &GameState,
+    depth: u32,
+    context: &mut SearchContext,
+) -> SearchResult {
+    let mut moves = MoveGenerator::new(game).generate_moves();
+    prune_underpromotions(game, &mut moves);
+    context.order_moves(game, &mut moves, None, 0);
+    run_root(Searcher::new().with_context(context), game, depth, &moves)
+}
+
+/// Searches the position considering only the given root moves.
+///
+/// This is the equivalent of UCI `go searchmoves`. Candidates are matched
+/// against the legal moves of the position; returns an error naming every
+/// candidate that is not legal, or if no candidates are given.
+pub fn search_with_root_moves(
+    game: &GameState,
+    depth: u32,
+    candidates: &[Move],
+) -> Result<SearchResult, String> {
+    if candidates.is_empty() {
+        return Err("No root moves to search".to_string());
+    }
+
+    let mut moves = Vec::with_capacity(candidates.len());
+    let mut illegal = Vec::new();
+    for mv in candidates {
+        match find_legal_move(game, mv) {
+            Some(legal) => moves.push(legal),
+            None => illegal.push(mv.to_uci()),
+        }
+    }
+    if !illegal.is_empty() {
+        return Err(format!("Illegal root moves: {}", illegal.join(" ")));
+    }
+    Ok(search_root(game, depth, &moves))
+}
+
+/// Scores every legal root move with a full window.
+///
+/// Unlike [`search`], each move gets an exact score rather than a bound, so
+/// callers can compare alternatives. Scores are from the side to move's view.
+pub fn score_root_moves(game: &GameState, depth: u32) -> Vec<(Move, i32)> {
+    let mut moves = MoveGenerator::new(game).generate_moves();
+    prune_underpromotions(game, &mut moves);
+
+    let mut searcher = Searcher::new();
+    let depth = depth.max(1);
+    moves
+        .into_iter()
+        .map(|mv| {
+            let mut next = game.clone();
+            next.make_move(&mv);
+            (
+                mv,
+                -searcher.negamax(&next, depth - 1, 1, -INFINITY, INFINITY),
+            )
+        })
+        .collect()
+}
+
+fn search_root(game: &GameState, depth: u32, moves: &[Move]) -> SearchResult {
+    run_root(Searcher::new(), game, depth, moves)
+}
+
+fn run_root(mut searcher: Searcher, game: &GameState, depth: u32, moves: &[Move]) -> SearchResult {
+    let depth = depth.max(1);
+
+    let (best_move, score) = if moves.is_empty() {
+        (None, searcher.negamax(game, 0, 0, -INFINITY, INFINITY))
+    } else {
+        searcher.search_moves(game, moves, depth, 0, -INFINITY, INFINITY)
+    };
+    if let Some(tt) = searcher.tt.as_mut().filter(|_| best_move.is_some()) {
+        tt.store(TtEntry {
+            key: game.hash(),
+            depth,
+            score: score_to_tt(score, 0),
+            bound: Bound::Exact,
+            best_move,
+        });
+    }
+
+    SearchResult {
+        best_move,
+        score,
+        depth,
+        nodes: searcher.nodes,
+        pv: searcher.root_pv(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Coord;
+
+    // White can win the queen with exd5, or blunder its own with Qd4
+    const HANGING_QUEENS: &str = "4k3/8/8/3q4/4P3/8/8/3QK3 w - - 0 1";
+
+    #[test]
+    fn test_search_wins_queen() {
+        let game = GameState::from_fen(HANGING_QUEENS).unwrap();
+        let result = search(&game, 2);
+        // Either exd5 or Qxd5 wins the queen
+        assert_eq!(result.best_move.unwrap().to, Coord::new(3, 4));
+        assert!(result.score > 500);
+    }
+
+    #[test]
+    fn test_search_with_single_losing_root_move() {
+        let game = GameState::from_fen(HANGING_QUEENS).unwrap();
+        let blunder = Move::from_uci("d1d4").unwrap();
+        let result = search_with_root_moves(&game, 2, &[blunder]).unwrap();
+        assert_eq!(result.best_move, Some(blunder));
+        assert!(result.score < -500);
+    }
+
+    #[test]
+    fn test_search_with_root_moves_rejects_illegal() {
+        let game = GameState::starting_position();
+        let candidates = [
+            Move::from_uci("e2e5").unwrap(),
+            Move::from_uci("d2d4").unwrap(),
+            Move::from_uci("a1a3").unwrap(),
+        ];
+        assert_eq!(
+            search_with_root_moves(&game, 1, &candidates),
+            Err("Illegal root moves: e2e5 a1a3".to_string())
+        );
+        assert!(search_with_root_moves(&game, 1, &[]).is_err());
+    }
+
+    #[test]
+    fn test_avoids_stalemating_queen_move() {
+        // Qb6 stalemates the cornered king
+        let game = GameState::from_fen("k7/2K5/7Q/8/8/8/8/8 w - - 0 1").unwrap();
+        let stalemate = Move::from_uci("h6b6").unwrap();
+
+        for depth in 1..=3 {
+            let result = search(&game, depth);
+            assert_ne!(result.best_move, Some(stalemate));
+            assert!(result.score > 500);
+        }
+    }
+
+    #[test]
+    fn test_quiescence_scores_stalemate_as_draw() {
+        // Kf7 leaves Black's king and pawn without a move
+        let game = GameState::from_fen("7k/4K2p/7P/P7/8/8/8/8 w - - 0 1").unwrap();
+        let stalemate = Move::from_uci("e7f7").unwrap();
+        let (_, score) = score_root_moves(&game, 1)
+            .into_iter()
+            .find(|(mv, _)| *mv == stalemate)
+            .unwrap();
+        assert_eq!(score, 0);
+    }
+
+    #[test]
+    fn test_stalemate_trap_adjustment() {
+        // Lost side with a single move is nearly stalemated
+        assert_eq!(
+            stalemate_trap_adjustment(-900, 1, false),
+            NEAR_STALEMATE_PENALTY
+        );
+        // Not while in check, with room to move, or when the game is balanced
+        assert_eq!(stalemate_trap_adjustment(-900, 1, true), 0);
+        assert_eq!(stalemate_trap_adjustment(-900, 5, false), 0);
+        assert_eq!(stalemate_trap_adjustment(0, 1, false), 0);
+    }
+
+    #[test]
+    fn test_quiescence_sees_hanging_queen() {
+        // Static eval only counts the extra pawn; exd5 wins the queen
+        let game = GameState::from_fen(HANGING_QUEENS).unwrap();
+        assert!(evaluate(&game) < 300);
+        assert!(quiescence(&game, -INFINITY, INFINITY) > 800);
+
+        // A quiet position scores its static evaluation
+        let quiet = GameState::starting_position();
+        assert_eq!(quiescence(&quiet, -INFINITY, INFINITY), evaluate(&quiet));
+    }
+
+    #[test]
+    fn test_quiescence_searches_quiet_promotion() {
+        // a8=Q is not a capture but is still searched
+        let game = GameState::from_fen("4k3/P7/8/8/8/8/8/4K3 w - - 0 1").unwrap();
+        assert!(quiescence(&game, -INFINITY, INFINITY) > evaluate(&game) + 500);
+    }
+
+    #[test]
+    fn test_finds_mate_in_one() {
+        // Back-rank mate with Ra8#
+        let game = GameState::from_fen("6k1/5ppp/8/8/8/8/5PPP/R5K1 w - - 0 1").unwrap();
+        assert_eq!(best_move(&game, 2), Move::from_uci("a1a8"));
+        assert_eq!(alpha_beta(&game, 2, -INFINITY, INFINITY), MATE - 1);
+    }
+
+    #[test]
+    fn test_tt_search_matches_plain_search() {
+        let game = GameState::from_fen(HANGING_QUEENS).unwrap();
+        let plain = search(&game, 4);
+        let mut tt = TranspositionTable::with_capacity_mb(1);
+        let cached = search_with_tt(&game, 4, &mut tt);
+
+        assert_eq!(cached.best_move, plain.best_move);
+        assert_eq!(cached.score, plain.score);
+        assert!(cached.nodes < plain.nodes);
+        assert_eq!(tt.probe(game.hash()).unwrap().best_move, plain.best_move);
+    }
+
+    #[test]
+    fn test_warm_context_searches_fewer_nodes() {
+        let game =
+            GameState::from_fen("r1bqkbnr/pppp1ppp/2n5/4p3/4P3/5N2/PPPP1PPP/RNBQKB1R w KQkq - 2 3")
+                .unwrap();
+        let mut context = SearchContext::new();
+        let cold = search_with_context(&game, 3, &mut context);
+        let warm = search_with_context(&game, 3, &mut context);
+
+        assert_eq!(warm.best_move, cold.best_move);
+        assert_eq!(warm.score, cold.score);
+        assert!(warm.nodes < cold.nodes);
+    }
+
+    #[test]
+    fn test_mate_score_tt_round_trip() {
+        assert_eq!(score_from_tt(score_to_tt(MATE - 5, 3), 3), MATE - 5);
+        assert_eq!(score_to_tt(-MATE + 5, 3), -MATE + 2);
+        assert_eq!(score_to_tt(120, 7), 120);
+    }
+
+    #[test]
+    fn test_checkmated_root() {
+        let game = GameState::from_fen(
+            "r1bqkb1r/pppp1Qpp/2n2n2/4p3/2B1P3/8/PPPP1PPP/RNB1K1NR b KQkq - 0 4",
+        )
+        .unwrap();
+        let result = search(&game, 2);
+        assert_eq!(result.best_move, None);
+        assert_eq!(result.score, -MATE);
+    }
+}
@@ -0,0 +1,60 @@
+//! Natural-language explanation of the engine's chosen move.
+
+use super::alpha_beta::search;
+use crate::core::GameState;
+use crate::eval::{explain, ExplainOptions, Verbosity};
+use crate::threats::{null_move_threats, threat_summary};
+
+/// Searches the position and explains the best move.
+///
+/// `Brief` yields a single sentence, `Normal` adds the evaluation terms that
+/// favor the resulting position, and `Detailed` adds centipawn values and
+/// the tactics involved.
+pub fn explain_best_move(game: &GameState, depth: u32, options: &ExplainOptions) -> String {
+    let result = search(game, depth);
+    let Some(best) = result.best_move else {
+        return "There are no legal moves.".to_string();
+    };
+
+    let mut text = format!("{} is the best move ({:+} cp).", best, result.score);
+    if options.verbosity == Verbosity::Brief {
+        return text;
+    }
+
+    let mut after = game.clone();
+    after.make_move(&best);
+    let breakdown = explain(&after).flipped();
+    text.push(' ');
+    text.push_str(&breakdown.explain(options));
+
+    if options.verbosity == Verbosity::Detailed {
+        let tactics = threat_summary(game)
+            .into_iter()
+            .filter(|tactic| tactic.mv() == best)
+            .chain(null_move_threats(&after));
+        for tactic in tactics {
+            text.push_str(&format!("\n  tactic: {}", tactic.describe()));
+        }
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_explain_best_move_verbosity() {
+        // Nc7+ forks king and rook
+        let game = GameState::from_fen("r3k3/8/8/3N4/8/8/8/4K3 w - - 0 1").unwrap();
+
+        let brief = explain_best_move(&game, 3, &ExplainOptions::new(Verbosity::Brief));
+        assert_eq!(brief.matches('.').count(), 1);
+        assert!(brief.starts_with("d5c7"));
+
+        let detailed = explain_best_move(&game, 3, &ExplainOptions::new(Verbosity::Detailed));
+        assert!(detailed.contains("material:"));
+        assert!(detailed.contains("cp"));
+        assert!(detailed.contains("tactic: d5c7 forks"));
+    }
+}
@@ -0,0 +1,140 @@
+//! Iterative deepening under depth, node, and time limits.
+
+use super::alpha_beta::{search, SearchResult, Searcher, INFINITY};
+use super::ordering::prune_underpromotions;
+use crate::core::GameState;
+use crate::movegen::MoveGenerator;
+use std::time::{Duration, Instant};
+
+/// Depth at which iterative deepening stops when no other limit applies.
+pub const MAX_DEPTH: u32 = 64;
+
+/// Limits on an iterative deepening search; `None` means unbounded.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SearchLimits {
+    /// Deepest iteration to run
+    pub max_depth: Option<u32>,
+    /// Total node budget across all iterations
+    pub max_nodes: Option<u64>,
+    /// Wall-clock time budget
+    pub time: Option<Duration>,
+}
+
+impl SearchLimits {
+    /// Limits the search to a fixed depth.
+    pub fn depth(depth: u32) -> Self {
+        Self {
+            max_depth: Some(depth),
+            ..Self::default()
+        }
+    }
+
+    /// Limits the search to a node budget.
+    pub fn nodes(nodes: u64) -> Self {
+        Self {
+            max_nodes: Some(nodes),
+            ..Self::default()
+        }
+    }
+
+    /// Limits the search to a time budget.
+    pub fn time(time: Duration) -> Self {
+        Self {
+            time: Some(time),
+            ..Self::default()
+        }
+    }
+}
+
+/// Searches one ply deeper at a time until a limit is hit.
+///
+/// Each iteration searches the previous iteration's best move first. An
+/// iteration cut short by the node or time budget is discarded, so the
+/// result always comes from the deepest completed iteration. If not even
+/// depth 1 completes, the first legal move is returned with depth 0.
+pub fn iterative_deepening(game: &GameState, limits: SearchLimits) -> SearchResult {
+    let deadline = limits.time.map(|time| Instant::now() + time);
+    let mut searcher = Searcher::with_limits(limits.max_nodes, deadline);
+
+    let mut moves = MoveGenerator::new(game).generate_moves();
+    prune_underpromotions(game, &mut moves);
+    if moves.is_empty() {
+        return search(game, 1);
+    }
+    let mut result = SearchResult {
+        best_move: Some(moves[0]),
+        score: 0,
+        depth: 0,
+        nodes: 0,
+        pv: vec![moves[0]],
+    };
+
+    let max_depth = limits.max_depth.unwrap_or(MAX_DEPTH).max(1);
+    for depth in 1..=max_depth {
+        if let Some(index) = moves.iter().position(|mv| Some(*mv) == result.best_move) {
+            moves[..=index].rotate_right(1);
+        }
+
+        let (best_move, score) = searcher.search_moves(game, &moves, depth, 0, -INFINITY, INFINITY);
+        if searcher.stopped {
+            break;
+        }
+        result = SearchResult {
+            best_move,
+            score,
+            depth,
+            nodes: searcher.nodes,
+            pv: searcher.root_pv(),
+        };
+    }
+
+    result.nodes = searcher.nodes;
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::movegen::generate_legal_moves;
+
+    #[test]
+    fn test_node_budget_returns_legal_move() {
+        let game = GameState::starting_position();
+        let result = iterative_deepening(&game, SearchLimits::nodes(2_000));
+
+        let best = result.best_move.unwrap();
+        assert!(generate_legal_moves(&game).contains(&best));
+        assert!(result.depth >= 1);
+        assert!(result.nodes <= 2_000);
+    }
+
+    #[test]
+    fn test_depth_limit_matches_fixed_search() {
+        let game = GameState::from_fen("4k3/8/8/3q4/4P3/8/8/3QK3 w - - 0 1").unwrap();
+        let result = iterative_deepening(&game, SearchLimits::depth(2));
+        assert_eq!(result.depth, 2);
+        assert_eq!(result.score, search(&game, 2).score);
+    }
+
+    #[test]
+    fn test_principal_variation_is_playable() {
+        let game = GameState::from_fen("6k1/5ppp/8/8/8/8/5PPP/R5K1 w - - 0 1").unwrap();
+        let result = iterative_deepening(&game, SearchLimits::depth(3));
+        assert_eq!(result.pv.first().copied(), result.best_move);
+
+        let mut position = game.clone();
+        for mv in &result.pv {
+            assert!(generate_legal_moves(&position).contains(mv));
+            position.make_move(mv);
+        }
+    }
+
+    #[test]
+    fn test_time_budget_stops() {
+        let game = GameState::starting_position();
+        let start = Instant::now();
+        let result = iterative_deepening(&game, SearchLimits::time(Duration::from_millis(50)));
+        assert!(result.best_move.is_some());
+        assert!(start.elapsed() < Duration::from_secs(2));
+    }
+}
@@ -0,0 +1,93 @@
+//! Dedicated forced-mate search.
+
+use super::alpha_beta::{MATE, MATE_THRESHOLD};
+use crate::core::{GameState, Move};
+use crate::movegen::MoveGenerator;
+
+/// Returns the number of moves to mate encoded in a search score.
+///
+/// Scores are `MATE - ply` for the side delivering mate and `-MATE + ply`
+/// for the side being mated. Positive results mean the side to move mates,
+/// negative that it gets mated; `None` for ordinary scores.
+pub fn mate_distance(score: i32) -> Option<i32> {
+    if score > MATE_THRESHOLD {
+        Some((MATE - score + 1) / 2)
+    } else if score < -MATE_THRESHOLD {
+        Some(-(MATE + score) / 2)
+    } else {
+        None
+    }
+}
+
+/// Finds the shortest forced mate of at most `max_moves` moves.
+///
+/// Returns the number of moves and the first move of the mate. Every
+/// defence is tried, so the result is a proof rather than a heuristic.
+pub fn mate_in(game: &GameState, max_moves: u32) -> Option<(u32, Move)> {
+    (1..=max_moves).find_map(|n| forced_mate(game, n).map(|mv| (n, mv)))
+}
+
+/// Returns a move that mates within `n` moves against any defence.
+fn forced_mate(game: &GameState, n: u32) -> Option<Move> {
+    MoveGenerator::new(game)
+        .generate_moves()
+        .into_iter()
+        .find(|mv| {
+            let mut after = game.clone();
+            after.make_move(mv);
+            let defender = MoveGenerator::new(&after);
+            let replies = defender.generate_moves();
+            if replies.is_empty() {
+                return defender.in_check();
+            }
+            n > 1
+                && replies.iter().all(|reply| {
+                    let mut next = after.clone();
+                    next.make_move(reply);
+                    forced_mate(&next, n - 1).is_some()
+                })
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::search::search;
+
+    #[test]
+    fn test_mate_in_two() {
+        // 1. Kb6 Kb8 2. Rh8#; Rh8+ at once lets the king out via a7
+        let game = GameState::from_fen("k7/8/2K5/8/8/8/8/7R w - - 0 1").unwrap();
+        assert_eq!(mate_in(&game, 1), None);
+        assert_eq!(mate_distance(search(&game, 3).score), Some(2));
+
+        let (moves, first) = mate_in(&game, 2).unwrap();
+        assert_eq!(moves, 2);
+        let mut after = game.clone();
+        after.make_move(&first);
+        for reply in MoveGenerator::new(&after).generate_moves() {
+            let mut next = after.clone();
+            next.make_move(&reply);
+            assert_eq!(mate_in(&next, 1).map(|(n, _)| n), Some(1));
+        }
+    }
+
+    #[test]
+    fn test_mate_in_one_and_none() {
+        let game = GameState::from_fen("6k1/5ppp/8/8/8/8/5PPP/R5K1 w - - 0 1").unwrap();
+        assert_eq!(
+            mate_in(&game, 3),
+            Some((1, Move::from_uci("a1a8").unwrap()))
+        );
+        assert_eq!(mate_in(&GameState::starting_position(), 1), None);
+    }
+
+    #[test]
+    fn test_mate_distance() {
+        assert_eq!(mate_distance(MATE - 1), Some(1));
+        assert_eq!(mate_distance(MATE - 3), Some(2));
+        assert_eq!(mate_distance(-MATE + 2), Some(-1));
+        assert_eq!(mate_distance(-MATE), Some(0));
+        assert_eq!(mate_distance(350), None);
+    }
+}
@@ -1,3 +1,24 @@
 //! Search algorithms (Alpha-Beta, MCTS, etc.)
 
-// TODO: Implement search
+pub mod alpha_beta;
+pub mod explain;
+pub mod iterative;
+pub mod mate;
+pub mod ordering;
+pub mod perpetual;
+pub mod randomness;
+pub mod see;
+pub mod tt;
+
+pub use alpha_beta::{
+    alpha_beta, best_move, quiescence, score_root_moves, search, search_with_root_moves,
+    search_with_tt, SearchResult, INFINITY, MATE,
+};
+pub use explain::explain_best_move;
+pub use iterative::{iterative_deepening, SearchLimits, MAX_DEPTH};
+pub use mate::{mate_distance, mate_in};
+pub use ordering::{order_moves, prune_underpromotions, SearchContext};
+pub use perpetual::has_perpetual_check;
+pub use randomness::best_move_with_randomness;
+pub use see::see;
+pub use tt::{Bound, TranspositionTable, TtEntry};
@@ -0,0 +1,243 @@
+//! Move ordering and pruning helpers for the search.
+
+use crate::core::{GameState, Move, PieceType, StandardBoard};
+use crate::eval::piece_value;
+use crate::movegen::{generate_legal_moves, is_in_check};
+
+/// Orders moves for alpha-beta: the TT move, then captures, then quiets.
+///
+/// Captures are sorted most-valuable-victim first, breaking ties by the
+/// least valuable attacker. Quiet moves keep their generated order.
+pub fn order_moves(game: &GameState, moves: &mut [Move], tt_move: Option<Move>) {
+    sort_moves(game, moves, tt_move, |_| (0, 0));
+}
+
+/// Sorts by (TT move, captures, quiets) with `quiet_key` ranking quiets.
+fn sort_moves(
+    game: &GameState,
+    moves: &mut [Move],
+    tt_move: Option<Move>,
+    quiet_key: impl Fn(&Move) -> (u8, i64),
+) {
+    moves.sort_by_cached_key(|mv| {
+        if Some(*mv) == tt_move {
+            return (0, 0, 0);
+        }
+        match capture_score(game, mv) {
+            Some(score) => (1, 0, -(score as i64)),
+            None => {
+                let (class, score) = quiet_key(mv);
+                (2, class, -score)
+            }
+        }
+    });
+}
+
+/// Killer and history tables learned from beta cutoffs.
+///
+/// Owned by the caller and threaded through the search, so a context can be
+/// kept warm across searches of related positions.
+#[derive(Clone, Debug)]
+pub struct SearchContext {
+    /// Two most recent quiet cutoff moves per ply, newest first
+    killers: Vec<[Option<Move>; 2]>,
+    /// Cutoff counts weighted by depth squared, indexed `[from][to]`
+    history: Vec<[u32; 64]>,
+}
+
+impl Default for SearchContext {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SearchContext {
+    /// Creates an empty context.
+    pub fn new() -> Self {
+        Self {
+            killers: Vec::new(),
+            history: vec![[0; 64]; 64],
+        }
+    }
+
+    /// Returns the killer moves recorded at `ply`.
+    pub fn killers(&self, ply: u32) -> [Option<Move>; 2] {
+        self.killers.get(ply as usize).copied().unwrap_or([None; 2])
+    }
+
+    /// Returns the history score of moving from `mv.from` to `mv.to`.
+    pub fn history(&self, mv: &Move) -> u32 {
+        match (
+            StandardBoard::to_index(&mv.from),
+            StandardBoard::to_index(&mv.to),
+        ) {
+            (Some(from), Some(to)) => self.history[from][to],
+            _ => 0,
+        }
+    }
+
+    /// Records a quiet move that caused a beta cutoff at `ply`.
+    pub fn record_cutoff(&mut self, mv: Move, ply: u32, depth: u32) {
+        let ply = ply as usize;
+        if self.killers.len() <= ply {
+            self.killers.resize(ply + 1, [None; 2]);
+        }
+        let slots = &mut self.killers[ply];
+        if slots[0] != Some(mv) {
+            slots[1] = slots[0];
+            slots[0] = Some(mv);
+        }
+
+        if let (Some(from), Some(to)) = (
+            StandardBoard::to_index(&mv.from),
+            StandardBoard::to_index(&mv.to),
+        ) {
+            let entry = &mut self.history[from][to];
+            *entry = entry.saturating_add(depth * depth);
+        }
+    }
+
+    /// Orders moves like [`order_moves`], then ranks quiets by killer slot
+    /// at `ply` and by history score.
+    pub fn order_moves(
+        &self,
+        game: &GameState,
+        moves: &mut [Move],
+        tt_move: Option<Move>,
+        ply: u32,
+    ) {
+        let killers = self.killers(ply);
+        sort_moves(game, moves, tt_move, |mv| {
+            match killers.iter().position(|k| *k == Some(*mv)) {
+                Some(slot) => (0, -(slot as i64)),
+                None => (1, self.history(mv) as i64),
+            }
+        });
+    }
+}
+
+/// Returns the MVV-LVA score of a capture, or `None` for a quiet move.
+pub(crate) fn capture_score(game: &GameState, mv: &Move) -> Option<i32> {
+    let victim = game.captured_piece(mv)?.piece_type;
+    let attacker = game.board().piece_at(&mv.from)?.piece_type;
+    Some(10 * piece_value(victim) - piece_value(attacker) + 1)
+}
+
+/// Prunes underpromotions that are unlikely to matter.
+///
+/// Queen and knight promotions are always kept (a knight promotion can give
+/// check or fork where the queen cannot). Rook and bishop promotions are only
+/// kept when promoting to a queen on the same square would stalemate the
+/// opponent and the underpromotion itself does not.
+pub fn prune_underpromotions(game: &GameState, moves: &mut Vec<Move>) {
+    let queen_stalemates = |mv: &Move| {
+        let queen_promo = Move::promotion(mv.from, mv.to, PieceType::Queen);
+        is_stalemate_after(game, &queen_promo)
+    };
+
+    moves.retain(|mv| match mv.promoted_piece() {
+        Some(PieceType::Rook) | Some(PieceType::Bishop) => {
+            queen_stalemates(mv) && !is_stalemate_after(game, mv)
+        }
+        _ => true,
+    });
+}
+
+/// Returns true if playing `mv` leaves the opponent stalemated.
+fn is_stalemate_after(game: &GameState, mv: &Move) -> bool {
+    let mut next = game.clone();
+    next.make_move(mv);
+    generate_legal_moves(&next).is_empty() && !is_in_check(&next)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Coord, MoveFlags};
+
+    fn promotions(moves: &[Move]) -> Vec<PieceType> {
+        moves.iter().filter_map(|m| m.promoted_piece()).collect()
+    }
+
+    #[test]
+    fn test_knight_promotion_mate_kept() {
+        // f8=N is mate; f8=Q is not
+        let game = GameState::from_fen("6bn/5Ppk/6pp/8/8/8/8/K7 w - - 0 1").unwrap();
+        let mut moves = generate_legal_moves(&game);
+        prune_underpromotions(&game, &mut moves);
+
+        let promos = promotions(&moves);
+        assert!(promos.contains(&PieceType::Queen));
+        assert!(promos.contains(&PieceType::Knight));
+        assert!(!promos.contains(&PieceType::Rook));
+        assert!(!promos.contains(&PieceType::Bishop));
+
+        let mate = Move::promotion(Coord::new(5, 6), Coord::new(5, 7), PieceType::Knight);
+        assert!(moves.contains(&mate));
+        let mut next = game.clone();
+        next.make_move(&mate);
+        assert!(generate_legal_moves(&next).is_empty());
+        assert!(is_in_check(&next));
+    }
+
+    #[test]
+    fn test_rook_promotion_kept_to_avoid_stalemate() {
+        // b1=Q and b1=B both stalemate the White king; b1=R does not
+        let game = GameState::from_fen("7K/5k2/8/8/8/8/1p6/8 b - - 0 1").unwrap();
+        let mut moves = generate_legal_moves(&game);
+        prune_underpromotions(&game, &mut moves);
+
+        let promos = promotions(&moves);
+        assert!(promos.contains(&PieceType::Queen));
+        assert!(promos.contains(&PieceType::Knight));
+        assert!(promos.contains(&PieceType::Rook));
+        assert!(!promos.contains(&PieceType::Bishop));
+    }
+
+    #[test]
+    fn test_mvv_lva_ordering() {
+        // exd5 (PxQ), Nxd2 (NxP) and Qxd2 (QxP) are all available
+        let game = GameState::from_fen("4k3/8/8/3q4/4P3/8/3p4/1N1Q3K w - - 0 1").unwrap();
+        let mut moves = generate_legal_moves(&game);
+        order_moves(&game, &mut moves, None);
+
+        let index = |uci: &str| moves.iter().position(|m| m.to_uci() == uci).unwrap();
+        assert_eq!(index("e4d5"), 0);
+        assert!(index("b1d2") < index("d1d2"));
+        assert!(moves[3..].iter().all(|m| capture_score(&game, m).is_none()));
+
+        // The TT move goes first even when quiet
+        let quiet = Move::from_uci("d1a4").unwrap();
+        order_moves(&game, &mut moves, Some(quiet));
+        assert_eq!(moves[0], quiet);
+        assert_eq!(moves[1].to_uci(), "e4d5");
+    }
+
+    #[test]
+    fn test_killers_and_history_order_quiets() {
+        let game = GameState::starting_position();
+        let mut context = SearchContext::new();
+        let killer = Move::from_uci("g1f3").unwrap();
+        let favored = Move::from_uci("b1c3").unwrap();
+        context.record_cutoff(favored, 0, 3);
+        context.record_cutoff(killer, 2, 1);
+
+        let mut moves = generate_legal_moves(&game);
+        context.order_moves(&game, &mut moves, None, 2);
+        assert_eq!(moves[0], killer);
+        assert_eq!(moves[1], favored);
+        assert_eq!(context.killers(2), [Some(killer), None]);
+        assert_eq!(context.history(&favored), 9);
+    }
+
+    #[test]
+    fn test_non_promotions_untouched() {
+        let game = GameState::starting_position();
+        let mut moves = generate_legal_moves(&game);
+        prune_underpromotions(&game, &mut moves);
+        assert_eq!(moves.len(), 20);
+        assert!(moves
+            .iter()
+            .all(|m| !matches!(m.flags, MoveFlags::Promotion { .. })));
+    }
+}
@@ -0,0 +1,76 @@
+//! Detection of forced perpetual check.
+
+use crate::core::GameState;
+use crate::movegen::MoveGenerator;
+
+/// Returns true if the side to move can force a repetition by checking.
+///
+/// Searches up to `max_depth` checking moves ahead: every move of the side to
+/// move must give check, and every defence must either repeat an earlier
+/// position or allow the checks to continue. Checkmate along the way also
+/// counts, since the checks never run out.
+pub fn has_perpetual_check(game: &GameState, max_depth: u32) -> bool {
+    let mut game = game.clone();
+    let mut history = vec![game.hash()];
+    can_force_checks(&mut game, max_depth, &mut history)
+}
+
+/// Returns true if some checking move keeps every defence within the net.
+///
+/// Positions are identified by their Zobrist hash; moves are made and unmade
+/// on `game`, which is left as it was.
+fn can_force_checks(game: &mut GameState, depth: u32, history: &mut Vec<u64>) -> bool {
+    if depth == 0 {
+        return false;
+    }
+
+    for mv in MoveGenerator::new(game).generate_moves() {
+        let undo = game.make_move(&mv);
+        let defender = MoveGenerator::new(game);
+        let holds = defender.in_check() && {
+            let replies = defender.generate_moves();
+            history.push(game.hash());
+            let holds = replies.iter().all(|reply| {
+                let undo = game.make_move(reply);
+                let key = game.hash();
+                let forced = history.contains(&key) || {
+                    history.push(key);
+                    let forced = can_force_checks(game, depth - 1, history);
+                    history.pop();
+                    forced
+                };
+                game.unmake_move(reply, undo);
+                forced
+            });
+            history.pop();
+            holds
+        };
+        game.unmake_move(&mv, undo);
+
+        if holds {
+            return true;
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_queen_perpetual() {
+        // Qh6+ Kg8 Qg6+ Kh8 Qh6+ repeats; the f8 rook blocks the escape
+        let game = GameState::from_fen("5r1k/ppp5/8/8/8/8/3Q4/1K6 w - - 0 1").unwrap();
+        assert!(has_perpetual_check(&game, 4));
+    }
+
+    #[test]
+    fn test_no_perpetual_in_quiet_position() {
+        assert!(!has_perpetual_check(&GameState::starting_position(), 4));
+
+        // A single check that the king escapes is not a perpetual
+        let game = GameState::from_fen("4k3/8/8/8/8/8/8/R3K3 w - - 0 1").unwrap();
+        assert!(!has_perpetual_check(&game, 3));
+    }
+}
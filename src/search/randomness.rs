@@ -0,0 +1,98 @@
+//! Randomized move selection for varied casual play.
+
+use super::alpha_beta::score_root_moves;
+use crate::core::{GameState, Move};
+
+/// Moves scoring within this many centipawns of the best are candidates.
+const RANDOM_WINDOW: i32 = 50;
+
+/// Minimal SplitMix64 generator so selections are reproducible from a seed.
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    /// Returns a uniform float in [0, 1).
+    fn next_f64(&mut self) -> f64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^= z >> 31;
+        (z >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+/// Picks a near-best move at random so the engine does not always play alike.
+///
+/// Moves within a small window of the best score are weighted by
+/// `exp((score - best) / (temperature * 100))`. A temperature of zero always
+/// returns the best move; higher temperatures flatten the weights. The same
+/// `seed` always gives the same choice. Returns `None` if there are no legal
+/// moves.
+pub fn best_move_with_randomness(
+    game: &GameState,
+    depth: u32,
+    temperature: f64,
+    seed: u64,
+) -> Option<Move> {
+    let scored = score_root_moves(game, depth);
+    let best = scored.iter().map(|&(_, score)| score).max()?;
+    if temperature <= 0.0 {
+        return scored
+            .iter()
+            .find(|&&(_, score)| score == best)
+            .map(|&(mv, _)| mv);
+    }
+
+    let candidates: Vec<(Move, f64)> = scored
+        .iter()
+        .filter(|&&(_, score)| score >= best - RANDOM_WINDOW)
+        .map(|&(mv, score)| (mv, ((score - best) as f64 / (temperature * 100.0)).exp()))
+        .collect();
+    let total: f64 = candidates.iter().map(|&(_, weight)| weight).sum();
+
+    let mut pick = SplitMix64(seed).next_f64() * total;
+    for &(mv, weight) in &candidates {
+        if pick < weight {
+            return Some(mv);
+        }
+        pick -= weight;
+    }
+    candidates.last().map(|&(mv, _)| mv)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::search::search;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_zero_temperature_is_best_move() {
+        let game = GameState::from_fen("4k3/8/8/3q4/4P3/8/8/3QK3 w - - 0 1").unwrap();
+        let best = search(&game, 2).best_move;
+        for seed in 0..10 {
+            assert_eq!(best_move_with_randomness(&game, 2, 0.0, seed), best);
+        }
+    }
+
+    #[test]
+    fn test_temperature_gives_variety() {
+        let game = GameState::starting_position();
+        let chosen: HashSet<Move> = (0..32)
+            .filter_map(|seed| best_move_with_randomness(&game, 1, 1.0, seed))
+            .collect();
+        assert!(chosen.len() > 1);
+
+        // Reproducible from the seed
+        assert_eq!(
+            best_move_with_randomness(&game, 1, 1.0, 7),
+            best_move_with_randomness(&game, 1, 1.0, 7)
+        );
+    }
+
+    #[test]
+    fn test_no_legal_moves() {
+        let game = GameState::from_fen("k7/1Q6/1K6/8/8/8/8/8 b - - 0 1").unwrap();
+        assert_eq!(best_move_with_randomness(&game, 1, 1.0, 0), None);
+    }
+}
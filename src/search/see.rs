@@ -0,0 +1,118 @@
+//! Static exchange evaluation of captures.
+
+use crate::core::{GameState, Move, PieceType, StandardBoard};
+use crate::eval::piece_value;
+use crate::movegen::attackers_to;
+
+/// Value of the king in an exchange; it only ever captures last.
+const KING_VALUE: i32 = 20_000;
+
+/// Pieces tried as attackers, cheapest first.
+const ATTACKER_ORDER: [PieceType; 6] = [
+    PieceType::Pawn,
+    PieceType::Knight,
+    PieceType::Bishop,
+    PieceType::Rook,
+    PieceType::Queen,
+    PieceType::King,
+];
+
+fn exchange_value(pt: PieceType) -> i32 {
+    match pt {
+        PieceType::King => KING_VALUE,
+        _ => piece_value(pt),
+    }
+}
+
+/// Returns the net material won by the side playing `mv` once all
+/// recaptures on the destination square are played out.
+///
+/// Each side recaptures with its least valuable attacker and may stop
+/// whenever continuing would lose material. Sliders behind a capturing
+/// piece join in as it leaves (X-rays). Pins are ignored. Quiet moves score
+/// the risk of moving onto an attacked square, i.e. zero or a loss.
+pub fn see(game: &GameState, mv: &Move) -> i32 {
+    let board = game.board();
+    let (Some(from), Some(to)) = (
+        StandardBoard::to_index(&mv.from),
+        StandardBoard::to_index(&mv.to),
+    ) else {
+        return 0;
+    };
+    let Some(mover) = board.piece_at(&mv.from).filter(|_| !mv.is_castling()) else {
+        return 0;
+    };
+
+    let mut occupied = board.occupied();
+    let mut gain = vec![0];
+    if mv.is_en_passant() {
+        gain[0] = piece_value(PieceType::Pawn);
+        occupied.clear(to - 8 + 16 * mover.color as usize);
+    } else if let Some(victim) = board.piece_at(&mv.to) {
+        gain[0] = exchange_value(victim.piece_type);
+    }
+    let mut on_square = exchange_value(mover.piece_type);
+    if let Some(promoted) = mv.promoted_piece() {
+        gain[0] += piece_value(promoted) - piece_value(PieceType::Pawn);
+        on_square = piece_value(promoted);
+    }
+    occupied.clear(from);
+
+    let mut side = mover.color.opposite();
+    loop {
+        let attackers = attackers_to(board, to, occupied) & occupied;
+        let ours = attackers & board.pieces_of_color(side);
+        let Some((sq, pt)) = ATTACKER_ORDER
+            .iter()
+            .find_map(|&pt| (ours & board.pieces_of(side, pt)).lsb().map(|sq| (sq, pt)))
+        else {
+            break;
+        };
+        // The king may not capture into a defended square
+        if pt == PieceType::King
+            && (attackers & board.pieces_of_color(side.opposite())).is_not_empty()
+        {
+            break;
+        }
+
+        gain.push(on_square - gain[gain.len() - 1]);
+        on_square = exchange_value(pt);
+        occupied.clear(sq);
+        side = side.opposite();
+    }
+
+    // Either side may decline to continue the exchange
+    for d in (1..gain.len()).rev() {
+        gain[d - 1] = -(-gain[d - 1]).max(gain[d]);
+    }
+    gain[0]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn see_uci(fen: &str, uci: &str) -> i32 {
+        let game = GameState::from_fen(fen).unwrap();
+        see(&game, &Move::from_uci(uci).unwrap())
+    }
+
+    #[test]
+    fn test_losing_and_winning_captures() {
+        // Rxd5 wins a pawn but loses the rook to cxd5
+        assert_eq!(see_uci("4k3/8/2p5/3p4/8/8/8/3RK3 w - - 0 1", "d1d5"), -400);
+        // exd5 wins an undefended queen
+        assert_eq!(see_uci("4k3/8/8/3q4/4P3/8/8/4K3 w - - 0 1", "e4d5"), 900);
+        // The king recaptures a lone rook but not one backed up by another
+        assert_eq!(see_uci("8/8/3k4/3p4/8/8/8/3RK3 w - - 0 1", "d1d5"), -400);
+        assert_eq!(see_uci("8/8/3k4/3p4/8/8/3R4/3RK3 w - - 0 1", "d2d5"), 100);
+    }
+
+    #[test]
+    fn test_xray_recapture() {
+        // The d1 rook backs up Rxd5 through d2, so Black's Rxd5 fails
+        assert_eq!(see_uci("4k3/3r4/8/3p4/8/8/3R4/3RK3 w - - 0 1", "d2d5"), 100);
+        // Without the backup the exchange loses the rook for a pawn
+        assert_eq!(see_uci("4k3/3r4/8/3p4/8/8/3R4/4K3 w - - 0 1", "d2d5"), -400);
+    }
+}
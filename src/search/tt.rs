@@ -0,0 +1,126 @@
+//! Transposition table keyed on the Zobrist hash.
+
+use crate::core::Move;
+use std::mem::size_of;
+
+/// How a stored score relates to the true score of the position.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Bound {
+    /// The score is exact
+    Exact,
+    /// The search failed high; the true score is at least this
+    Lower,
+    /// The search failed low; the true score is at most this
+    Upper,
+}
+
+/// A stored search result.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TtEntry {
+    /// Full Zobrist hash, used to reject index collisions
+    pub key: u64,
+    /// Depth the position was searched to
+    pub depth: u32,
+    /// Score from the side to move's view
+    pub score: i32,
+    /// Whether the score is exact or a bound
+    pub bound: Bound,
+    /// Best or refuting move found, if any
+    pub best_move: Option<Move>,
+}
+
+/// Two entries sharing an index: one kept by depth, one always replaced.
+type Bucket = [Option<TtEntry>; 2];
+
+/// Fixed-size hash table of search results.
+#[derive(Clone, Debug)]
+pub struct TranspositionTable {
+    buckets: Vec<Bucket>,
+}
+
+impl TranspositionTable {
+    /// Creates a table using roughly `mb` megabytes (at least one bucket).
+    pub fn with_capacity_mb(mb: usize) -> Self {
+        let count = (mb * 1024 * 1024 / size_of::<Bucket>()).max(1);
+        Self {
+            buckets: vec![[None; 2]; count],
+        }
+    }
+
+    /// Returns the number of buckets.
+    pub fn buckets(&self) -> usize {
+        self.buckets.len()
+    }
+
+    fn index(&self, key: u64) -> usize {
+        (key % self.buckets.len() as u64) as usize
+    }
+
+    /// Looks up the entry stored for `key`.
+    pub fn probe(&self, key: u64) -> Option<TtEntry> {
+        self.buckets[self.index(key)]
+            .iter()
+            .flatten()
+            .find(|entry| entry.key == key)
+            .copied()
+    }
+
+    /// Stores a search result.
+    ///
+    /// The first slot keeps the deepest entry; whatever it displaces, or any
+    /// shallower result, goes to the second slot.
+    pub fn store(&mut self, entry: TtEntry) {
+        let index = self.index(entry.key);
+        let bucket = &mut self.buckets[index];
+        match bucket[0] {
+            Some(old) if old.key != entry.key && old.depth > entry.depth => {
+                bucket[1] = Some(entry);
+            }
+            Some(old) if old.key != entry.key => {
+                bucket[1] = Some(old);
+                bucket[0] = Some(entry);
+            }
+            _ => bucket[0] = Some(entry),
+        }
+    }
+
+    /// Removes every entry.
+    pub fn clear(&mut self) {
+        self.buckets.fill([None; 2]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(key: u64, depth: u32) -> TtEntry {
+        TtEntry {
+            key,
+            depth,
+            score: depth as i32,
+            bound: Bound::Exact,
+            best_move: None,
+        }
+    }
+
+    #[test]
+    fn test_store_and_replace() {
+        let mut tt = TranspositionTable::with_capacity_mb(0);
+        assert_eq!(tt.buckets(), 1);
+
+        tt.store(entry(1, 5));
+        tt.store(entry(2, 3));
+        assert_eq!(tt.probe(1).unwrap().depth, 5);
+        assert_eq!(tt.probe(2).unwrap().depth, 3);
+
+        // A deeper entry takes the first slot, pushing out the shallow one
+        tt.store(entry(3, 7));
+        assert_eq!(tt.probe(3).unwrap().depth, 7);
+        assert_eq!(tt.probe(1).unwrap().depth, 5);
+        assert_eq!(tt.probe(2), None);
+
+        tt.clear();
+        assert_eq!(tt.probe(3), None);
+    }
+}
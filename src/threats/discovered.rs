@@ -0,0 +1,186 @@
+//! Discovered attacks: moving a piece out of a friendly slider's line.
+
+use crate::core::{Color, Coord, GameState, Move, PieceType, StandardBoard};
+use crate::movegen::{piece_attacks, MoveGenerator};
+
+/// A move that unmasks a friendly slider's attack on an enemy piece.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DiscoveredAttack {
+    /// Move of the piece that steps out of the line
+    pub mv: Move,
+    /// Square of the slider whose attack is revealed
+    pub slider: Coord,
+    /// Square of the enemy piece attacked after the move
+    pub target: Coord,
+    /// True if the revealed attack is on the king (discovered check)
+    pub discovered_check: bool,
+    /// True if the moving piece gives check itself as well
+    pub mover_checks: bool,
+    /// Type of piece the moving piece captures, if any
+    pub captures: Option<PieceType>,
+}
+
+/// Returns the enemy pieces `slider` attacks through exactly one piece of its
+/// own side, as (blocker, target) pairs.
+fn masked_targets(game: &GameState, slider: Coord, color: Color) -> Vec<(Coord, Coord)> {
+    let board = game.board();
+    let Some(piece) = board.piece_at(&slider) else {
+        return Vec::new();
+    };
+    let sq = StandardBoard::to_index(&slider).unwrap();
+    let blockers = piece_attacks(piece, sq, board.occupied()) & board.pieces_of_color(color);
+
+    blockers
+        .iter()
+        .filter_map(|blocker| {
+            let mut without = board.occupied();
+            without.clear(blocker);
+            let revealed = piece_attacks(piece, sq, without)
+                & !piece_attacks(piece, sq, board.occupied())
+                & board.pieces_of_color(color.opposite());
+            let target = revealed.lsb()?;
+            Some((
+                StandardBoard::from_index(blocker)?,
+                StandardBoard::from_index(target)?,
+            ))
+        })
+        .collect()
+}
+
+/// Lists the discovered attacks available to `color`.
+///
+/// For every rook, bishop, or queen of `color` masked by one friendly piece
+/// from an enemy piece, each legal move of the masking piece that opens the
+/// line is reported. If `color` is not to move the turn is passed first,
+/// unless the side to move is in check.
+pub fn find_discovered_attacks(game: &GameState, color: Color) -> Vec<DiscoveredAttack> {
+    let mut position = game.clone();
+    if position.side_to_move() != color {
+        if MoveGenerator::new(game).in_check() {
+            return Vec::new();
+        }
+        position.make_null_move();
+    }
+
+    let board = position.board();
+    let lines: Vec<(Coord, Coord, Coord)> = board
+        .pieces()
+        .filter(|(_, p)| {
+            p.color == color
+                && matches!(
+                    p.piece_type,
+                    PieceType::Rook | PieceType::Bishop | PieceType::Queen
+                )
+        })
+        .flat_map(|(slider, _)| {
+            masked_targets(&position, slider, color)
+                .into_iter()
+                .map(move |(blocker, target)| (slider, blocker, target))
+        })
+        .collect();
+    if lines.is_empty() {
+        return Vec::new();
+    }
+
+    let mut found = Vec::new();
+    for mv in MoveGenerator::new(&position).generate_moves() {
+        for &(slider, _, target) in lines.iter().filter(|(_, b, _)| *b == mv.from) {
+            let mut after = position.clone();
+            after.make_move(&mv);
+            let after_board = after.board();
+            let (Some(slider_piece), Some(slider_sq), Some(target_sq)) = (
+                after_board.piece_at(&slider),
+                StandardBoard::to_index(&slider),
+                StandardBoard::to_index(&target),
+            ) else {
+                continue;
+            };
+            let still_enemy = after_board
+                .piece_at(&target)
+                .is_some_and(|p| p.color != color);
+            if !still_enemy
+                || !piece_attacks(slider_piece, slider_sq, after_board.occupied()).get(target_sq)
+            {
+                continue;
+            }
+
+            let enemy_king = after_board.find_king(color.opposite());
+            let mover_checks = match (after_board.piece_at(&mv.to), enemy_king) {
+                (Some(piece), Some(king)) => {
+                    let to = StandardBoard::to_index(&mv.to).unwrap();
+                    let king = StandardBoard::to_index(&king).unwrap();
+                    piece_attacks(piece, to, after_board.occupied()).get(king)
+                }
+                _ => false,
+            };
+            let captures = game.captured_piece(&mv).map(|p| p.piece_type);
+
+            found.push(DiscoveredAttack {
+                mv,
+                slider,
+                target,
+                discovered_check: enemy_king == Some(target),
+                mover_checks,
+                captures,
+            });
+        }
+    }
+    found
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_knight_discovers_rook_on_queen() {
+        // Any knight move uncovers Rd1's attack on the d8 queen
+        let game = GameState::from_fen("3qk3/8/8/8/3N4/8/8/3RK3 w - - 0 1").unwrap();
+        let attacks = find_discovered_attacks(&game, Color::White);
+
+        assert_eq!(attacks.len(), 8);
+        assert!(attacks.iter().all(|a| a.slider == Coord::new(3, 0)
+            && a.target == Coord::new(3, 7)
+            && !a.discovered_check
+            && a.captures.is_none()));
+
+        let nf5 = attacks
+            .iter()
+            .find(|a| a.mv.to == Coord::new(5, 4))
+            .unwrap();
+        assert!(!nf5.mover_checks);
+    }
+
+    #[test]
+    fn test_double_check() {
+        // Nf6+ and Nd6+ are double checks; other knight moves are
+        // discovered checks only
+        let game = GameState::from_fen("4k3/8/8/8/4N3/8/8/4RK2 w - - 0 1").unwrap();
+        let attacks = find_discovered_attacks(&game, Color::White);
+
+        assert!(attacks.iter().all(|a| a.discovered_check));
+        let double: Vec<String> = attacks
+            .iter()
+            .filter(|a| a.mover_checks)
+            .map(|a| a.mv.to_uci())
+            .collect();
+        assert_eq!(double.len(), 2);
+        assert!(double.contains(&"e4f6".to_string()));
+        assert!(double.contains(&"e4d6".to_string()));
+    }
+
+    #[test]
+    fn test_capture_discovers_bishop() {
+        // cxb5 wins the rook and opens the b3 bishop onto the d5 knight;
+        // c5 opens it without capturing
+        let game = GameState::from_fen("4k3/8/8/1r1n4/2P5/1B6/8/4K3 w - - 0 1").unwrap();
+        let attacks = find_discovered_attacks(&game, Color::White);
+
+        let by_uci = |uci: &str| attacks.iter().find(|a| a.mv.to_uci() == uci).unwrap();
+        assert_eq!(by_uci("c4b5").captures, Some(PieceType::Rook));
+        assert_eq!(by_uci("c4c5").captures, None);
+        assert!(attacks.iter().all(|a| a.target == Coord::new(3, 4)));
+        // Taking the target itself only re-blocks the line
+        assert!(attacks.iter().all(|a| a.mv.to_uci() != "c4d5"));
+    }
+}
@@ -0,0 +1,137 @@
+//! Knight and queen fork detection.
+
+use super::tactics::fork_targets;
+use crate::core::{Color, Coord, GameState, Move, PieceType};
+use crate::movegen::MoveGenerator;
+
+/// One piece attacking two or more valuable enemy pieces at once.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Fork {
+    /// Square the forking piece stands on (after `mv`, if any)
+    pub square: Coord,
+    /// Type of the forking piece
+    pub piece: PieceType,
+    /// Squares of the forked pieces, in square index order
+    pub targets: Vec<Coord>,
+    /// Move that sets up the fork, or `None` if it is already on the board
+    pub mv: Option<Move>,
+}
+
+impl Fork {
+    /// Returns a sentence such as "the knight on c7 forks the king on e8 and
+    /// the rook on a8".
+    pub fn describe(&self, game: &GameState) -> String {
+        let board = game.board();
+        let name = |pt: PieceType| format!("{:?}", pt).to_lowercase();
+        let targets: Vec<String> = self
+            .targets
+            .iter()
+            .map(|sq| match board.piece_at(sq) {
+                Some(piece) => format!("the {} on {}", name(piece.piece_type), sq),
+                None => sq.to_string(),
+            })
+            .collect();
+        format!(
+            "the {} on {} forks {}",
+            name(self.piece),
+            self.square,
+            targets.join(" and ")
+        )
+    }
+}
+
+/// Returns true for the piece types whose forks are reported.
+fn forks_with(pt: PieceType) -> bool {
+    matches!(pt, PieceType::Knight | PieceType::Queen)
+}
+
+/// Lists the knight and queen forks available to `color`.
+///
+/// Forks already on the board come first, then those set up by one of
+/// `color`'s legal moves. If `color` is not to move the turn is passed to
+/// find its moves, unless the side to move is in check. A target counts if
+/// it is the king, worth more than the forking piece, or undefended.
+pub fn find_forks(game: &GameState, color: Color) -> Vec<Fork> {
+    let mut forks: Vec<Fork> = game
+        .board()
+        .pieces()
+        .filter(|(_, piece)| piece.color == color && forks_with(piece.piece_type))
+        .filter_map(|(square, piece)| {
+            let targets = fork_targets(game, &square, color);
+            (targets.len() >= 2).then_some(Fork {
+                square,
+                piece: piece.piece_type,
+                targets,
+                mv: None,
+            })
+        })
+        .collect();
+
+    let mut position = game.clone();
+    if position.side_to_move() != color {
+        if MoveGenerator::new(game).in_check() {
+            return forks;
+        }
+        position.make_null_move();
+    }
+
+    let board = position.board();
+    for mv in MoveGenerator::new(&position).generate_moves() {
+        let Some(piece) = board
+            .piece_at(&mv.from)
+            .filter(|p| forks_with(p.piece_type))
+        else {
+            continue;
+        };
+        let mut after = position.clone();
+        after.make_move(&mv);
+        let targets = fork_targets(&after, &mv.to, color);
+        if targets.len() >= 2 {
+            forks.push(Fork {
+                square: mv.to,
+                piece: piece.piece_type,
+                targets,
+                mv: Some(mv),
+            });
+        }
+    }
+    forks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_royal_fork() {
+        // Nc7+ forks the king on e8 and the rook on a8
+        let game = GameState::from_fen("r3k3/8/8/1N6/8/8/8/4K3 w - - 0 1").unwrap();
+        let forks = find_forks(&game, Color::White);
+
+        assert_eq!(
+            forks,
+            vec![Fork {
+                square: Coord::new(2, 6),
+                piece: PieceType::Knight,
+                targets: vec![Coord::new(0, 7), Coord::new(4, 7)],
+                mv: Some(Move::from_uci("b5c7").unwrap()),
+            }]
+        );
+        assert_eq!(
+            forks[0].describe(&game),
+            "the knight on c7 forks the rook on a8 and the king on e8"
+        );
+    }
+
+    #[test]
+    fn test_existing_queen_fork() {
+        // The queen on d4 already hits the undefended a7 and g1 rooks
+        let game = GameState::from_fen("4k3/r7/8/8/3Q4/7K/8/6r1 b - - 0 1").unwrap();
+        let forks = find_forks(&game, Color::White);
+
+        let existing = forks.iter().find(|f| f.mv.is_none()).unwrap();
+        assert_eq!(existing.square, Coord::new(3, 3));
+        assert_eq!(existing.piece, PieceType::Queen);
+        assert_eq!(existing.targets, vec![Coord::new(6, 0), Coord::new(0, 6)]);
+    }
+}
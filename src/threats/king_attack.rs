@@ -0,0 +1,72 @@
+//! King-attack pressure: how strongly a side bears down on the enemy king.
+
+use crate::core::{Color, GameState, PieceType, StandardBoard};
+use crate::movegen::{king_attacks, piece_attacks, Bitboard64};
+
+/// Weight of each attacking piece type per king-zone square it attacks.
+fn attack_weight(piece_type: PieceType) -> i32 {
+    match piece_type {
+        PieceType::Knight | PieceType::Bishop => 2,
+        PieceType::Rook => 3,
+        PieceType::Queen => 5,
+        PieceType::Pawn | PieceType::King => 0,
+    }
+}
+
+/// Returns the enemy king's square plus its neighbors.
+fn king_zone(game: &GameState, color: Color) -> Bitboard64 {
+    game.board()
+        .find_king(color)
+        .and_then(|king| StandardBoard::to_index(&king))
+        .map_or(Bitboard64::EMPTY, |sq| {
+            king_attacks(sq) | Bitboard64::from_square(sq)
+        })
+}
+
+/// Sums the attack pressure of `attacker`'s pieces on the enemy king zone.
+///
+/// Each knight, bishop, rook, and queen contributes its weight (queen most)
+/// for every zone square it attacks, so a queen and rook lined up on the
+/// king produce a high weight.
+pub fn king_attack_weight(game: &GameState, attacker: Color) -> i32 {
+    let zone = king_zone(game, attacker.opposite());
+    if zone.is_empty() {
+        return 0;
+    }
+
+    let board = game.board();
+    let occupied = board.occupied();
+    board
+        .pieces()
+        .filter(|(_, piece)| piece.color == attacker)
+        .map(|(coord, piece)| {
+            let sq = StandardBoard::to_index(&coord).unwrap();
+            let hits = (piece_attacks(piece, sq, occupied) & zone).popcount() as i32;
+            hits * attack_weight(piece.piece_type)
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_queen_and_rook_attack() {
+        // Queen on h5 and rook on the open g-file aim at the castled king
+        let attack = GameState::from_fen("5rk1/5p1p/8/7Q/8/8/5P1P/6RK w - - 0 1").unwrap();
+        let quiet = GameState::from_fen("5rk1/5ppp/8/8/8/8/5PPP/R2Q3K w - - 0 1").unwrap();
+
+        let weight = king_attack_weight(&attack, Color::White);
+        assert_eq!(weight, 16); // Qh5: h7, f7 (2 x 5); Rg1: g7, g8 (2 x 3)
+        assert_eq!(king_attack_weight(&quiet, Color::White), 0);
+        assert!(weight > king_attack_weight(&attack, Color::Black));
+    }
+
+    #[test]
+    fn test_start_position_has_no_attack() {
+        let game = GameState::starting_position();
+        assert_eq!(king_attack_weight(&game, Color::White), 0);
+        assert_eq!(king_attack_weight(&game, Color::Black), 0);
+    }
+}
@@ -1,3 +1,15 @@
 //! Threat analysis module.
 
-// TODO: Implement threat detection
+pub mod discovered;
+pub mod forks;
+pub mod king_attack;
+pub mod patterns;
+pub mod pins;
+pub mod tactics;
+
+pub use discovered::{find_discovered_attacks, DiscoveredAttack};
+pub use forks::{find_forks, Fork};
+pub use king_attack::king_attack_weight;
+pub use patterns::{mate_pattern, MatePattern};
+pub use pins::{absolute_pins, find_pins, find_skewers, pinned_against, Pin, Skewer};
+pub use tactics::{null_move_threats, threat_summary, Tactic};
@@ -0,0 +1,135 @@
+//! Recognition of named checkmate patterns.
+
+use crate::core::{Color, Coord, GameState, Piece, PieceType, StandardBoard};
+use crate::movegen::{knight_attacks, piece_attacks, MoveGenerator};
+
+/// A named checkmate pattern.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MatePattern {
+    /// A rook or queen mates along the back rank, the king hemmed in by its own pieces.
+    BackRank,
+    /// A knight mates a king surrounded entirely by its own pieces.
+    Smothered,
+    /// A rook or queen mates on an edge file while a knight covers the escape squares.
+    Anastasia,
+    /// A checkmate that matches no named pattern.
+    Other,
+}
+
+impl MatePattern {
+    /// Returns the conventional name of the pattern.
+    pub fn name(&self) -> &'static str {
+        match self {
+            MatePattern::BackRank => "back-rank mate",
+            MatePattern::Smothered => "smothered mate",
+            MatePattern::Anastasia => "Anastasia's mate",
+            MatePattern::Other => "checkmate",
+        }
+    }
+}
+
+/// Classifies the checkmate in `game`, or returns `None` if it is not mate.
+///
+/// The pattern is matched against the mated king's square, the checking
+/// pieces, and which of the king's neighbors are blocked by its own pieces.
+pub fn mate_pattern(game: &GameState) -> Option<MatePattern> {
+    let gen = MoveGenerator::new(game);
+    if !gen.in_check() || !gen.generate_moves().is_empty() {
+        return None;
+    }
+
+    let board = game.board();
+    let mated = game.side_to_move();
+    let king = board.find_king(mated)?;
+    let king_sq = StandardBoard::to_index(&king)?;
+    let occupied = board.occupied();
+
+    let checkers: Vec<(Coord, Piece)> = board
+        .pieces()
+        .filter(|(coord, piece)| {
+            piece.color != mated
+                && piece_attacks(*piece, StandardBoard::to_index(coord).unwrap(), occupied)
+                    .get(king_sq)
+        })
+        .collect();
+    let [(checker_sq, checker)] = checkers[..] else {
+        return Some(MatePattern::Other);
+    };
+
+    let own_blocked = |sq: Coord| board.piece_at(&sq).is_some_and(|p| p.color == mated);
+    let is_line_piece = matches!(checker.piece_type, PieceType::Rook | PieceType::Queen);
+    let back_rank = match mated {
+        Color::White => 0,
+        Color::Black => StandardBoard::HEIGHT - 1,
+    };
+    let edge_file = king.file == 0 || king.file == StandardBoard::WIDTH - 1;
+
+    if checker.piece_type == PieceType::Knight && StandardBoard::neighbors(king, 1).all(own_blocked)
+    {
+        return Some(MatePattern::Smothered);
+    }
+
+    if is_line_piece && king.rank == back_rank && checker_sq.rank == back_rank {
+        let forward_blocked = StandardBoard::neighbors(king, 1)
+            .filter(|sq| sq.rank != back_rank)
+            .all(own_blocked);
+        if forward_blocked {
+            return Some(MatePattern::BackRank);
+        }
+    }
+
+    if is_line_piece && edge_file && checker_sq.file == king.file {
+        let enemy_knight = Piece::new(PieceType::Knight, mated.opposite());
+        let knight_covers_escape = board.pieces().any(|(coord, piece)| {
+            piece == enemy_knight
+                && StandardBoard::neighbors(king, 1).any(|sq| {
+                    knight_attacks(StandardBoard::to_index(&coord).unwrap())
+                        .get(StandardBoard::to_index(&sq).unwrap())
+                })
+        });
+        if knight_covers_escape {
+            return Some(MatePattern::Anastasia);
+        }
+    }
+
+    Some(MatePattern::Other)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pattern(fen: &str) -> Option<MatePattern> {
+        mate_pattern(&GameState::from_fen(fen).unwrap())
+    }
+
+    #[test]
+    fn test_back_rank_mate() {
+        assert_eq!(
+            pattern("R5k1/5ppp/8/8/8/8/8/6K1 b - - 0 1"),
+            Some(MatePattern::BackRank)
+        );
+    }
+
+    #[test]
+    fn test_smothered_mate() {
+        assert_eq!(
+            pattern("6rk/5Npp/8/8/8/8/8/6K1 b - - 0 1"),
+            Some(MatePattern::Smothered)
+        );
+    }
+
+    #[test]
+    fn test_anastasia_mate() {
+        assert_eq!(
+            pattern("8/4N1pk/8/7R/8/8/8/6K1 b - - 0 1"),
+            Some(MatePattern::Anastasia)
+        );
+    }
+
+    #[test]
+    fn test_not_mate() {
+        assert_eq!(pattern("6k1/5ppp/8/8/8/8/8/R5K1 b - - 0 1"), None);
+        assert_eq!(pattern("k7/8/1K6/8/8/8/8/7R b - - 0 1"), None);
+    }
+}
@@ -0,0 +1,305 @@
+//! Pin detection against arbitrary anchor pieces.
+
+use crate::core::{Color, Coord, Delta, GameState, PieceType, StandardBoard};
+use crate::eval::piece_value;
+
+/// A piece pinned to a more valuable piece behind it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Pin {
+    /// Square of the pinned piece
+    pub pinned: Coord,
+    /// Square of the enemy slider creating the pin
+    pub pinner: Coord,
+    /// Square of the piece the pinned piece is shielding
+    pub anchor: Coord,
+}
+
+impl Pin {
+    /// Returns a sentence such as "the bishop pins the knight to the queen".
+    pub fn describe(&self, game: &GameState) -> String {
+        format!(
+            "the {} pins the {} to the {}",
+            piece_name(game, &self.pinner),
+            piece_name(game, &self.pinned),
+            piece_name(game, &self.anchor)
+        )
+    }
+}
+
+/// A valuable piece attacked by a slider with a lesser piece behind it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Skewer {
+    /// Square of the skewering slider
+    pub attacker: Coord,
+    /// Square of the attacked piece that must move
+    pub front: Coord,
+    /// Square of the piece exposed once the front piece moves
+    pub behind: Coord,
+}
+
+impl Skewer {
+    /// Returns a sentence such as "the rook skewers the king to the rook".
+    pub fn describe(&self, game: &GameState) -> String {
+        format!(
+            "the {} skewers the {} to the {}",
+            piece_name(game, &self.attacker),
+            piece_name(game, &self.front),
+            piece_name(game, &self.behind)
+        )
+    }
+}
+
+/// Returns the lowercase type name of the piece on `sq`.
+fn piece_name(game: &GameState, sq: &Coord) -> String {
+    game.board().piece_at(sq).map_or_else(
+        || sq.to_string(),
+        |p| format!("{:?}", p.piece_type).to_lowercase(),
+    )
+}
+
+/// Value used to compare pieces on a line; the king outranks everything.
+fn line_value(pt: PieceType) -> i32 {
+    match pt {
+        PieceType::King => i32::MAX,
+        _ => piece_value(pt),
+    }
+}
+
+/// Returns the ray directions of a slider of type `pt`.
+fn directions(pt: PieceType) -> &'static [Delta] {
+    const ORTHOGONAL: [Delta; 4] = [
+        Delta::new(1, 0),
+        Delta::new(-1, 0),
+        Delta::new(0, 1),
+        Delta::new(0, -1),
+    ];
+    const ALL: [Delta; 8] = [
+        Delta::new(1, 0),
+        Delta::new(-1, 0),
+        Delta::new(0, 1),
+        Delta::new(0, -1),
+        Delta::new(1, 1),
+        Delta::new(1, -1),
+        Delta::new(-1, 1),
+        Delta::new(-1, -1),
+    ];
+    match pt {
+        PieceType::Rook => &ORTHOGONAL,
+        PieceType::Bishop => &ALL[4..],
+        PieceType::Queen => &ALL,
+        _ => &[],
+    }
+}
+
+/// Lists every (slider, first enemy, second enemy) line of `color`'s sliders.
+///
+/// Only lines where the first two pieces met along a ray both belong to the
+/// opponent are returned.
+fn enemy_lines(game: &GameState, color: Color) -> Vec<(Coord, Coord, Coord)> {
+    let board = game.board();
+    let mut lines = Vec::new();
+
+    for (from, piece) in board.pieces().filter(|(_, p)| p.color == color) {
+        for &dir in directions(piece.piece_type) {
+            let mut hits = Vec::with_capacity(2);
+            let mut current = from;
+            while let Some(next) = StandardBoard::offset(&current, dir) {
+                current = next;
+                if let Some(hit) = board.piece_at(&next) {
+                    hits.push((next, hit.color));
+                    if hits.len() == 2 {
+                        break;
+                    }
+                }
+            }
+            if let [(front, c1), (behind, c2)] = hits[..] {
+                if c1 != color && c2 != color {
+                    lines.push((from, front, behind));
+                }
+            }
+        }
+    }
+    lines
+}
+
+/// Lists the pins `color`'s sliders exert on enemy pieces.
+///
+/// Unlike [`pinned_against`], which takes the anchor as given, this finds
+/// every enemy piece shielding a more valuable one (or the king) from one of
+/// `color`'s rooks, bishops, or queens.
+pub fn find_pins(game: &GameState, color: Color) -> Vec<Pin> {
+    let board = game.board();
+    enemy_lines(game, color)
+        .into_iter()
+        .filter_map(|(pinner, pinned, anchor)| {
+            let front = board.piece_at(&pinned)?.piece_type;
+            let back = board.piece_at(&anchor)?.piece_type;
+            (line_value(back) > line_value(front)).then_some(Pin {
+                pinned,
+                pinner,
+                anchor,
+            })
+        })
+        .collect()
+}
+
+/// Lists the skewers `color`'s sliders exert on enemy pieces.
+///
+/// A skewer is the reverse of a pin: the front piece is the more valuable
+/// (or the king), so once it steps aside the piece behind it can be taken.
+pub fn find_skewers(game: &GameState, color: Color) -> Vec<Skewer> {
+    let board = game.board();
+    enemy_lines(game, color)
+        .into_iter()
+        .filter_map(|(attacker, front, behind)| {
+            let front_type = board.piece_at(&front)?.piece_type;
+            let behind_type = board.piece_at(&behind)?.piece_type;
+            (line_value(front_type) > line_value(behind_type)).then_some(Skewer {
+                attacker,
+                front,
+                behind,
+            })
+        })
+        .collect()
+}
+
+/// Returns true if a slider of type `pt` moves along the line from `a` to `b`.
+fn slides_along(pt: PieceType, a: &Coord, b: &Coord) -> bool {
+    let delta = a.delta_to(*b);
+    let orthogonal = delta.dx == 0 || delta.dy == 0;
+    let diagonal = delta.dx.abs() == delta.dy.abs();
+    match pt {
+        PieceType::Rook => orthogonal,
+        PieceType::Bishop => diagonal,
+        PieceType::Queen => orthogonal || diagonal,
+        _ => false,
+    }
+}
+
+/// Returns the pieces of `color` pinned against the piece on `anchor`.
+///
+/// A pin exists when an enemy slider attacks along a line to `anchor` and
+/// exactly one piece, belonging to `color`, stands in between. The anchor
+/// can be any piece, so relative pins (e.g. against the queen) are found.
+pub fn pinned_against(game: &GameState, anchor: Coord, color: Color) -> Vec<Pin> {
+    let board = game.board();
+    if !board.piece_at(&anchor).is_some_and(|p| p.color == color) {
+        return Vec::new();
+    }
+
+    board
+        .pieces()
+        .filter(|(coord, piece)| {
+            piece.color != color && slides_along(piece.piece_type, coord, &anchor)
+        })
+        .filter_map(|(pinner, _)| {
+            let blockers: Vec<Coord> = StandardBoard::between(&pinner, &anchor)
+                .into_iter()
+                .filter(|sq| board.piece_at(sq).is_some())
+                .collect();
+            match blockers[..] {
+                [pinned] if board.piece_at(&pinned)?.color == color => Some(Pin {
+                    pinned,
+                    pinner,
+                    anchor,
+                }),
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+/// Returns the pieces of `color` pinned against their own king.
+pub fn absolute_pins(game: &GameState, color: Color) -> Vec<Pin> {
+    game.board()
+        .find_king(color)
+        .map_or_else(Vec::new, |king| pinned_against(game, king, color))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_knight_pinned_against_queen() {
+        // Bb5 pins the d3 knight to the e2 queen
+        let game = GameState::from_fen("4k3/8/8/1b6/8/3N4/4Q3/4K3 w - - 0 1").unwrap();
+        let pins = pinned_against(&game, Coord::new(4, 1), Color::White);
+        assert_eq!(
+            pins,
+            vec![Pin {
+                pinned: Coord::new(3, 2),
+                pinner: Coord::new(1, 4),
+                anchor: Coord::new(4, 1),
+            }]
+        );
+
+        // Not an absolute pin: the king is not behind the knight
+        assert!(absolute_pins(&game, Color::White).is_empty());
+    }
+
+    #[test]
+    fn test_absolute_pin() {
+        let game = GameState::from_fen("4k3/8/8/8/4r3/8/4N3/4K3 w - - 0 1").unwrap();
+        let pins = absolute_pins(&game, Color::White);
+        assert_eq!(pins.len(), 1);
+        assert_eq!(pins[0].pinned, Coord::new(4, 1));
+        assert_eq!(pins[0].pinner, Coord::new(4, 3));
+    }
+
+    #[test]
+    fn test_find_pins() {
+        // Bb5 pins the c6 knight to the e8 king; Bg5 pins the f6 knight to
+        // the d8 queen
+        let game = GameState::from_fen("3qk3/8/2n2n2/1B4B1/8/8/8/4K3 w - - 0 1").unwrap();
+        let pins = find_pins(&game, Color::White);
+        assert_eq!(pins.len(), 2);
+
+        let absolute = pins.iter().find(|p| p.pinner == Coord::new(1, 4)).unwrap();
+        assert_eq!(absolute.pinned, Coord::new(2, 5));
+        assert_eq!(
+            absolute.describe(&game),
+            "the bishop pins the knight to the king"
+        );
+
+        let relative = pins.iter().find(|p| p.pinner == Coord::new(6, 4)).unwrap();
+        assert_eq!(relative.anchor, Coord::new(3, 7));
+        assert_eq!(
+            relative.describe(&game),
+            "the bishop pins the knight to the queen"
+        );
+
+        assert!(find_skewers(&game, Color::White).is_empty());
+    }
+
+    #[test]
+    fn test_find_skewers() {
+        // Ra4 skewers the queen on d4 to the rook on g4
+        let game = GameState::from_fen("4k3/8/8/8/R2q2r1/8/8/4K3 w - - 0 1").unwrap();
+        let skewers = find_skewers(&game, Color::White);
+        assert_eq!(
+            skewers,
+            vec![Skewer {
+                attacker: Coord::new(0, 3),
+                front: Coord::new(3, 3),
+                behind: Coord::new(6, 3),
+            }]
+        );
+        assert_eq!(
+            skewers[0].describe(&game),
+            "the rook skewers the queen to the rook"
+        );
+        assert!(find_pins(&game, Color::White).is_empty());
+    }
+
+    #[test]
+    fn test_no_pin_with_two_blockers_or_wrong_line() {
+        // Two pieces between rook and king
+        let game = GameState::from_fen("4k3/8/4r3/8/4P3/8/4N3/4K3 w - - 0 1").unwrap();
+        assert!(absolute_pins(&game, Color::White).is_empty());
+
+        // A rook cannot pin along a diagonal
+        let game = GameState::from_fen("4k3/8/8/1r6/8/3N4/8/5K2 w - - 0 1").unwrap();
+        assert!(absolute_pins(&game, Color::White).is_empty());
+    }
+}
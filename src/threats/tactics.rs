@@ -0,0 +1,155 @@
+//! Tactic detection: checks and forks created by a single move.
+
+use crate::core::{Color, Coord, GameState, Move, PieceType, StandardBoard};
+use crate::eval::piece_value;
+use crate::movegen::{attacked_squares, generate_legal_moves, piece_attacks, MoveGenerator};
+
+/// A tactical threat available to the side to move.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Tactic {
+    /// A move that gives check.
+    Check {
+        /// The checking move
+        mv: Move,
+    },
+    /// A move after which one piece attacks two or more valuable targets.
+    Fork {
+        /// The forking move
+        mv: Move,
+        /// Type of the forking piece
+        piece: PieceType,
+        /// Squares of the forked pieces
+        targets: Vec<Coord>,
+    },
+}
+
+impl Tactic {
+    /// Returns the move that executes the tactic.
+    pub fn mv(&self) -> Move {
+        match self {
+            Tactic::Check { mv } | Tactic::Fork { mv, .. } => *mv,
+        }
+    }
+
+    /// Returns a short human-readable description.
+    pub fn describe(&self) -> String {
+        match self {
+            Tactic::Check { mv } => format!("{} gives check", mv),
+            Tactic::Fork { mv, piece, targets } => {
+                let targets: Vec<String> = targets.iter().map(|t| t.to_string()).collect();
+                let piece = format!("{:?}", piece).to_lowercase();
+                format!("{} forks {} with the {}", mv, targets.join(" and "), piece)
+            }
+        }
+    }
+}
+
+/// Returns the enemy pieces the piece on `to` attacks that are worth forking.
+///
+/// A target counts if it is the king, worth more than the attacker, or
+/// undefended after the move.
+pub(crate) fn fork_targets(after: &GameState, to: &Coord, us: Color) -> Vec<Coord> {
+    let board = after.board();
+    let Some(attacker) = board.piece_at(to) else {
+        return Vec::new();
+    };
+    let sq = StandardBoard::to_index(to).unwrap();
+    let attacks =
+        piece_attacks(attacker, sq, board.occupied()) & board.pieces_of_color(us.opposite());
+    let defended = attacked_squares(board, us.opposite());
+
+    attacks
+        .iter()
+        .filter_map(|target_sq| {
+            let coord = StandardBoard::from_index(target_sq)?;
+            let target = board.piece_at(&coord)?;
+            let valuable = target.piece_type == PieceType::King
+                || piece_value(target.piece_type) > piece_value(attacker.piece_type)
+                || !defended.get(target_sq);
+            valuable.then_some(coord)
+        })
+        .collect()
+}
+
+/// Lists the checks and forks available to the side to move.
+pub fn threat_summary(game: &GameState) -> Vec<Tactic> {
+    let us = game.side_to_move();
+    let mut tactics = Vec::new();
+
+    for mv in generate_legal_moves(game) {
+        let mut after = game.clone();
+        after.make_move(&mv);
+
+        let targets = fork_targets(&after, &mv.to, us);
+        if targets.len() >= 2 {
+            let piece = after.board().piece_at(&mv.to).unwrap().piece_type;
+            tactics.push(Tactic::Fork { mv, piece, targets });
+        } else if MoveGenerator::new(&after).in_check() {
+            tactics.push(Tactic::Check { mv });
+        }
+    }
+
+    tactics
+}
+
+/// Lists what the side *not* to move is threatening.
+///
+/// The turn is passed with a null move and the threat summary is computed
+/// for the opponent, answering "what is my opponent threatening?". Returns
+/// nothing when the side to move is in check, since passing is illegal.
+pub fn null_move_threats(game: &GameState) -> Vec<Tactic> {
+    if MoveGenerator::new(game).in_check() {
+        return Vec::new();
+    }
+    let mut flipped = game.clone();
+    flipped.make_null_move();
+    threat_summary(&flipped)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_threat_summary_finds_fork() {
+        // Nc7+ forks the king on e8 and the rook on a8
+        let game = GameState::from_fen("r3k3/8/8/3N4/8/8/8/4K3 w - - 0 1").unwrap();
+        let tactics = threat_summary(&game);
+
+        let fork = tactics
+            .iter()
+            .find(|t| matches!(t, Tactic::Fork { .. }))
+            .expect("fork should be found");
+        assert_eq!(fork.mv(), Move::from_uci("d5c7").unwrap());
+        if let Tactic::Fork { piece, targets, .. } = fork {
+            assert_eq!(*piece, PieceType::Knight);
+            assert!(targets.contains(&Coord::new(4, 7)));
+            assert!(targets.contains(&Coord::new(0, 7)));
+        }
+    }
+
+    #[test]
+    fn test_null_move_threats() {
+        // White to move, but Black threatens ...Nc2+ forking king and rook
+        let game = GameState::from_fen("4k3/8/8/8/3n4/8/8/R3K3 w - - 0 1").unwrap();
+        let threats = null_move_threats(&game);
+
+        let fork = threats
+            .iter()
+            .find(|t| matches!(t, Tactic::Fork { .. }))
+            .expect("opponent fork should be found");
+        assert_eq!(fork.mv(), Move::from_uci("d4c2").unwrap());
+        assert!(fork.describe().contains("knight"));
+
+        // ...Nf3+ is a plain check
+        assert!(threats.contains(&Tactic::Check {
+            mv: Move::from_uci("d4f3").unwrap()
+        }));
+    }
+
+    #[test]
+    fn test_null_move_threats_in_check() {
+        let game = GameState::from_fen("4k3/8/8/8/8/8/8/r3K3 w - - 0 1").unwrap();
+        assert!(null_move_threats(&game).is_empty());
+    }
+}
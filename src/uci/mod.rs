@@ -1,3 +1,242 @@
 //! UCI (Universal Chess Interface) protocol implementation.
 
-// TODO: Implement UCI protocol
+use crate::core::{Color, GameState, GameStatus, Move};
+use crate::movegen::find_legal_move;
+use crate::search::{iterative_deepening, search, search_with_root_moves, SearchLimits};
+use std::io::{self, BufRead, Write};
+use std::time::Duration;
+
+/// Search depth used when `go` does not specify one.
+pub const DEFAULT_DEPTH: u32 = 4;
+
+/// Parameters of a `go` command.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct GoParams {
+    /// Maximum search depth in plies
+    pub depth: Option<u32>,
+    /// Time to search in milliseconds
+    pub movetime: Option<u64>,
+    /// Maximum number of nodes to search
+    pub nodes: Option<u64>,
+    /// Restrict the search to these root moves (empty = all moves)
+    pub searchmoves: Vec<Move>,
+}
+
+impl GoParams {
+    /// Parses the arguments following `go`.
+    ///
+    /// Unknown tokens are ignored, as the protocol requires.
+    pub fn parse(args: &str) -> Self {
+        let mut params = Self::default();
+        let mut tokens = args.split_whitespace().peekable();
+
+        while let Some(token) = tokens.next() {
+            match token {
+                "depth" => params.depth = tokens.next().and_then(|t| t.parse().ok()),
+                "movetime" => params.movetime = tokens.next().and_then(|t| t.parse().ok()),
+                "nodes" => params.nodes = tokens.next().and_then(|t| t.parse().ok()),
+                "searchmoves" => {
+                    while let Some(mv) = tokens.peek().and_then(|t| Move::from_uci(t)) {
+                        params.searchmoves.push(mv);
+                        tokens.next();
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        params
+    }
+}
+
+/// Describes why a position has no move to play, if it is terminal.
+///
+/// Positions missing a king are reported instead of reaching the move
+/// generator, which requires both kings.
+fn terminal_reason(game: &GameState) -> Option<&'static str> {
+    let board = game.board();
+    if board.find_king(Color::White).is_none() || board.find_king(Color::Black).is_none() {
+        return Some("invalid position: missing king");
+    }
+    match game.status() {
+        GameStatus::Checkmate => Some("checkmate"),
+        GameStatus::Stalemate => Some("stalemate"),
+        _ => None,
+    }
+}
+
+/// Runs the search for a `go` command and returns the `bestmove` line.
+///
+/// On a terminal position an `info string` line naming the result precedes
+/// `bestmove 0000`. If `searchmoves` lists illegal moves, they are reported
+/// in an `info string` line and all legal moves are searched instead.
+pub fn go(game: &GameState, params: &GoParams) -> String {
+    if let Some(reason) = terminal_reason(game) {
+        return format!("info string {}\nbestmove 0000", reason);
+    }
+
+    let depth = params.depth.unwrap_or(DEFAULT_DEPTH);
+    let mut info = String::new();
+    let restricted = if params.searchmoves.is_empty() {
+        None
+    } else {
+        match search_with_root_moves(game, depth, &params.searchmoves) {
+            Ok(result) => Some(result),
+            Err(e) => {
+                info = format!("info string {}\n", e);
+                None
+            }
+        }
+    };
+    let result = if let Some(result) = restricted {
+        result
+    } else if params.movetime.is_some() || params.nodes.is_some() {
+        iterative_deepening(
+            game,
+            SearchLimits {
+                max_depth: params.depth,
+                max_nodes: params.nodes,
+                time: params.movetime.map(Duration::from_millis),
+            },
+        )
+    } else {
+        search(game, depth)
+    };
+
+    match result.best_move {
+        Some(mv) => format!("{}bestmove {}", info, mv),
+        None => format!("{}bestmove 0000", info),
+    }
+}
+
+/// Parses the arguments of a `position` command.
+///
+/// Accepts `startpos` or `fen <fields>`, optionally followed by `moves` and
+/// a list of UCI moves. An illegal move rejects the whole command.
+pub fn parse_position(args: &str) -> Result<GameState, String> {
+    let (setup, moves) = match args.split_once("moves") {
+        Some((setup, moves)) => (setup.trim(), moves),
+        None => (args.trim(), ""),
+    };
+
+    let mut game = if setup == "startpos" {
+        GameState::starting_position()
+    } else if let Some(fen) = setup.strip_prefix("fen") {
+        GameState::from_fen(fen.trim())?
+    } else {
+        return Err(format!("Invalid position command: {}", args));
+    };
+
+    for token in moves.split_whitespace() {
+        let mv = Move::from_uci(token)
+            .and_then(|mv| find_legal_move(&game, &mv))
+            .ok_or_else(|| format!("Illegal move: {}", token))?;
+        game.make_move(&mv);
+    }
+    Ok(game)
+}
+
+/// Runs the UCI command loop until `quit` or end of input.
+///
+/// Handles `uci`, `isready`, `ucinewgame`, `position`, `go`, and `quit`;
+/// other commands are ignored. Errors in `position` are reported as
+/// `info string` lines and leave the current position unchanged.
+pub fn run(input: impl BufRead, mut output: impl Write) -> io::Result<()> {
+    let mut game = GameState::starting_position();
+
+    for line in input.lines() {
+        let line = line?;
+        let line = line.trim();
+        let (command, args) = line.split_once(' ').unwrap_or((line, ""));
+
+        match command {
+            "uci" => {
+                writeln!(output, "id name InterpretableChessEngine")?;
+                writeln!(output, "id author Gingnose")?;
+                writeln!(output, "uciok")?;
+            }
+            "isready" => writeln!(output, "readyok")?,
+            "ucinewgame" => game = GameState::starting_position(),
+            "position" => match parse_position(args) {
+                Ok(position) => game = position,
+                Err(e) => writeln!(output, "info string {}", e)?,
+            },
+            "go" => writeln!(output, "{}", go(&game, &GoParams::parse(args)))?,
+            "quit" => break,
+            _ => {}
+        }
+        output.flush()?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_go_searchmoves() {
+        let params = GoParams::parse("depth 3 searchmoves e2e4 d2d4 wtime 1000");
+        assert_eq!(params.depth, Some(3));
+        assert_eq!(
+            params.searchmoves,
+            vec![
+                Move::from_uci("e2e4").unwrap(),
+                Move::from_uci("d2d4").unwrap()
+            ]
+        );
+    }
+
+    #[test]
+    fn test_go_searchmoves_restricts_root() {
+        let game = GameState::starting_position();
+        let params = GoParams::parse("depth 2 searchmoves a2a3");
+        assert_eq!(go(&game, &params), "bestmove a2a3");
+    }
+
+    #[test]
+    fn test_go_reports_illegal_searchmoves() {
+        let game = GameState::starting_position();
+        let params = GoParams::parse("depth 1 searchmoves e2e5 a1a3");
+        let output = go(&game, &params);
+        let (info, bestmove) = output.split_once('\n').unwrap();
+        assert_eq!(info, "info string Illegal root moves: e2e5 a1a3");
+        assert!(bestmove.starts_with("bestmove "));
+        assert_ne!(bestmove, "bestmove 0000");
+    }
+
+    #[test]
+    fn test_go_on_terminal_positions() {
+        let params = GoParams::parse("depth 3");
+
+        // Fool's mate
+        let mated =
+            GameState::from_fen("rnb1kbnr/pppp1ppp/8/4p3/6Pq/5P2/PPPPP2P/RNBQKBNR w KQkq - 1 3")
+                .unwrap();
+        assert_eq!(go(&mated, &params), "info string checkmate\nbestmove 0000");
+
+        let stalemate = GameState::from_fen("k7/2Q5/1K6/8/8/8/8/8 b - - 0 1").unwrap();
+        assert_eq!(
+            go(&stalemate, &params),
+            "info string stalemate\nbestmove 0000"
+        );
+
+        let kingless = GameState::from_fen("8/8/8/8/8/8/8/4K3 w - - 0 1").unwrap();
+        assert!(go(&kingless, &params).ends_with("bestmove 0000"));
+    }
+
+    #[test]
+    fn test_parse_position() {
+        let game = parse_position("startpos moves e2e4 e7e5 g1f3").unwrap();
+        assert_eq!(
+            game.to_fen(),
+            "rnbqkbnr/pppp1ppp/8/4p3/4P3/5N2/PPPP1PPP/RNBQKB1R b KQkq - 1 2"
+        );
+
+        let game = parse_position("fen 4k3/8/8/8/8/8/4P3/4K3 w - - 0 1 moves e2e4").unwrap();
+        assert_eq!(game.to_fen(), "4k3/8/8/8/4P3/8/8/4K3 b - e3 0 1");
+
+        assert!(parse_position("startpos moves e2e5").is_err());
+        assert!(parse_position("nonsense").is_err());
+    }
+}
@@ -0,0 +1,326 @@
+//! A board for variants whose pieces come from a [`PieceRegistry`].
+//!
+//! Unlike [`crate::core::Board`], which only knows the six standard piece
+//! types, this board stores registry ids and generates moves from each
+//! piece's [`PieceDefinition`]. Castling and en passant are not supported.
+
+use super::registry::{PieceId, PieceRegistry};
+use super::variant::Variant;
+use crate::core::{
+    Color, Coord, Move, MovementType, Piece, PieceDefinition, PieceType, StandardBoard,
+};
+
+/// Piece placement and side to move for a registry-based variant.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VariantBoard {
+    /// Piece at each square (None if empty)
+    squares: [Option<(PieceId, Color)>; 64],
+    /// Whose turn it is
+    side_to_move: Color,
+}
+
+impl VariantBoard {
+    /// Parses the placement and side-to-move fields of a FEN.
+    ///
+    /// Piece letters are looked up in `registry`; the remaining fields are
+    /// ignored.
+    pub fn from_fen(registry: &PieceRegistry, fen: &str) -> Result<Self, String> {
+        let parts: Vec<&str> = fen.split_whitespace().collect();
+        if parts.len() < 2 {
+            return Err("FEN must have at least 2 parts".to_string());
+        }
+
+        let ranks: Vec<&str> = parts[0].split('/').collect();
+        if ranks.len() != 8 {
+            return Err(format!("Expected 8 ranks, found {}", ranks.len()));
+        }
+
+        let mut squares = [None; 64];
+        for (i, rank_str) in ranks.iter().enumerate() {
+            let rank = 7 - i as u8;
+            let mut file = 0u8;
+            for ch in rank_str.chars() {
+                if let Some(skip) = ch.to_digit(10) {
+                    file += skip as u8;
+                    continue;
+                }
+                let id = registry
+                    .id_of(ch)
+                    .ok_or_else(|| format!("Unknown piece character: {}", ch))?;
+                let color = if ch.is_ascii_uppercase() {
+                    Color::White
+                } else {
+                    Color::Black
+                };
+                let index = StandardBoard::to_index(&Coord::new(file, rank))
+                    .ok_or_else(|| format!("Too many squares on rank {}", rank + 1))?;
+                squares[index] = Some((id, color));
+                file += 1;
+            }
+            if file != 8 {
+                return Err(format!("Rank {} does not have 8 squares", rank + 1));
+            }
+        }
+
+        let side_to_move = match parts[1] {
+            "w" => Color::White,
+            "b" => Color::Black,
+            side => return Err(format!("Invalid side to move: {}", side)),
+        };
+
+        Ok(Self {
+            squares,
+            side_to_move,
+        })
+    }
+
+    /// Converts the placement and side to move back to FEN.
+    pub fn to_fen(&self, registry: &PieceRegistry) -> String {
+        let mut placement = String::new();
+        for rank in (0..8).rev() {
+            let mut empty = 0;
+            for file in 0..8 {
+                match self.piece_at(Coord::new(file, rank)) {
+                    Some((id, color)) => {
+                        if empty > 0 {
+                            placement.push_str(&empty.to_string());
+                            empty = 0;
+                        }
+                        placement.push(registry.char_of(id, color).unwrap_or('?'));
+                    }
+                    None => empty += 1,
+                }
+            }
+            if empty > 0 {
+                placement.push_str(&empty.to_string());
+            }
+            if rank > 0 {
+                placement.push('/');
+            }
+        }
+        let side = match self.side_to_move {
+            Color::White => 'w',
+            Color::Black => 'b',
+        };
+        format!("{} {} - - 0 1", placement, side)
+    }
+
+    /// Returns the piece id and color at `coord`, if any.
+    pub fn piece_at(&self, coord: Coord) -> Option<(PieceId, Color)> {
+        StandardBoard::to_index(&coord).and_then(|index| self.squares[index])
+    }
+
+    /// Returns whose turn it is.
+    pub fn side_to_move(&self) -> Color {
+        self.side_to_move
+    }
+
+    /// Plays `mv` without checking legality.
+    ///
+    /// A promotion becomes the registry piece with the promoted piece's
+    /// letter; the piece is left unchanged if no such piece is registered.
+    pub fn make_move(&mut self, registry: &PieceRegistry, mv: &Move) {
+        let (Some(from), Some(to)) = (
+            StandardBoard::to_index(&mv.from),
+            StandardBoard::to_index(&mv.to),
+        ) else {
+            return;
+        };
+        let Some((mut id, color)) = self.squares[from].take() else {
+            return;
+        };
+        if let Some(promoted) = mv.promoted_piece() {
+            id = registry
+                .id_of(Piece::new(promoted, color).to_char())
+                .unwrap_or(id);
+        }
+        self.squares[to] = Some((id, color));
+        self.side_to_move = self.side_to_move.opposite();
+    }
+
+    /// Returns true if any piece of color `by` attacks `target`.
+    pub fn is_attacked(&self, registry: &PieceRegistry, target: Coord, by: Color) -> bool {
+        self.pieces(by).any(|(from, id)| {
+            registry.definition(id, by).is_some_and(|definition| {
+                definition
+                    .attacks(from, |c| self.piece_at(c).is_some(), on_board)
+                    .contains(&target)
+            })
+        })
+    }
+
+    /// Returns true if a royal piece of `color` is attacked.
+    pub fn in_check(&self, registry: &PieceRegistry, color: Color) -> bool {
+        self.pieces(color).any(|(coord, id)| {
+            registry
+                .definition(id, color)
+                .is_some_and(|definition| definition.is_royal)
+                && self.is_attacked(registry, coord, color.opposite())
+        })
+    }
+
+    /// Iterates over the pieces of `color` with their squares.
+    fn pieces(&self, color: Color) -> impl Iterator<Item = (Coord, PieceId)> + '_ {
+        self.squares
+            .iter()
+            .enumerate()
+            .filter_map(move |(index, square)| match square {
+                Some((id, c)) if *c == color => Some((StandardBoard::from_index(index)?, *id)),
+                _ => None,
+            })
+    }
+
+    /// Generates the moves of `color`'s pieces, ignoring royal safety.
+    fn pseudo_legal_moves(&self, registry: &PieceRegistry, color: Color) -> Vec<Move> {
+        let mut moves = Vec::new();
+        let occupied = |c: Coord| self.piece_at(c).is_some();
+        let enemy = |c: Coord| self.piece_at(c).is_some_and(|(_, owner)| owner != color);
+
+        for (from, id) in self.pieces(color) {
+            let Some(definition) = registry.definition(id, color) else {
+                continue;
+            };
+
+            // Pawn captures only land on enemies, so pawn movement is
+            // handled separately from the definition's other attacks
+            let mut pawn_moves = Vec::new();
+            let mut others = Vec::new();
+            for movement in &definition.movements {
+                match movement {
+                    MovementType::Pawn {
+                        forward,
+                        captures,
+                        double_move_from_rank,
+                    } => {
+                        let step = |c: Coord, d| c.try_offset(d).filter(|&c| on_board(c));
+                        if let Some(one) = step(from, *forward).filter(|&c| !occupied(c)) {
+                            pawn_moves.push(one);
+                            if from.rank == *double_move_from_rank {
+                                pawn_moves.extend(step(one, *forward).filter(|&c| !occupied(c)));
+                            }
+                        }
+                        pawn_moves.extend(
+                            captures
+                                .iter()
+                                .filter_map(|&d| step(from, d))
+                                .filter(|&c| enemy(c)),
+                        );
+                    }
+                    other => others.push(other.clone()),
+                }
+            }
+            let movers = PieceDefinition {
+                movements: others,
+                ..definition.clone()
+            };
+            let targets = movers
+                .attacks(from, occupied, on_board)
+                .into_iter()
+                .filter(|&c| !self.piece_at(c).is_some_and(|(_, owner)| owner == color));
+
+            let last_rank = match color {
+                Color::White => 7,
+                Color::Black => 0,
+            };
+            for to in pawn_moves {
+                if to.rank == last_rank {
+                    moves.extend(
+                        [
+                            PieceType::Queen,
+                            PieceType::Rook,
+                            PieceType::Bishop,
+                            PieceType::Knight,
+                        ]
+                        .into_iter()
+                        .filter(|&p| registry.id_of(Piece::new(p, color).to_char()).is_some())
+                        .map(|p| Move::promotion(from, to, p)),
+                    );
+                } else {
+                    moves.push(Move::new(from, to));
+                }
+            }
+            moves.extend(targets.map(|to| Move::new(from, to)));
+        }
+
+        moves
+    }
+}
+
+/// Returns true if `coord` lies on the 8x8 board.
+fn on_board(coord: Coord) -> bool {
+    StandardBoard::is_valid(&coord)
+}
+
+/// Generates the legal moves in `board` using the pieces of `variant`.
+///
+/// Moves come from each piece's [`PieceDefinition`]; a move is legal if it
+/// leaves no royal piece of the mover attacked.
+pub fn generate_variant_moves(variant: &dyn Variant, board: &VariantBoard) -> Vec<Move> {
+    let registry = variant.registry();
+    let color = board.side_to_move();
+    board
+        .pseudo_legal_moves(registry, color)
+        .into_iter()
+        .filter(|mv| {
+            let mut next = board.clone();
+            next.make_move(registry, mv);
+            !next.in_check(registry, color)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::variants::{AmazonChess, StandardChess};
+
+    fn count(variant: &dyn Variant, board: &VariantBoard, depth: u32) -> u64 {
+        if depth == 0 {
+            return 1;
+        }
+        generate_variant_moves(variant, board)
+            .iter()
+            .map(|mv| {
+                let mut next = board.clone();
+                next.make_move(variant.registry(), mv);
+                count(variant, &next, depth - 1)
+            })
+            .sum()
+    }
+
+    #[test]
+    fn test_standard_variant_moves() {
+        let variant = StandardChess::new();
+        let board = variant.start_position();
+        assert_eq!(board.to_fen(variant.registry()), variant.start_fen());
+        assert_eq!(count(&variant, &board, 1), 20);
+        assert_eq!(count(&variant, &board, 2), 400);
+    }
+
+    #[test]
+    fn test_amazon_moves() {
+        let variant = AmazonChess::new();
+        // The Amazon on d1 adds two knight leaps over the pawns
+        assert_eq!(
+            generate_variant_moves(&variant, &variant.start_position()).len(),
+            22
+        );
+
+        let board =
+            VariantBoard::from_fen(variant.registry(), "4k3/8/8/8/3A4/8/8/4K3 w - - 0 1").unwrap();
+        let amazon_moves = generate_variant_moves(&variant, &board)
+            .into_iter()
+            .filter(|mv| mv.from == Coord::new(3, 3))
+            .count();
+        assert_eq!(amazon_moves, 35);
+    }
+
+    #[test]
+    fn test_amazon_gives_mate() {
+        let variant = AmazonChess::new();
+        let board =
+            VariantBoard::from_fen(variant.registry(), "4k3/8/3A4/8/8/8/8/4K3 b - - 0 1").unwrap();
+        assert!(board.in_check(variant.registry(), Color::Black));
+        assert!(generate_variant_moves(&variant, &board).is_empty());
+    }
+}
@@ -0,0 +1,53 @@
+//! Horde: White's thirty-six pawns against Black's regular army.
+//!
+//! White has no king, so it can never be checkmated; it loses once every
+//! white piece has been captured. Pawns on the first rank may advance two
+//! squares, which the standard move generator already allows.
+
+use crate::core::{Color, GameState};
+
+/// The standard Horde starting position.
+pub const HORDE_START_FEN: &str =
+    "rnbqkbnr/pppppppp/8/1PP2PP1/PPPPPPPP/PPPPPPPP/PPPPPPPP/PPPPPPPP w kq - 0 1";
+
+/// Creates the Horde starting position.
+pub fn horde_start() -> GameState {
+    GameState::from_fen(HORDE_START_FEN).expect("Horde start FEN should be valid")
+}
+
+/// Returns true if the horde (White) has no pieces left.
+pub fn horde_destroyed(game: &GameState) -> bool {
+    game.board().pieces_of_color(Color::White).is_empty()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Move;
+    use crate::movegen::{find_legal_move, generate_legal_moves, perft};
+
+    #[test]
+    fn test_horde_perft() {
+        let game = horde_start();
+        assert_eq!(perft(&game, 1), 8);
+        assert_eq!(perft(&game, 2), 128);
+        assert_eq!(perft(&game, 3), 1274);
+    }
+
+    #[test]
+    fn test_first_rank_double_push() {
+        let game = GameState::from_fen("4k3/8/8/8/8/8/8/P7 w - - 0 1").unwrap();
+        let mut moves: Vec<String> = generate_legal_moves(&game)
+            .iter()
+            .map(|m| m.to_uci())
+            .collect();
+        moves.sort();
+        assert_eq!(moves, ["a1a2", "a1a3"]);
+
+        // No en passant square is left behind
+        let mut next = game.clone();
+        let mv = find_legal_move(&game, &Move::from_uci("a1a3").unwrap()).unwrap();
+        next.make_move(&mv);
+        assert_eq!(next.en_passant(), None);
+    }
+}
@@ -0,0 +1,30 @@
+//! King of the Hill: bringing your king to the center wins the game.
+
+use crate::core::{Color, Coord, GameState};
+
+/// The four center squares: d4, e4, d5 and e5.
+pub const HILL: [Coord; 4] = [
+    Coord { file: 3, rank: 3 },
+    Coord { file: 4, rank: 3 },
+    Coord { file: 3, rank: 4 },
+    Coord { file: 4, rank: 4 },
+];
+
+/// Returns true if `color`'s king stands on one of the center squares.
+pub fn king_on_hill(game: &GameState, color: Color) -> bool {
+    game.board()
+        .find_king(color)
+        .is_some_and(|king| HILL.contains(&king))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_king_on_hill() {
+        let game = GameState::from_fen("8/8/8/4k3/8/8/8/4K3 w - - 0 1").unwrap();
+        assert!(king_on_hill(&game, Color::Black));
+        assert!(!king_on_hill(&game, Color::White));
+    }
+}
@@ -1,3 +1,17 @@
 //! Chess variant definitions.
 
-// TODO: Implement variants (standard, amazon, camel)
+pub mod board;
+pub mod horde;
+pub mod king_of_the_hill;
+pub mod registry;
+pub mod rules;
+pub mod three_check;
+pub mod variant;
+
+pub use board::{generate_variant_moves, VariantBoard};
+pub use horde::{horde_destroyed, horde_start, HORDE_START_FEN};
+pub use king_of_the_hill::{king_on_hill, HILL};
+pub use registry::{PieceId, PieceRegistry};
+pub use rules::{variant_status, Rules, VariantStatus};
+pub use three_check::{ThreeCheckState, CHECKS_TO_WIN};
+pub use variant::{AmazonChess, StandardChess, Variant};
@@ -0,0 +1,184 @@
+//! Mapping from board characters to piece definitions.
+
+use crate::core::{Color, Delta, MovementType, PieceDefinition};
+
+/// Index of a piece in a [`PieceRegistry`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PieceId(pub u8);
+
+/// A registered piece: its FEN character and its movement for each color.
+#[derive(Clone, Debug)]
+struct Entry {
+    ch: char,
+    /// Indexed by color; Black's definition is mirrored vertically.
+    definitions: [PieceDefinition; 2],
+}
+
+/// The pieces a variant plays with, keyed by FEN character and id.
+///
+/// Characters are registered uppercase for White; the lowercase character
+/// denotes the same piece for Black.
+#[derive(Clone, Debug, Default)]
+pub struct PieceRegistry {
+    entries: Vec<Entry>,
+}
+
+impl PieceRegistry {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a registry holding the six standard pieces.
+    pub fn standard() -> Self {
+        let mut registry = Self::new();
+        for (ch, definition) in [
+            ('P', PieceDefinition::pawn_white()),
+            ('N', PieceDefinition::knight()),
+            ('B', PieceDefinition::bishop()),
+            ('R', PieceDefinition::rook()),
+            ('Q', PieceDefinition::queen()),
+            ('K', PieceDefinition::king()),
+        ] {
+            registry
+                .register(ch, definition)
+                .expect("standard pieces have distinct characters");
+        }
+        registry
+    }
+
+    /// Registers `definition`, given from White's point of view, under `ch`.
+    ///
+    /// Returns the new piece's id, or an error if `ch` is not a letter or is
+    /// already taken.
+    pub fn register(&mut self, ch: char, definition: PieceDefinition) -> Result<PieceId, String> {
+        if !ch.is_ascii_alphabetic() {
+            return Err(format!("Piece character must be a letter: {}", ch));
+        }
+        let ch = ch.to_ascii_uppercase();
+        if self.id_of(ch).is_some() {
+            return Err(format!("Piece character already registered: {}", ch));
+        }
+        let id = u8::try_from(self.entries.len())
+            .map_err(|_| "Too many pieces registered".to_string())?;
+
+        let black = mirrored(&definition);
+        self.entries.push(Entry {
+            ch,
+            definitions: [definition, black],
+        });
+        Ok(PieceId(id))
+    }
+
+    /// Returns the id registered for `ch`, ignoring case.
+    pub fn id_of(&self, ch: char) -> Option<PieceId> {
+        let ch = ch.to_ascii_uppercase();
+        self.entries
+            .iter()
+            .position(|entry| entry.ch == ch)
+            .map(|index| PieceId(index as u8))
+    }
+
+    /// Returns the FEN character of `id` for `color`.
+    pub fn char_of(&self, id: PieceId, color: Color) -> Option<char> {
+        let ch = self.entries.get(id.0 as usize)?.ch;
+        Some(match color {
+            Color::White => ch,
+            Color::Black => ch.to_ascii_lowercase(),
+        })
+    }
+
+    /// Returns how `id` moves when it belongs to `color`.
+    pub fn definition(&self, id: PieceId, color: Color) -> Option<&PieceDefinition> {
+        self.entries
+            .get(id.0 as usize)
+            .map(|entry| &entry.definitions[color as usize])
+    }
+
+    /// Returns the number of registered pieces.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns true if no pieces are registered.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+/// Flips a definition to move toward rank 1 instead of rank 8.
+fn mirrored(definition: &PieceDefinition) -> PieceDefinition {
+    let flip = |deltas: &[Delta]| -> Vec<Delta> {
+        deltas.iter().map(|d| Delta::new(d.dx, -d.dy)).collect()
+    };
+    let movements = definition
+        .movements
+        .iter()
+        .map(|movement| match movement {
+            MovementType::Slide {
+                directions,
+                max_distance,
+            } => MovementType::Slide {
+                directions: flip(directions),
+                max_distance: *max_distance,
+            },
+            MovementType::Leap { offsets } => MovementType::Leap {
+                offsets: flip(offsets),
+            },
+            MovementType::Ride {
+                offsets,
+                max_distance,
+            } => MovementType::Ride {
+                offsets: flip(offsets),
+                max_distance: *max_distance,
+            },
+            MovementType::Pawn {
+                forward,
+                captures,
+                double_move_from_rank,
+            } => MovementType::Pawn {
+                forward: Delta::new(forward.dx, -forward.dy),
+                captures: flip(captures),
+                double_move_from_rank: 7 - double_move_from_rank,
+            },
+        })
+        .collect();
+
+    PieceDefinition {
+        movements,
+        ..definition.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_standard_registry() {
+        let registry = PieceRegistry::standard();
+        assert_eq!(registry.len(), 6);
+        let pawn = registry.id_of('p').unwrap();
+        assert_eq!(registry.id_of('P'), Some(pawn));
+        assert_eq!(registry.char_of(pawn, Color::Black), Some('p'));
+        assert_eq!(
+            registry.definition(pawn, Color::Black).unwrap().movements,
+            PieceDefinition::pawn_black().movements
+        );
+    }
+
+    #[test]
+    fn test_register_custom_piece() {
+        let mut registry = PieceRegistry::standard();
+        let amazon = registry.register('a', PieceDefinition::amazon()).unwrap();
+        assert_eq!(amazon, PieceId(6));
+        assert_eq!(registry.char_of(amazon, Color::White), Some('A'));
+        assert_eq!(
+            registry.definition(amazon, Color::White).unwrap().name,
+            "Amazon"
+        );
+
+        assert!(registry.register('Q', PieceDefinition::amazon()).is_err());
+        assert!(registry.register('1', PieceDefinition::amazon()).is_err());
+    }
+}
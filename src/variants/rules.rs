@@ -0,0 +1,131 @@
+//! Rule sets layered over the standard game.
+
+use super::horde::horde_destroyed;
+use super::king_of_the_hill::king_on_hill;
+use super::three_check::CHECKS_TO_WIN;
+use crate::core::{Color, GameState, GameStatus};
+
+/// The rule set a game is played under.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Rules {
+    /// Standard chess.
+    #[default]
+    Standard,
+    /// A king reaching d4, e4, d5 or e5 wins immediately.
+    KingOfTheHill,
+    /// White's pawn horde loses once all of its pieces are captured.
+    Horde,
+    /// Giving check three times wins.
+    ///
+    /// `checks` holds the checks each side has given so far, indexed by
+    /// color, since [`GameState`] does not track them; see
+    /// [`super::ThreeCheckState`].
+    ThreeCheck { checks: [u8; 2] },
+}
+
+/// The result of a position under a variant's rules.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VariantStatus {
+    /// No variant rule applies; the standard status stands.
+    Standard(GameStatus),
+    /// A variant rule has ended the game in favour of this color.
+    Win(Color),
+}
+
+/// Returns the status of `game` under `rules`.
+///
+/// Variant wins take precedence over the standard terminal checks, since the
+/// game ended on the move that produced them.
+pub fn variant_status(game: &GameState, rules: Rules) -> VariantStatus {
+    match rules {
+        Rules::Standard => {}
+        Rules::KingOfTheHill => {
+            for color in [Color::White, Color::Black] {
+                if king_on_hill(game, color) {
+                    return VariantStatus::Win(color);
+                }
+            }
+        }
+        Rules::Horde => {
+            if horde_destroyed(game) {
+                return VariantStatus::Win(Color::Black);
+            }
+        }
+        Rules::ThreeCheck { checks } => {
+            for color in [Color::White, Color::Black] {
+                if checks[color as usize] >= CHECKS_TO_WIN {
+                    return VariantStatus::Win(color);
+                }
+            }
+        }
+    }
+    VariantStatus::Standard(game.status())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Move;
+    use crate::movegen::find_legal_move;
+
+    #[test]
+    fn test_king_of_the_hill_win() {
+        let mut game = GameState::from_fen("4k3/8/8/8/8/4K3/8/8 w - - 0 1").unwrap();
+        let mv = find_legal_move(&game, &Move::from_uci("e3e4").unwrap()).unwrap();
+        game.make_move(&mv);
+        assert_eq!(
+            variant_status(&game, Rules::KingOfTheHill),
+            VariantStatus::Win(Color::White)
+        );
+        assert_eq!(
+            variant_status(&game, Rules::Standard),
+            VariantStatus::Standard(GameStatus::DrawInsufficientMaterial)
+        );
+    }
+
+    #[test]
+    fn test_king_next_to_hill() {
+        let game = GameState::from_fen("4k3/8/8/8/8/4K3/8/R7 b - - 0 1").unwrap();
+        assert_eq!(
+            variant_status(&game, Rules::KingOfTheHill),
+            VariantStatus::Standard(GameStatus::Ongoing)
+        );
+    }
+
+    #[test]
+    fn test_horde_destroyed() {
+        let game = GameState::from_fen("4k3/8/8/8/8/8/8/8 w - - 0 1").unwrap();
+        assert_eq!(
+            variant_status(&game, Rules::Horde),
+            VariantStatus::Win(Color::Black)
+        );
+
+        let game = GameState::from_fen("4k3/8/8/8/8/8/8/P7 w - - 0 1").unwrap();
+        assert_eq!(
+            variant_status(&game, Rules::Horde),
+            VariantStatus::Standard(GameStatus::Ongoing)
+        );
+    }
+
+    #[test]
+    fn test_three_check_win() {
+        let game = GameState::from_fen("7k/8/R7/8/8/8/8/4K3 b - - 0 1").unwrap();
+        assert_eq!(
+            variant_status(&game, Rules::ThreeCheck { checks: [3, 1] }),
+            VariantStatus::Win(Color::White)
+        );
+        assert_eq!(
+            variant_status(&game, Rules::ThreeCheck { checks: [2, 1] }),
+            VariantStatus::Standard(GameStatus::Ongoing)
+        );
+    }
+
+    #[test]
+    fn test_horde_checkmates_black() {
+        let game = GameState::from_fen("7k/6PP/5PP1/8/8/8/8/8 b - - 0 1").unwrap();
+        assert_eq!(
+            variant_status(&game, Rules::Horde),
+            VariantStatus::Standard(GameStatus::Checkmate)
+        );
+    }
+}
@@ -0,0 +1,165 @@
+//! Three-Check: giving check three times wins the game.
+
+use super::rules::{variant_status, Rules, VariantStatus};
+use crate::core::{Color, GameState, Move, UndoInfo};
+
+/// Number of checks needed to win.
+pub const CHECKS_TO_WIN: u8 = 3;
+
+/// A game state extended with the number of checks each side has given.
+#[derive(Clone, Debug)]
+pub struct ThreeCheckState {
+    game: GameState,
+    /// Checks given so far, indexed by color.
+    checks: [u8; 2],
+}
+
+impl ThreeCheckState {
+    /// Wraps a game state with no checks given yet.
+    pub fn new(game: GameState) -> Self {
+        Self {
+            game,
+            checks: [0; 2],
+        }
+    }
+
+    /// Parses a FEN with an optional trailing `+N+M` field.
+    ///
+    /// `N` and `M` are the checks already given by White and Black.
+    /// Example: "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1 +0+0"
+    pub fn from_fen(fen: &str) -> Result<Self, String> {
+        let fen = fen.trim();
+        let (base, counts) = match fen.rsplit_once(char::is_whitespace) {
+            Some((base, last)) if last.starts_with('+') => (base, Some(last)),
+            _ => (fen, None),
+        };
+
+        let mut state = Self::new(GameState::from_fen(base)?);
+        if let Some(counts) = counts {
+            let parts: Vec<&str> = counts[1..].split('+').collect();
+            if parts.len() != 2 {
+                return Err(format!("Invalid check counter: {}", counts));
+            }
+            for (color, part) in parts.iter().enumerate() {
+                let n: u8 = part
+                    .parse()
+                    .map_err(|_| format!("Invalid check count: {}", part))?;
+                if n > CHECKS_TO_WIN {
+                    return Err(format!("Check count out of range: {}", n));
+                }
+                state.checks[color] = n;
+            }
+        }
+        Ok(state)
+    }
+
+    /// Converts to FEN with the `+N+M` check counter appended.
+    pub fn to_fen(&self) -> String {
+        format!(
+            "{} +{}+{}",
+            self.game.to_fen(),
+            self.checks[0],
+            self.checks[1]
+        )
+    }
+
+    /// Returns the underlying game state.
+    pub fn game(&self) -> &GameState {
+        &self.game
+    }
+
+    /// Returns the number of checks `color` has given.
+    pub fn checks(&self, color: Color) -> u8 {
+        self.checks[color as usize]
+    }
+
+    /// Makes a move, counting it if it gives check.
+    pub fn make_move(&mut self, mv: &Move) -> UndoInfo {
+        let mover = self.game.side_to_move();
+        let undo = self.game.make_move(mv);
+        if self.game.is_check() {
+            self.checks[mover as usize] += 1;
+        }
+        undo
+    }
+
+    /// Takes back a move made with [`ThreeCheckState::make_move`].
+    pub fn unmake_move(&mut self, mv: &Move, undo: UndoInfo) {
+        if self.game.is_check() {
+            let mover = self.game.side_to_move().opposite();
+            self.checks[mover as usize] -= 1;
+        }
+        self.game.unmake_move(mv, undo);
+    }
+
+    /// Returns the Three-Check rule set with the current check counts.
+    pub fn rules(&self) -> Rules {
+        Rules::ThreeCheck {
+            checks: self.checks,
+        }
+    }
+
+    /// Returns the status, declaring a win once a side reaches three checks.
+    ///
+    /// Shorthand for [`variant_status`] with [`ThreeCheckState::rules`].
+    pub fn status(&self) -> VariantStatus {
+        variant_status(&self.game, self.rules())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::GameStatus;
+    use crate::movegen::find_legal_move;
+
+    fn play(state: &mut ThreeCheckState, uci: &str) -> (Move, UndoInfo) {
+        let mv = find_legal_move(state.game(), &Move::from_uci(uci).unwrap()).unwrap();
+        let undo = state.make_move(&mv);
+        (mv, undo)
+    }
+
+    #[test]
+    fn test_fen_check_counter() {
+        let fen = "7k/8/8/8/8/8/8/R3K3 w - - 0 1 +2+1";
+        let state = ThreeCheckState::from_fen(fen).unwrap();
+        assert_eq!(state.checks(Color::White), 2);
+        assert_eq!(state.checks(Color::Black), 1);
+        assert_eq!(state.to_fen(), fen);
+
+        let plain = ThreeCheckState::from_fen("7k/8/8/8/8/8/8/R3K3 w - - 0 1").unwrap();
+        assert_eq!(plain.checks(Color::White), 0);
+        assert!(ThreeCheckState::from_fen("7k/8/8/8/8/8/8/R3K3 w - - 0 1 +x+0").is_err());
+    }
+
+    #[test]
+    fn test_three_checks_win() {
+        let mut state = ThreeCheckState::from_fen("7k/8/8/8/8/8/8/R3K3 w - - 0 1").unwrap();
+        for uci in ["a1a8", "h8g7", "a8a7", "g7f6"] {
+            play(&mut state, uci);
+        }
+        assert_eq!(state.checks(Color::White), 2);
+        assert_eq!(state.status(), VariantStatus::Standard(GameStatus::Ongoing));
+
+        play(&mut state, "a7a6");
+        assert_eq!(state.checks(Color::White), 3);
+        assert_eq!(state.status(), VariantStatus::Win(Color::White));
+    }
+
+    #[test]
+    fn test_unmake_restores_counter() {
+        let mut state = ThreeCheckState::from_fen("7k/8/8/8/8/8/8/R3K3 w - - 0 1 +1+0").unwrap();
+        let before = state.to_fen();
+
+        let (mv, undo) = play(&mut state, "a1a8");
+        assert_eq!(state.checks(Color::White), 2);
+        state.unmake_move(&mv, undo);
+        assert_eq!(state.checks(Color::White), 1);
+        assert_eq!(state.to_fen(), before);
+
+        let (mv, undo) = play(&mut state, "e1d2");
+        assert_eq!(state.checks(Color::White), 1);
+        state.unmake_move(&mv, undo);
+        assert_eq!(state.to_fen(), before);
+    }
+}
@@ -0,0 +1,95 @@
+//! The `Variant` trait and the variants built on it.
+
+use super::board::VariantBoard;
+use super::registry::PieceRegistry;
+use crate::core::PieceDefinition;
+
+/// A chess variant: the pieces it uses and where they start.
+pub trait Variant {
+    /// Display name of the variant.
+    fn name(&self) -> &str;
+
+    /// The pieces available in this variant.
+    fn registry(&self) -> &PieceRegistry;
+
+    /// The starting position in FEN, using the registry's characters.
+    fn start_fen(&self) -> &str;
+
+    /// Parses the starting position.
+    fn start_position(&self) -> VariantBoard {
+        VariantBoard::from_fen(self.registry(), self.start_fen())
+            .expect("Variant start FEN should be valid")
+    }
+}
+
+/// Standard chess expressed as a variant.
+#[derive(Clone, Debug)]
+pub struct StandardChess {
+    registry: PieceRegistry,
+}
+
+impl StandardChess {
+    /// Creates the standard variant.
+    pub fn new() -> Self {
+        Self {
+            registry: PieceRegistry::standard(),
+        }
+    }
+}
+
+impl Default for StandardChess {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Variant for StandardChess {
+    fn name(&self) -> &str {
+        "Standard"
+    }
+
+    fn registry(&self) -> &PieceRegistry {
+        &self.registry
+    }
+
+    fn start_fen(&self) -> &str {
+        "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w - - 0 1"
+    }
+}
+
+/// Standard chess with White's queen replaced by an Amazon (queen + knight).
+#[derive(Clone, Debug)]
+pub struct AmazonChess {
+    registry: PieceRegistry,
+}
+
+impl AmazonChess {
+    /// Creates the variant, registering the Amazon as `A`.
+    pub fn new() -> Self {
+        let mut registry = PieceRegistry::standard();
+        registry
+            .register('A', PieceDefinition::amazon())
+            .expect("A is not a standard piece character");
+        Self { registry }
+    }
+}
+
+impl Default for AmazonChess {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Variant for AmazonChess {
+    fn name(&self) -> &str {
+        "Amazon"
+    }
+
+    fn registry(&self) -> &PieceRegistry {
+        &self.registry
+    }
+
+    fn start_fen(&self) -> &str {
+        "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBAKBNR w - - 0 1"
+    }
+}
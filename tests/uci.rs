@@ -0,0 +1,39 @@
+//! Drives the UCI command loop with a scripted session.
+
+use interpretable_chess_engine::core::Move;
+use interpretable_chess_engine::movegen::find_legal_move;
+use interpretable_chess_engine::uci::{parse_position, run};
+
+#[test]
+fn test_run_scripted_session() {
+    let script = "uci\nisready\nucinewgame\nposition startpos moves e2e4 e7e5\n\
+                  go depth 2\nposition fen 4k3/8/8/8/8/8/4P3/4K3 w - - 0 1\n\
+                  go nodes 300\nquit\ngo depth 1\n";
+    let mut output = Vec::new();
+    run(script.as_bytes(), &mut output).unwrap();
+    let output = String::from_utf8(output).unwrap();
+    let lines: Vec<&str> = output.lines().collect();
+
+    assert_eq!(
+        lines[..4],
+        [
+            "id name InterpretableChessEngine",
+            "id author Gingnose",
+            "uciok",
+            "readyok"
+        ]
+    );
+    let bestmoves: Vec<&str> = lines
+        .iter()
+        .filter_map(|line| line.strip_prefix("bestmove "))
+        .collect();
+    // The go after quit is never run
+    assert_eq!(bestmoves.len(), 2);
+
+    let game = parse_position("startpos moves e2e4 e7e5").unwrap();
+    let mv = Move::from_uci(bestmoves[0]).unwrap();
+    assert!(find_legal_move(&game, &mv).is_some());
+    let game = parse_position("fen 4k3/8/8/8/8/8/4P3/4K3 w - - 0 1").unwrap();
+    let mv = Move::from_uci(bestmoves[1]).unwrap();
+    assert!(find_legal_move(&game, &mv).is_some());
+}